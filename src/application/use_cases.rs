@@ -1,7 +1,16 @@
 use crate::domain::errors::Result;
-use crate::domain::models::{Board, BoardId, Issue, IssueFilter, Paginated, Worklog, WorklogEntry};
-use crate::domain::repositories::JiraRepository;
+use crate::domain::models::{
+    Attachment, Board, BoardConfiguration, BoardFilter, BoardId, ChangelogEntry, Comment,
+    FieldMeta, FieldValue, Issue, IssueFilter, IssueLinkType, IssueType, LinkDirection, NewIssue,
+    PageCursor, Paginated, SavedQuery, Sprint, SprintId, SprintState, Transition, User, Votes,
+    Watchers, Worklog, WorklogEntry,
+};
+use crate::domain::repositories::{JiraRepository, SavedQueryRepository};
+use futures::StreamExt;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
 
 /// Use Case: Retrieve all visible boards for the authenticated user.
 pub struct GetBoardsUseCase {
@@ -13,8 +22,30 @@ impl GetBoardsUseCase {
         Self { repository }
     }
 
-    pub async fn execute(&self) -> Result<Vec<Board>> {
-        self.repository.get_boards().await
+    pub async fn execute(
+        &self,
+        filter: BoardFilter,
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Board>> {
+        self.repository
+            .get_boards(filter, cursor, max_results)
+            .await
+    }
+}
+
+/// Use Case: Retrieve the column layout of a board for the Kanban view.
+pub struct GetBoardConfigurationUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetBoardConfigurationUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, board_id: BoardId) -> Result<BoardConfiguration> {
+        self.repository.get_board_configuration(board_id).await
     }
 }
 
@@ -27,19 +58,180 @@ impl GetBacklogUseCase {
         Self { repository }
     }
 
+    /// Loads a page of the board issues, or of a single sprint when `sprint_id` is set.
     pub async fn execute(
         &self,
         board_id: BoardId,
-        start_at: u64,
+        sprint_id: Option<SprintId>,
+        cursor: PageCursor,
         max_results: u64,
         filter: IssueFilter,
     ) -> Result<Paginated<Issue>> {
+        match sprint_id {
+            Some(sprint_id) => {
+                self.repository
+                    .get_sprint_issues(sprint_id, cursor, max_results, filter)
+                    .await
+            }
+            None => {
+                self.repository
+                    .get_issues_by_board(board_id, cursor, max_results, filter)
+                    .await
+            }
+        }
+    }
+}
+
+/// Use Case: List the sprints of a board for the sprint picker.
+///
+/// Every page is fetched; sprints come back active first, then future ones in
+/// planned order, then closed ones from the most recent.
+pub struct GetSprintsUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetSprintsUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, board_id: BoardId) -> Result<Vec<Sprint>> {
+        let states = [
+            SprintState::Active,
+            SprintState::Future,
+            SprintState::Closed,
+        ];
+        let mut sprints = Vec::new();
+        let mut cursor = PageCursor::First;
+        loop {
+            let page = self
+                .repository
+                .get_sprints(board_id, &states, cursor, 50)
+                .await?;
+            sprints.extend(page.items);
+            match page.next {
+                Some(next) => cursor = next,
+                None => break,
+            }
+        }
+
+        let (mut closed, open): (Vec<Sprint>, Vec<Sprint>) = sprints
+            .into_iter()
+            .partition(|s| s.state == SprintState::Closed);
+        let (mut ordered, future): (Vec<Sprint>, Vec<Sprint>) = open
+            .into_iter()
+            .partition(|s| s.state == SprintState::Active);
+        ordered.extend(future);
+        closed.reverse();
+        ordered.extend(closed);
+        Ok(ordered)
+    }
+}
+
+/// Use Case: Move an issue into a sprint, or back to the backlog when `sprint_id` is `None`.
+pub struct MoveIssueToSprintUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl MoveIssueToSprintUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, sprint_id: Option<SprintId>) -> Result<()> {
+        let issue_keys = [issue_key.to_string()];
+        match sprint_id {
+            Some(sprint_id) => {
+                self.repository
+                    .move_issues_to_sprint(sprint_id, &issue_keys)
+                    .await
+            }
+            None => self.repository.move_issues_to_backlog(&issue_keys).await,
+        }
+    }
+}
+
+/// Use Case: List the issue types that can be created in a project.
+pub struct GetCreateIssueTypesUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetCreateIssueTypesUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, project_key: &str) -> Result<Vec<IssueType>> {
+        self.repository.get_create_issue_types(project_key).await
+    }
+}
+
+/// Use Case: Retrieve the create screen fields of an issue type.
+pub struct GetCreateFieldsUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetCreateFieldsUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, project_key: &str, issue_type_id: &str) -> Result<Vec<FieldMeta>> {
         self.repository
-            .get_issues_by_board(board_id, start_at, max_results, filter)
+            .get_create_fields(project_key, issue_type_id)
             .await
     }
 }
 
+/// Use Case: Create an issue from a filled-in create form.
+pub struct CreateIssueUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl CreateIssueUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue: NewIssue) -> Result<Issue> {
+        self.repository.create_issue(issue).await
+    }
+}
+
+/// Use Case: List the fields of an issue that can be edited.
+pub struct GetEditFieldsUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetEditFieldsUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str) -> Result<Vec<FieldMeta>> {
+        self.repository.get_edit_fields(issue_key).await
+    }
+}
+
+/// Use Case: Save the fields changed in the issue edit form.
+pub struct UpdateIssueUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl UpdateIssueUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        issue_key: &str,
+        changes: Vec<(FieldMeta, Option<FieldValue>)>,
+    ) -> Result<()> {
+        self.repository.update_issue(issue_key, changes).await
+    }
+}
+
 pub struct AddWorklogUseCase {
     repository: Arc<dyn JiraRepository>,
 }
@@ -104,3 +296,561 @@ impl DeleteWorklogUseCase {
         self.repository.delete_worklog(issue_key, worklog_id).await
     }
 }
+
+/// Use Case: Fetch a page of the comments of an issue.
+pub struct GetCommentsUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetCommentsUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        issue_key: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<Comment>> {
+        self.repository
+            .get_comments(issue_key, start_at, max_results)
+            .await
+    }
+}
+
+/// Use Case: Comment on an issue.
+pub struct AddCommentUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl AddCommentUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, body: &str) -> Result<Comment> {
+        self.repository.add_comment(issue_key, body).await
+    }
+}
+
+/// Use Case: Rewrite an existing comment.
+pub struct UpdateCommentUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl UpdateCommentUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, comment_id: &str, body: &str) -> Result<Comment> {
+        self.repository
+            .update_comment(issue_key, comment_id, body)
+            .await
+    }
+}
+
+/// Use Case: Delete a comment.
+pub struct DeleteCommentUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl DeleteCommentUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, comment_id: &str) -> Result<()> {
+        self.repository.delete_comment(issue_key, comment_id).await
+    }
+}
+
+/// Use Case: Fetch a page of the history of an issue.
+pub struct GetChangelogUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetChangelogUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        issue_key: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<ChangelogEntry>> {
+        self.repository
+            .get_changelog(issue_key, start_at, max_results)
+            .await
+    }
+}
+
+/// Use Case: Find users to @mention.
+pub struct SearchUsersUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl SearchUsersUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, query: &str) -> Result<Vec<User>> {
+        self.repository.search_users(query).await
+    }
+}
+
+/// Use Case: Find the users an issue can be assigned to.
+pub struct SearchAssignableUsersUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl SearchAssignableUsersUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, query: &str) -> Result<Vec<User>> {
+        self.repository
+            .search_assignable_users(issue_key, query)
+            .await
+    }
+}
+
+/// Use Case: Get the user the session is authenticated as.
+pub struct GetCurrentUserUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetCurrentUserUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<User> {
+        self.repository.get_current_user().await
+    }
+}
+
+/// Use Case: Assign an issue to a user, or leave it unassigned.
+pub struct AssignIssueUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl AssignIssueUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, account_id: Option<&str>) -> Result<()> {
+        self.repository.assign_issue(issue_key, account_id).await
+    }
+}
+
+/// Use Case: Look up the custom fields shown with the issues.
+pub struct GetCustomFieldsUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetCustomFieldsUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<FieldMeta>> {
+        self.repository.get_custom_fields().await
+    }
+}
+
+/// Use Case: List who watches an issue.
+pub struct GetWatchersUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetWatchersUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str) -> Result<Watchers> {
+        self.repository.get_watchers(issue_key).await
+    }
+}
+
+/// Use Case: Start or stop watching an issue and return its updated watchers.
+pub struct WatchIssueUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl WatchIssueUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        issue_key: &str,
+        account_id: &str,
+        watch: bool,
+    ) -> Result<Watchers> {
+        if watch {
+            self.repository.add_watcher(issue_key, account_id).await?;
+        } else {
+            self.repository
+                .remove_watcher(issue_key, account_id)
+                .await?;
+        }
+        self.repository.get_watchers(issue_key).await
+    }
+}
+
+/// Use Case: Vote for an issue or withdraw the vote, returning the updated votes.
+pub struct VoteIssueUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl VoteIssueUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, vote: bool) -> Result<Votes> {
+        if vote {
+            self.repository.add_vote(issue_key).await?;
+        } else {
+            self.repository.remove_vote(issue_key).await?;
+        }
+        self.repository.get_votes(issue_key).await
+    }
+}
+
+/// Use Case: Fetch a single issue, e.g. to open one reached through a link.
+pub struct GetIssueUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetIssueUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str) -> Result<Issue> {
+        self.repository.get_issue(issue_key).await
+    }
+}
+
+/// Use Case: List the kinds of links issues can have.
+pub struct GetIssueLinkTypesUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetIssueLinkTypesUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<IssueLinkType>> {
+        self.repository.get_issue_link_types().await
+    }
+}
+
+/// Use Case: Link an issue to another one and return it with its updated links.
+pub struct LinkIssuesUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl LinkIssuesUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        issue_key: &str,
+        link_type: &IssueLinkType,
+        direction: LinkDirection,
+        other_key: &str,
+    ) -> Result<Issue> {
+        self.repository
+            .link_issues(issue_key, link_type, direction, other_key)
+            .await?;
+        // Jira does not return the new link, so its ID comes with the issue
+        self.repository.get_issue(issue_key).await
+    }
+}
+
+/// Use Case: Remove a link between two issues.
+pub struct DeleteIssueLinkUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl DeleteIssueLinkUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, link_id: &str) -> Result<()> {
+        self.repository.delete_issue_link(link_id).await
+    }
+}
+
+/// Use Case: Save an attachment in the download directory, reporting the bytes received.
+pub struct DownloadAttachmentUseCase {
+    repository: Arc<dyn JiraRepository>,
+    download_dir: PathBuf,
+}
+
+impl DownloadAttachmentUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>, download_dir: PathBuf) -> Self {
+        Self {
+            repository,
+            download_dir,
+        }
+    }
+
+    /// Returns the path written, which gets a numbered name when the file already exists.
+    pub async fn execute(
+        &self,
+        attachment: &Attachment,
+        on_progress: impl Fn(u64),
+    ) -> Result<PathBuf> {
+        let mut chunks = self.repository.download_attachment(attachment).await?;
+
+        tokio::fs::create_dir_all(&self.download_dir).await?;
+        let (path, mut file) = create_free_file(&self.download_dir, attachment).await?;
+
+        let mut received = 0;
+        let written: Result<()> = async {
+            while let Some(chunk) = chunks.next().await {
+                let chunk = chunk?;
+                file.write_all(&chunk).await?;
+                received += chunk.len() as u64;
+                on_progress(received);
+            }
+            file.flush().await?;
+            Ok(())
+        }
+        .await;
+
+        // Do not leave a truncated file behind
+        if written.is_err() {
+            let _ = tokio::fs::remove_file(&path).await;
+        }
+        written.map(|_| path)
+    }
+}
+
+/// Creates a new file in `dir` for the attachment without overwriting an existing one:
+/// `log.txt`, then `log (1).txt`, `log (2).txt`...
+///
+/// Each name is claimed with `create_new`, so concurrent downloads of the same file cannot
+/// pick the same one.
+async fn create_free_file(dir: &Path, attachment: &Attachment) -> Result<(PathBuf, File)> {
+    for path in candidate_paths(dir, attachment) {
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .await
+        {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    unreachable!("the candidate names never run out")
+}
+
+/// The names tried for the attachment in `dir`, in order.
+fn candidate_paths(dir: &Path, attachment: &Attachment) -> impl Iterator<Item = PathBuf> {
+    // Only the file name is kept, so the download cannot escape the directory
+    let filename = Path::new(&attachment.filename)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| format!("attachment-{}", attachment.id));
+    let name = Path::new(&filename);
+    let stem = name
+        .file_stem()
+        .map_or(filename.clone(), |s| s.to_string_lossy().into_owned());
+    let extension = name
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+
+    let dir = dir.to_path_buf();
+    std::iter::once(dir.join(&filename))
+        .chain((1..).map(move |n| dir.join(format!("{} ({}){}", stem, n, extension))))
+}
+
+/// Use Case: Attach a local file to an issue.
+pub struct UploadAttachmentUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl UploadAttachmentUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, path: &Path) -> Result<Vec<Attachment>> {
+        self.repository.add_attachment(issue_key, path).await
+    }
+}
+
+/// Use Case: List the workflow transitions available for an issue.
+pub struct GetTransitionsUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetTransitionsUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str) -> Result<Vec<Transition>> {
+        self.repository.get_transitions(issue_key).await
+    }
+}
+
+/// Use Case: Move an issue to another status by applying a transition.
+pub struct TransitionIssueUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl TransitionIssueUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, issue_key: &str, transition_id: &str) -> Result<()> {
+        self.repository
+            .transition_issue(issue_key, transition_id)
+            .await
+    }
+}
+
+/// Use Case: Run a free-form JQL query.
+pub struct SearchIssuesUseCase {
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl SearchIssuesUseCase {
+    pub fn new(repository: Arc<dyn JiraRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        jql: &str,
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Issue>> {
+        self.repository
+            .search_issues(jql, cursor, max_results)
+            .await
+    }
+}
+
+/// Use Case: List local saved queries followed by the user's Jira filters.
+///
+/// Jira filters are best effort: if they cannot be fetched the local
+/// queries are still returned.
+pub struct GetSavedQueriesUseCase {
+    saved_queries: Arc<dyn SavedQueryRepository>,
+    repository: Arc<dyn JiraRepository>,
+}
+
+impl GetSavedQueriesUseCase {
+    pub fn new(
+        saved_queries: Arc<dyn SavedQueryRepository>,
+        repository: Arc<dyn JiraRepository>,
+    ) -> Self {
+        Self {
+            saved_queries,
+            repository,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<SavedQuery>> {
+        let mut queries = self.saved_queries.load().await?;
+        match self.repository.get_my_filters().await {
+            Ok(filters) => queries.extend(filters),
+            Err(e) => log::warn!("Could not load Jira filters: {}", e),
+        }
+        Ok(queries)
+    }
+}
+
+pub struct SaveQueryUseCase {
+    saved_queries: Arc<dyn SavedQueryRepository>,
+}
+
+impl SaveQueryUseCase {
+    pub fn new(saved_queries: Arc<dyn SavedQueryRepository>) -> Self {
+        Self { saved_queries }
+    }
+
+    pub async fn execute(&self, query: SavedQuery) -> Result<()> {
+        self.saved_queries.save(query).await
+    }
+}
+
+pub struct DeleteSavedQueryUseCase {
+    saved_queries: Arc<dyn SavedQueryRepository>,
+}
+
+impl DeleteSavedQueryUseCase {
+    pub fn new(saved_queries: Arc<dyn SavedQueryRepository>) -> Self {
+        Self { saved_queries }
+    }
+
+    pub async fn execute(&self, name: &str) -> Result<()> {
+        self.saved_queries.delete(name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(filename: &str) -> Attachment {
+        Attachment {
+            id: "10".to_string(),
+            filename: filename.to_string(),
+            size: 0,
+            mime_type: "text/plain".to_string(),
+            author: "Ana Lopez".to_string(),
+            created_at: chrono::Utc::now(),
+            content_url: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn downloads_never_overwrite_or_leave_the_directory() {
+        let dir = std::env::temp_dir().join(format!("jira-tui-downloads-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let created = |filename: &'static str| {
+            let dir = dir.clone();
+            async move {
+                create_free_file(&dir, &attachment(filename))
+                    .await
+                    .unwrap()
+                    .0
+            }
+        };
+
+        assert_eq!(created("crash.log").await, dir.join("crash.log"));
+        std::fs::write(dir.join("crash (1).log"), "").unwrap();
+        assert_eq!(created("crash.log").await, dir.join("crash (2).log"));
+        // Concurrent downloads of the same file get a name each
+        let (a, b) = tokio::join!(created("crash.log"), created("crash.log"));
+        assert_ne!(a, b);
+        assert_eq!(created("../../etc/passwd").await, dir.join("passwd"));
+        assert_eq!(created("..").await, dir.join("attachment-10"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
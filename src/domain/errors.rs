@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -14,6 +15,29 @@ pub enum AppError {
     #[error("Unauthorized operation. Check credentials.")]
     Unauthorized,
 
+    /// 400: Jira rejected the request, with general messages and per-field errors.
+    #[error("{}", format_validation(messages, field_errors))]
+    Validation {
+        messages: Vec<String>,
+        field_errors: BTreeMap<String, String>,
+    },
+
+    /// 403: authenticated, but without permission for this operation.
+    #[error("Permission denied: {0}")]
+    Forbidden(String),
+
+    /// 429 once the retries are exhausted.
+    #[error("{}", format_rate_limited(*retry_after_secs))]
+    RateLimited { retry_after_secs: Option<u64> },
+
+    /// 409: the resource changed or is locked.
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// 5xx responses.
+    #[error("Jira server error ({status}): {message}")]
+    ServerError { status: u16, message: String },
+
     #[error("Input/Output Error: {0}")]
     IOError(#[from] std::io::Error),
 
@@ -21,4 +45,42 @@ pub enum AppError {
     Unknown(String),
 }
 
+impl AppError {
+    /// Whether the same request may succeed if it is tried again later.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            AppError::ApiError(_)
+                | AppError::RateLimited { .. }
+                | AppError::Conflict(_)
+                | AppError::ServerError { .. }
+        )
+    }
+}
+
+fn format_validation(messages: &[String], field_errors: &BTreeMap<String, String>) -> String {
+    let lines: Vec<String> = messages
+        .iter()
+        .cloned()
+        .chain(
+            field_errors
+                .iter()
+                .map(|(field, message)| format!("{}: {}", field, message)),
+        )
+        .collect();
+
+    if lines.is_empty() {
+        "Invalid request".to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+fn format_rate_limited(retry_after_secs: Option<u64>) -> String {
+    match retry_after_secs {
+        Some(secs) => format!("Rate limited by Jira, try again in {}s", secs),
+        None => "Rate limited by Jira, try again later".to_string(),
+    }
+}
+
 pub type Result<T> = std::result::Result<T, AppError>;
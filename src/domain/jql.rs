@@ -0,0 +1,569 @@
+use chrono::NaiveDate;
+use std::fmt;
+
+/// A value on the right-hand side of a JQL condition.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JqlValue {
+    /// Free text, always emitted as a quoted and escaped string.
+    String(String),
+    Number(i64),
+    /// A JQL function call such as `currentUser()` or `membersOf("devs")`.
+    Function {
+        name: String,
+        args: Vec<String>,
+    },
+    /// The `EMPTY` keyword.
+    Empty,
+    /// An absolute date, emitted as `"yyyy-MM-dd"`.
+    Date(NaiveDate),
+    /// A relative offset from now such as `-7d` or `2w`.
+    Relative {
+        amount: i64,
+        unit: TimeUnit,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeUnit {
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl JqlValue {
+    /// A function call without arguments, e.g. `JqlValue::function("currentUser")`.
+    pub fn function(name: &str) -> Self {
+        Self::Function {
+            name: name.to_string(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn function_with_args(name: &str, args: &[&str]) -> Self {
+        Self::Function {
+            name: name.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    pub fn days_ago(days: i64) -> Self {
+        Self::Relative {
+            amount: -days,
+            unit: TimeUnit::Days,
+        }
+    }
+}
+
+impl From<&str> for JqlValue {
+    fn from(value: &str) -> Self {
+        JqlValue::String(value.to_string())
+    }
+}
+
+impl From<String> for JqlValue {
+    fn from(value: String) -> Self {
+        JqlValue::String(value)
+    }
+}
+
+impl From<i64> for JqlValue {
+    fn from(value: i64) -> Self {
+        JqlValue::Number(value)
+    }
+}
+
+impl From<NaiveDate> for JqlValue {
+    fn from(value: NaiveDate) -> Self {
+        JqlValue::Date(value)
+    }
+}
+
+impl fmt::Display for JqlValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JqlValue::String(s) => write!(f, "{}", quote(s)),
+            JqlValue::Number(n) => write!(f, "{}", n),
+            JqlValue::Function { name, args } => {
+                let args: Vec<String> = args.iter().map(|a| quote(a)).collect();
+                write!(f, "{}({})", name, args.join(", "))
+            }
+            JqlValue::Empty => write!(f, "EMPTY"),
+            JqlValue::Date(date) => write!(f, "\"{}\"", date.format("%Y-%m-%d")),
+            JqlValue::Relative { amount, unit } => {
+                let unit = match unit {
+                    TimeUnit::Minutes => 'm',
+                    TimeUnit::Hours => 'h',
+                    TimeUnit::Days => 'd',
+                    TimeUnit::Weeks => 'w',
+                };
+                write!(f, "{}{}", amount, unit)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    /// Text search (`~`).
+    Contains,
+    /// Negated text search (`!~`).
+    NotContains,
+}
+
+impl Operator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Eq => "=",
+            Operator::NotEq => "!=",
+            Operator::Gt => ">",
+            Operator::Ge => ">=",
+            Operator::Lt => "<",
+            Operator::Le => "<=",
+            Operator::Contains => "~",
+            Operator::NotContains => "!~",
+        }
+    }
+}
+
+/// A composable JQL condition tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Clause {
+    Condition {
+        field: String,
+        op: Operator,
+        value: JqlValue,
+    },
+    In {
+        field: String,
+        values: Vec<JqlValue>,
+        negated: bool,
+    },
+    And(Vec<Clause>),
+    Or(Vec<Clause>),
+    Not(Box<Clause>),
+}
+
+impl Clause {
+    pub fn condition(field: &str, op: Operator, value: impl Into<JqlValue>) -> Self {
+        Clause::Condition {
+            field: field.to_string(),
+            op,
+            value: value.into(),
+        }
+    }
+
+    pub fn eq(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::condition(field, Operator::Eq, value)
+    }
+
+    pub fn not_eq(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::condition(field, Operator::NotEq, value)
+    }
+
+    pub fn gt(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::condition(field, Operator::Gt, value)
+    }
+
+    pub fn ge(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::condition(field, Operator::Ge, value)
+    }
+
+    pub fn lt(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::condition(field, Operator::Lt, value)
+    }
+
+    pub fn le(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::condition(field, Operator::Le, value)
+    }
+
+    /// Text search, e.g. `summary ~ "login error"`.
+    pub fn contains(field: &str, text: &str) -> Self {
+        Self::condition(field, Operator::Contains, text)
+    }
+
+    pub fn not_contains(field: &str, text: &str) -> Self {
+        Self::condition(field, Operator::NotContains, text)
+    }
+
+    pub fn is_empty(field: &str) -> Self {
+        Self::eq(field, JqlValue::Empty)
+    }
+
+    pub fn is_not_empty(field: &str) -> Self {
+        Self::not_eq(field, JqlValue::Empty)
+    }
+
+    pub fn in_list<V: Into<JqlValue>>(field: &str, values: impl IntoIterator<Item = V>) -> Self {
+        Clause::In {
+            field: field.to_string(),
+            values: values.into_iter().map(Into::into).collect(),
+            negated: false,
+        }
+    }
+
+    pub fn not_in<V: Into<JqlValue>>(field: &str, values: impl IntoIterator<Item = V>) -> Self {
+        Clause::In {
+            field: field.to_string(),
+            values: values.into_iter().map(Into::into).collect(),
+            negated: true,
+        }
+    }
+
+    /// Combines two clauses with AND, flattening nested AND groups.
+    pub fn and(self, other: Clause) -> Self {
+        match (self, other) {
+            (Clause::And(mut a), Clause::And(b)) => {
+                a.extend(b);
+                Clause::And(a)
+            }
+            (Clause::And(mut a), other) => {
+                a.push(other);
+                Clause::And(a)
+            }
+            (this, Clause::And(mut b)) => {
+                b.insert(0, this);
+                Clause::And(b)
+            }
+            (this, other) => Clause::And(vec![this, other]),
+        }
+    }
+
+    /// Combines two clauses with OR, flattening nested OR groups.
+    pub fn or(self, other: Clause) -> Self {
+        match (self, other) {
+            (Clause::Or(mut a), Clause::Or(b)) => {
+                a.extend(b);
+                Clause::Or(a)
+            }
+            (Clause::Or(mut a), other) => {
+                a.push(other);
+                Clause::Or(a)
+            }
+            (this, Clause::Or(mut b)) => {
+                b.insert(0, this);
+                Clause::Or(b)
+            }
+            (this, other) => Clause::Or(vec![this, other]),
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Clause::Not(Box::new(self))
+    }
+
+    fn is_group(&self) -> bool {
+        matches!(self, Clause::And(v) | Clause::Or(v) if v.len() > 1)
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Clause::Condition {
+                field,
+                op,
+                value: JqlValue::Empty,
+            } if matches!(op, Operator::Eq | Operator::NotEq) => {
+                let keyword = if *op == Operator::Eq { "IS" } else { "IS NOT" };
+                write!(f, "{} {} EMPTY", quote_field(field), keyword)
+            }
+            Clause::Condition { field, op, value } => {
+                write!(f, "{} {} {}", quote_field(field), op.as_str(), value)
+            }
+            Clause::In {
+                field,
+                values,
+                negated,
+            } => {
+                let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                let keyword = if *negated { "NOT IN" } else { "IN" };
+                write!(
+                    f,
+                    "{} {} ({})",
+                    quote_field(field),
+                    keyword,
+                    values.join(", ")
+                )
+            }
+            Clause::And(clauses) => write_group(f, clauses, "AND"),
+            Clause::Or(clauses) => write_group(f, clauses, "OR"),
+            Clause::Not(inner) => {
+                if inner.is_group() {
+                    write!(f, "NOT ({})", inner)
+                } else {
+                    write!(f, "NOT {}", inner)
+                }
+            }
+        }
+    }
+}
+
+fn write_group(f: &mut fmt::Formatter<'_>, clauses: &[Clause], joiner: &str) -> fmt::Result {
+    let parts: Vec<String> = clauses
+        .iter()
+        .map(|c| {
+            // Nested groups are always parenthesized to make precedence explicit
+            if c.is_group() {
+                format!("({})", c)
+            } else {
+                c.to_string()
+            }
+        })
+        .collect();
+    write!(f, "{}", parts.join(&format!(" {} ", joiner)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBy {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+impl OrderBy {
+    pub fn asc(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            direction: SortDirection::Asc,
+        }
+    }
+
+    pub fn desc(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            direction: SortDirection::Desc,
+        }
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        };
+        write!(f, "{} {}", quote_field(&self.field), direction)
+    }
+}
+
+/// A complete JQL query: an optional condition plus ORDER BY keys.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JqlQuery {
+    pub clause: Option<Clause>,
+    pub order_by: Vec<OrderBy>,
+}
+
+impl JqlQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ANDs a clause onto the current condition.
+    pub fn and(mut self, clause: Clause) -> Self {
+        self.clause = Some(match self.clause.take() {
+            Some(existing) => existing.and(clause),
+            None => clause,
+        });
+        self
+    }
+
+    /// ORs a clause onto the current condition.
+    pub fn or(mut self, clause: Clause) -> Self {
+        self.clause = Some(match self.clause.take() {
+            Some(existing) => existing.or(clause),
+            None => clause,
+        });
+        self
+    }
+
+    pub fn order_by(mut self, key: OrderBy) -> Self {
+        self.order_by.push(key);
+        self
+    }
+}
+
+impl fmt::Display for JqlQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(clause) = &self.clause {
+            parts.push(clause.to_string());
+        }
+        if !self.order_by.is_empty() {
+            let keys: Vec<String> = self.order_by.iter().map(|k| k.to_string()).collect();
+            parts.push(format!("ORDER BY {}", keys.join(", ")));
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// Wraps a value in double quotes, escaping backslashes and quotes.
+fn quote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Field names are left bare when they are simple identifiers (`status`,
+/// `cf[10010]`) and quoted otherwise (`"Story Points"`).
+fn quote_field(field: &str) -> String {
+    let is_custom_id = field
+        .strip_prefix("cf[")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .is_some_and(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()));
+    let is_identifier = !field.is_empty()
+        && field
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+
+    if is_custom_id || is_identifier {
+        field.to_string()
+    } else {
+        quote(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_quoted_and_escaped() {
+        let clause = Clause::eq("status", r#"Won't "fix" \ later"#);
+        assert_eq!(clause.to_string(), r#"status = "Won't \"fix\" \\ later""#);
+    }
+
+    #[test]
+    fn injection_attempts_stay_inside_the_value() {
+        let clause = Clause::eq("status", r#"Done" OR project = "SECRET"#);
+        assert_eq!(
+            clause.to_string(),
+            r#"status = "Done\" OR project = \"SECRET""#
+        );
+    }
+
+    #[test]
+    fn fields_are_quoted_only_when_needed() {
+        assert_eq!(Clause::eq("cf[10010]", 5).to_string(), "cf[10010] = 5");
+        assert_eq!(
+            Clause::eq("Story Points", 3).to_string(),
+            r#""Story Points" = 3"#
+        );
+    }
+
+    #[test]
+    fn functions_and_empty() {
+        assert_eq!(
+            Clause::eq("assignee", JqlValue::function("currentUser")).to_string(),
+            "assignee = currentUser()"
+        );
+        assert_eq!(
+            Clause::in_list(
+                "assignee",
+                [JqlValue::function_with_args(
+                    "membersOf",
+                    &["jira \"devs\""]
+                )]
+            )
+            .to_string(),
+            r#"assignee IN (membersOf("jira \"devs\""))"#
+        );
+        assert_eq!(
+            Clause::is_empty("assignee").to_string(),
+            "assignee IS EMPTY"
+        );
+        assert_eq!(
+            Clause::is_not_empty("sprint").to_string(),
+            "sprint IS NOT EMPTY"
+        );
+    }
+
+    #[test]
+    fn in_lists_and_text_search() {
+        assert_eq!(
+            Clause::in_list("status", ["To Do", "In Progress"]).to_string(),
+            r#"status IN ("To Do", "In Progress")"#
+        );
+        assert_eq!(
+            Clause::not_in("priority", ["Low"]).to_string(),
+            r#"priority NOT IN ("Low")"#
+        );
+        assert_eq!(
+            Clause::contains("summary", "login error").to_string(),
+            r#"summary ~ "login error""#
+        );
+    }
+
+    #[test]
+    fn dates() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+        assert_eq!(
+            Clause::ge("created", date).to_string(),
+            r#"created >= "2024-03-09""#
+        );
+        assert_eq!(
+            Clause::ge("updated", JqlValue::days_ago(7)).to_string(),
+            "updated >= -7d"
+        );
+    }
+
+    #[test]
+    fn groups_are_parenthesized() {
+        let clause = Clause::eq("project", "APP")
+            .and(Clause::eq("status", "Done").or(Clause::is_empty("resolution")))
+            .and(Clause::in_list("sprint", [JqlValue::function("openSprints")]).not());
+        assert_eq!(
+            clause.to_string(),
+            r#"project = "APP" AND (status = "Done" OR resolution IS EMPTY) AND NOT sprint IN (openSprints())"#
+        );
+
+        let negated_group = Clause::eq("a", 1).or(Clause::eq("b", 2)).not();
+        assert_eq!(negated_group.to_string(), "NOT (a = 1 OR b = 2)");
+    }
+
+    #[test]
+    fn query_with_multiple_order_keys() {
+        let query = JqlQuery::new()
+            .and(Clause::eq("assignee", JqlValue::function("currentUser")))
+            .and(Clause::eq("status", "In Progress"))
+            .order_by(OrderBy::desc("priority"))
+            .order_by(OrderBy::asc("key"));
+        assert_eq!(
+            query.to_string(),
+            r#"assignee = currentUser() AND status = "In Progress" ORDER BY priority DESC, key ASC"#
+        );
+    }
+
+    #[test]
+    fn empty_query_and_order_only() {
+        assert_eq!(JqlQuery::new().to_string(), "");
+        assert_eq!(
+            JqlQuery::new()
+                .order_by(OrderBy::desc("updated"))
+                .to_string(),
+            "ORDER BY updated DESC"
+        );
+    }
+}
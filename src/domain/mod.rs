@@ -1,3 +1,5 @@
 pub mod errors;
+pub mod jql;
 pub mod models;
 pub mod repositories;
+pub mod rich_text;
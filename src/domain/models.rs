@@ -1,8 +1,12 @@
-use chrono::{DateTime, Utc};
+use crate::domain::jql::{Clause, JqlQuery, JqlValue, OrderBy};
+use crate::domain::rich_text::RichText;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 pub type BoardId = u64;
 pub type IssueId = String;
+pub type SprintId = u64;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Board {
@@ -20,18 +24,206 @@ pub enum IssueStatus {
     Other(String),
 }
 
+impl IssueStatus {
+    /// Maps a Jira status name to the closest known status bucket.
+    pub fn from_name(name: &str) -> Self {
+        match name.to_lowercase().as_str() {
+            "to do" | "new" | "open" => IssueStatus::Todo,
+            "in progress" | "in review" => IssueStatus::InProgress,
+            "done" | "closed" | "resolved" => IssueStatus::Done,
+            _ => IssueStatus::Other(name.to_string()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Issue {
     pub key: IssueId,
     pub summary: String,
-    pub description: Option<String>,
+    pub description: Option<RichText>,
     pub status: IssueStatus,
-    pub assignee: Option<String>,
+    /// Jira ID of the exact status, used to place the issue on board columns.
+    pub status_id: Option<String>,
+    pub issue_type: IssueType,
+    /// Parent issue: the epic of a story, or the issue a subtask belongs to.
+    pub parent_key: Option<IssueId>,
+    pub subtask_keys: Vec<IssueId>,
+    /// Links to other issues (blockers, duplicates, related tickets...).
+    pub links: Vec<IssueLink>,
+    pub attachments: Vec<Attachment>,
+    pub watchers: Watchers,
+    pub votes: Votes,
+    pub labels: Vec<String>,
+    /// Values of the configured custom fields, by field ID; empty fields are left out.
+    pub fields: HashMap<String, FieldValue>,
+    pub assignee: Option<User>,
     pub priority: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Level of an issue type in the Jira hierarchy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum IssueLevel {
+    Epic,
+    #[default]
+    Standard,
+    Subtask,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueType {
+    pub id: String,
+    pub name: String,
+    pub level: IssueLevel,
+}
+
+/// How a field is edited and sent to Jira, derived from its schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FieldKind {
+    Text,
+    /// Rich text (description, text areas), typed as Markdown
+    RichText,
+    Number,
+    /// One of `allowed_values` (priority, select lists)
+    Choice,
+    /// Any number of `allowed_values` (components, versions, multi-selects)
+    MultiChoice,
+    /// Free-form labels
+    Labels,
+    /// Account ID on Jira Cloud, username on Jira Server
+    User,
+    /// Key of another issue (parent, epic link)
+    IssueKey,
+    /// Calendar date, typed as YYYY-MM-DD
+    Date,
+    /// Schema type this client cannot edit
+    Unsupported(String),
+}
+
+/// A value offered by a choice field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldOption {
+    pub id: String,
+    pub name: String,
+}
+
+/// A field of the create or edit screen of an issue type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldMeta {
+    pub id: String,
+    pub name: String,
+    pub kind: FieldKind,
+    pub required: bool,
+    /// Jira fills the field in when it is left empty (e.g. reporter)
+    pub has_default: bool,
+    pub allowed_values: Vec<FieldOption>,
+}
+
+impl FieldMeta {
+    /// Whether the field must be filled in by the user.
+    pub fn needs_value(&self) -> bool {
+        self.required && !self.has_default
+    }
+
+    /// How JQL refers to the field: `cf[10016]` for custom fields, the ID otherwise.
+    pub fn jql_name(&self) -> String {
+        match self.id.strip_prefix("customfield_") {
+            Some(number) => format!("cf[{}]", number),
+            None => self.id.clone(),
+        }
+    }
+
+    /// Clause matching issues whose field holds `value`; text fields match by contents.
+    pub fn jql_clause(&self, value: &str) -> Clause {
+        let field = self.jql_name();
+        match self.kind {
+            FieldKind::Text | FieldKind::RichText => Clause::contains(&field, value),
+            FieldKind::Number => match value.parse::<i64>() {
+                Ok(number) => Clause::eq(&field, number),
+                Err(_) => Clause::eq(&field, value),
+            },
+            FieldKind::Date => match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                Ok(date) => Clause::eq(&field, date),
+                Err(_) => Clause::eq(&field, value),
+            },
+            _ => Clause::eq(&field, value),
+        }
+    }
+}
+
+/// Value of a field: typed in a form before it is encoded, or read from an issue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FieldValue {
+    Text(String),
+    /// Markdown, converted to ADF or wiki markup
+    RichText(String),
+    Number(f64),
+    /// ID of the chosen option
+    Choice(String),
+    Choices(Vec<String>),
+    Labels(Vec<String>),
+    User(String),
+    IssueKey(String),
+    Date(NaiveDate),
+    /// Option read from an issue, with its name
+    Option(FieldOption),
+    Options(Vec<FieldOption>),
+    /// User read from an issue, with their name
+    Account(User),
+}
+
+/// An issue to be created.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewIssue {
+    pub project_key: String,
+    pub issue_type_id: String,
+    /// Values to set, with the metadata that decides how each one is encoded
+    pub fields: Vec<(FieldMeta, FieldValue)>,
+}
+
+impl NewIssue {
+    /// The issue Jira created under `key`, as far as the values sent tell.
+    pub fn into_created(self, key: IssueId) -> Issue {
+        let now = Utc::now();
+        let mut issue = Issue {
+            key,
+            summary: String::new(),
+            description: None,
+            status: IssueStatus::Todo,
+            status_id: None,
+            issue_type: IssueType {
+                id: self.issue_type_id,
+                ..IssueType::default()
+            },
+            parent_key: None,
+            subtask_keys: Vec::new(),
+            links: Vec::new(),
+            attachments: Vec::new(),
+            watchers: Watchers::default(),
+            votes: Votes::default(),
+            labels: Vec::new(),
+            fields: HashMap::new(),
+            assignee: None,
+            priority: None,
+            created_at: now,
+            updated_at: now,
+        };
+        for (meta, value) in self.fields {
+            match (meta.id.as_str(), value) {
+                ("summary", FieldValue::Text(summary)) => issue.summary = summary,
+                ("description", FieldValue::RichText(markdown)) => {
+                    issue.description =
+                        Some(RichText::from_markdown(&markdown)).filter(|d| !d.is_empty());
+                }
+                ("labels", FieldValue::Labels(labels)) => issue.labels = labels,
+                _ => {}
+            }
+        }
+        issue
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Worklog {
     pub issue_key: IssueId,
@@ -52,21 +244,237 @@ pub struct WorklogEntry {
     pub updated_at: DateTime<Utc>,
 }
 
+/// A comment on an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    pub author: String,
+    pub body: RichText,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A set of field changes made together on an issue, as recorded in its changelog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    pub id: String,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    pub changes: Vec<FieldChange>,
+}
+
+/// The change of one field: `None` when the field was empty before or after it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// A Jira user, as returned by the user searches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    /// Account ID on Jira Cloud, username on Jira Server
+    pub account_id: String,
+    pub display_name: String,
+}
+
+/// Who watches an issue, as seen by the authenticated user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Watchers {
+    pub count: u64,
+    pub is_watching: bool,
+    /// The watchers themselves, only known once fetched from the watchers endpoint.
+    pub users: Vec<User>,
+}
+
+/// Votes of an issue, as seen by the authenticated user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Votes {
+    pub count: u64,
+    pub has_voted: bool,
+}
+
+/// A file attached to an issue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: String,
+    pub filename: String,
+    /// Size in bytes
+    pub size: u64,
+    pub mime_type: String,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    /// URL the content is downloaded from
+    pub content_url: String,
+}
+
+/// Which end of a link an issue is on.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LinkDirection {
+    /// The issue is the subject of the outward description ("blocks").
+    Outward,
+    /// The issue is the subject of the inward description ("is blocked by").
+    Inward,
+}
+
+/// A kind of link between issues, such as "Blocks" or "Duplicate".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueLinkType {
+    pub id: String,
+    pub name: String,
+    /// Description read from the inward issue, e.g. "is blocked by"
+    pub inward: String,
+    /// Description read from the outward issue, e.g. "blocks"
+    pub outward: String,
+}
+
+impl IssueLinkType {
+    pub fn description(&self, direction: LinkDirection) -> &str {
+        match direction {
+            LinkDirection::Outward => &self.outward,
+            LinkDirection::Inward => &self.inward,
+        }
+    }
+}
+
+/// A link from an issue to another one, seen from the issue holding it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueLink {
+    pub id: String,
+    pub link_type: IssueLinkType,
+    pub direction: LinkDirection,
+    pub issue_key: IssueId,
+    pub issue_summary: String,
+    pub issue_status: IssueStatus,
+}
+
+impl IssueLink {
+    /// How the issue relates to the linked one, e.g. "is blocked by".
+    pub fn description(&self) -> &str {
+        self.link_type.description(self.direction)
+    }
+}
+
+/// A workflow transition available for an issue in its current status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transition {
+    pub id: String,
+    pub name: String,
+    /// Status the issue ends up in once the transition is applied.
+    pub to_status: IssueStatus,
+    pub to_status_id: Option<String>,
+}
+
+/// A column of an Agile board and the statuses mapped to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardColumn {
+    pub name: String,
+    pub status_ids: Vec<String>,
+}
+
+/// Column layout of an Agile board, as configured in Jira.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardConfiguration {
+    pub board_id: BoardId,
+    pub columns: Vec<BoardColumn>,
+}
+
+impl BoardConfiguration {
+    /// Index of the column showing `issue`, if its status is mapped to one.
+    pub fn column_of(&self, issue: &Issue) -> Option<usize> {
+        let status_id = issue.status_id.as_ref()?;
+        self.columns
+            .iter()
+            .position(|column| column.status_ids.contains(status_id))
+    }
+}
+
+/// Lifecycle state of a sprint.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SprintState {
+    Active,
+    Future,
+    Closed,
+}
+
+impl SprintState {
+    /// Parses the `state` value returned by the Agile API.
+    pub fn from_api(state: &str) -> Option<Self> {
+        match state {
+            "active" => Some(SprintState::Active),
+            "future" => Some(SprintState::Future),
+            "closed" => Some(SprintState::Closed),
+            _ => None,
+        }
+    }
+
+    /// Value of the `state` query parameter.
+    pub fn to_query(&self) -> &'static str {
+        match self {
+            SprintState::Active => "active",
+            SprintState::Future => "future",
+            SprintState::Closed => "closed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sprint {
+    pub id: SprintId,
+    pub name: String,
+    pub state: SprintState,
+    pub goal: Option<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+impl Sprint {
+    /// Whole days left until the sprint ends, counting a started day as a full one.
+    ///
+    /// Negative once the end date has passed; `None` for sprints without an end date.
+    pub fn days_remaining(&self, now: DateTime<Utc>) -> Option<i64> {
+        let seconds = (self.end_date? - now).num_seconds();
+        Some(seconds.div_euclid(86_400) + i64::from(seconds.rem_euclid(86_400) > 0))
+    }
+}
+
+/// Where a saved JQL query comes from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SavedQuerySource {
+    /// Stored in the local saved queries file.
+    Local,
+    /// A filter owned or favourited in Jira, identified by its filter ID.
+    JiraFilter(String),
+}
+
+/// A named JQL query the user can recall from the search screen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedQuery {
+    pub name: String,
+    pub jql: String,
+    pub source: SavedQuerySource,
+}
+
 /// Assignee filter options for the UI
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AssigneeFilter {
     CurrentUser,
     Unassigned,
     All,
+    /// Issues assigned to one user, chosen in the user picker
+    User(User),
 }
 
 impl AssigneeFilter {
     /// Converts the enum to the JQL assignee value
-    pub fn to_jql(&self) -> Option<String> {
+    pub fn to_jql(&self) -> Option<JqlValue> {
         match self {
-            AssigneeFilter::CurrentUser => Some("currentUser()".to_string()),
-            AssigneeFilter::Unassigned => Some("EMPTY".to_string()),
+            AssigneeFilter::CurrentUser => Some(JqlValue::function("currentUser")),
+            AssigneeFilter::Unassigned => Some(JqlValue::Empty),
             AssigneeFilter::All => None,
+            AssigneeFilter::User(user) => Some(JqlValue::String(user.account_id.clone())),
         }
     }
 
@@ -76,23 +484,53 @@ impl AssigneeFilter {
             AssigneeFilter::CurrentUser => "Mi asignación",
             AssigneeFilter::Unassigned => "Sin asignar",
             AssigneeFilter::All => "Todos",
+            AssigneeFilter::User(user) => &user.display_name,
+        }
+    }
+}
+
+/// Watcher filter options for the UI
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatcherFilter {
+    #[default]
+    All,
+    /// Issues the current user watches
+    Watching,
+}
+
+impl WatcherFilter {
+    /// Converts the enum to the JQL watcher value
+    pub fn to_jql(&self) -> Option<JqlValue> {
+        match self {
+            WatcherFilter::All => None,
+            WatcherFilter::Watching => Some(JqlValue::function("currentUser")),
+        }
+    }
+
+    /// Get display label for UI
+    pub fn label(&self) -> &str {
+        match self {
+            WatcherFilter::All => "Todas",
+            WatcherFilter::Watching => "Siguiendo",
         }
     }
 }
 
 /// Order by options for the UI
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum OrderByFilter {
     UpdatedDesc,
     CreatedDesc,
 }
 
 impl OrderByFilter {
-    /// Converts the enum to JQL ORDER BY clause
-    pub fn to_jql(&self) -> String {
+    /// Converts the enum to a JQL ORDER BY key
+    pub fn to_jql(&self) -> OrderBy {
         match self {
-            OrderByFilter::UpdatedDesc => "updated DESC".to_string(),
-            OrderByFilter::CreatedDesc => "created DESC".to_string(),
+            OrderByFilter::UpdatedDesc => OrderBy::desc("updated"),
+            OrderByFilter::CreatedDesc => OrderBy::desc("created"),
         }
     }
 
@@ -106,7 +544,8 @@ impl OrderByFilter {
 }
 
 /// Status filter options for the UI
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum StatusFilter {
     All,
     Todo,
@@ -136,37 +575,147 @@ impl StatusFilter {
     }
 }
 
+/// Board type filter options for the UI
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum BoardTypeFilter {
+    #[default]
+    All,
+    Scrum,
+    Kanban,
+}
+
+impl BoardTypeFilter {
+    /// Converts the enum to the Agile API `type` parameter
+    pub fn to_query(&self) -> Option<&'static str> {
+        match self {
+            BoardTypeFilter::All => None,
+            BoardTypeFilter::Scrum => Some("scrum"),
+            BoardTypeFilter::Kanban => Some("kanban"),
+        }
+    }
+
+    /// Get display label for UI
+    pub fn label(&self) -> &str {
+        match self {
+            BoardTypeFilter::All => "Todos los tipos",
+            BoardTypeFilter::Scrum => "Scrum",
+            BoardTypeFilter::Kanban => "Kanban",
+        }
+    }
+}
+
+/// Represents the search criteria for boards, applied by Jira.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoardFilter {
+    /// Boards whose name contains this text.
+    pub name: Option<String>,
+    /// Boards of this project (key or ID).
+    pub project_key: Option<String>,
+    pub board_type: BoardTypeFilter,
+}
+
 /// Represents the search criteria for issues.
 #[derive(Debug, Clone, Default)]
 pub struct IssueFilter {
-    /// If Some, filters by this username. If "currentUser()", uses Jira's dynamic value.
-    pub assignee: Option<String>,
+    /// If Some, filters by this assignee (a user, `currentUser()` or `EMPTY`).
+    pub assignee: Option<JqlValue>,
+    /// If Some, only issues watched by this user (`currentUser()`).
+    pub watcher: Option<JqlValue>,
+    /// Conditions on custom fields, all of which must hold.
+    pub fields: Vec<Clause>,
     /// Filter by specific status name (e.g., "In Progress").
     pub status: Option<String>,
-    /// JQL Order By clause. Default should be "updated DESC".
-    pub order_by: Option<String>,
+    /// ORDER BY keys, applied in order. Default should be "updated DESC".
+    pub order_by: Vec<OrderBy>,
 }
 
 impl IssueFilter {
     /// Creates a default filter for the current user sorted by update time.
     pub fn default_active_user() -> Self {
         Self {
-            assignee: Some("currentUser()".to_string()),
+            assignee: Some(JqlValue::function("currentUser")),
+            watcher: None,
+            fields: Vec::new(),
             status: None, // None means "All statuses"
-            order_by: Some("updated DESC".to_string()),
+            order_by: vec![OrderBy::desc("updated")],
         }
     }
 
     /// Creates a filter from UI-friendly enum options
     pub fn from_options(
         assignee: AssigneeFilter,
+        watcher: WatcherFilter,
         status: StatusFilter,
         order_by: OrderByFilter,
     ) -> Self {
         Self {
             assignee: assignee.to_jql(),
+            watcher: watcher.to_jql(),
+            fields: Vec::new(),
             status: status.to_jql(),
-            order_by: Some(order_by.to_jql()),
+            order_by: vec![order_by.to_jql()],
+        }
+    }
+
+    /// Adds a condition on a field, matched the way its kind allows.
+    pub fn with_field(mut self, meta: &FieldMeta, value: &str) -> Self {
+        self.fields.push(meta.jql_clause(value));
+        self
+    }
+
+    /// Builds the typed JQL query for this filter.
+    pub fn to_query(&self) -> JqlQuery {
+        let mut query = JqlQuery::new();
+        if let Some(assignee) = &self.assignee {
+            query = query.and(Clause::eq("assignee", assignee.clone()));
+        }
+        if let Some(watcher) = &self.watcher {
+            query = query.and(Clause::eq("watcher", watcher.clone()));
+        }
+        for clause in &self.fields {
+            query = query.and(clause.clone());
+        }
+        if let Some(status) = &self.status {
+            query = query.and(Clause::eq("status", status.as_str()));
+        }
+        for key in &self.order_by {
+            query = query.order_by(key.clone());
+        }
+        query
+    }
+
+    /// Compiles the filter into a JQL string.
+    pub fn to_jql(&self) -> String {
+        self.to_query().to_string()
+    }
+}
+
+/// Position of a page within a paginated result set.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageCursor {
+    /// The first page, whatever the pagination style of the endpoint.
+    First,
+    /// Offset-based pagination (`startAt`), used by the Agile API and Jira Server.
+    Offset(u64),
+    /// Token-based pagination (`nextPageToken`), used by the enhanced Cloud search.
+    Token(String),
+}
+
+impl PageCursor {
+    /// Returns the `startAt` offset for offset-based endpoints.
+    pub fn as_offset(&self) -> Option<u64> {
+        match self {
+            PageCursor::First => Some(0),
+            PageCursor::Offset(start_at) => Some(*start_at),
+            PageCursor::Token(_) => None,
+        }
+    }
+
+    /// Returns the `nextPageToken` for token-based endpoints (`None` for the first page).
+    pub fn as_token(&self) -> Option<&str> {
+        match self {
+            PageCursor::Token(token) => Some(token),
+            _ => None,
         }
     }
 }
@@ -174,18 +723,169 @@ impl IssueFilter {
 #[derive(Debug, Clone)]
 pub struct Paginated<T> {
     pub items: Vec<T>,
-    pub total: u64,
-    pub start_at: u64,
-    pub max_results: u64,
+    /// Total number of results, when the endpoint reports it.
+    pub total: Option<u64>,
+    /// True for the first page of a result set (lists are replaced instead of extended).
+    pub is_first_page: bool,
+    /// Cursor for the following page; `None` once the last page has been reached.
+    pub next: Option<PageCursor>,
 }
 
 impl<T> Paginated<T> {
-    pub fn new(items: Vec<T>, total: u64, start_at: u64, max_results: u64) -> Self {
+    /// Builds a page from an offset-based (`startAt`/`total`) response.
+    pub fn new(items: Vec<T>, total: u64, start_at: u64) -> Self {
+        let end = start_at + items.len() as u64;
+        let next = (!items.is_empty() && end < total).then_some(PageCursor::Offset(end));
         Self {
             items,
-            total,
-            start_at,
-            max_results,
+            total: Some(total),
+            is_first_page: start_at == 0,
+            next,
         }
     }
+
+    /// Builds a page from an offset-based response that reports `isLast` instead of a total.
+    pub fn with_is_last(items: Vec<T>, start_at: u64, is_last: bool) -> Self {
+        let end = start_at + items.len() as u64;
+        let next = (!items.is_empty() && !is_last).then_some(PageCursor::Offset(end));
+        Self {
+            items,
+            total: None,
+            is_first_page: start_at == 0,
+            next,
+        }
+    }
+
+    /// Builds a page from a token-based (`nextPageToken`) response.
+    pub fn with_token(items: Vec<T>, is_first_page: bool, next_page_token: Option<String>) -> Self {
+        Self {
+            items,
+            total: None,
+            is_first_page,
+            next: next_page_token.map(PageCursor::Token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_pages_stop_at_total() {
+        let page = Paginated::new(vec![1, 2], 4, 0);
+        assert!(page.is_first_page);
+        assert_eq!(page.next, Some(PageCursor::Offset(2)));
+
+        let last = Paginated::new(vec![3, 4], 4, 2);
+        assert!(!last.is_first_page);
+        assert_eq!(last.next, None);
+
+        // An empty page never asks for more, even if the total says otherwise
+        let empty: Paginated<u8> = Paginated::new(vec![], 10, 4);
+        assert_eq!(empty.next, None);
+    }
+
+    #[test]
+    fn is_last_pages_continue_until_flagged() {
+        let page = Paginated::with_is_last(vec![1, 2], 0, false);
+        assert!(page.is_first_page);
+        assert_eq!(page.total, None);
+        assert_eq!(page.next, Some(PageCursor::Offset(2)));
+
+        let last = Paginated::with_is_last(vec![3], 2, true);
+        assert_eq!(last.next, None);
+    }
+
+    #[test]
+    fn issue_filter_compiles_to_jql() {
+        let filter = IssueFilter::from_options(
+            AssigneeFilter::CurrentUser,
+            WatcherFilter::All,
+            StatusFilter::InProgress,
+            OrderByFilter::CreatedDesc,
+        );
+        assert_eq!(
+            filter.to_jql(),
+            r#"assignee = currentUser() AND status = "In Progress" ORDER BY created DESC"#
+        );
+
+        let unassigned = IssueFilter::from_options(
+            AssigneeFilter::Unassigned,
+            WatcherFilter::All,
+            StatusFilter::All,
+            OrderByFilter::UpdatedDesc,
+        );
+        assert_eq!(
+            unassigned.to_jql(),
+            "assignee IS EMPTY ORDER BY updated DESC"
+        );
+
+        let user = IssueFilter::from_options(
+            AssigneeFilter::User(User {
+                account_id: "abc-123".to_string(),
+                display_name: "Ana Lopez".to_string(),
+            }),
+            WatcherFilter::All,
+            StatusFilter::All,
+            OrderByFilter::UpdatedDesc,
+        );
+        assert_eq!(
+            user.to_jql(),
+            r#"assignee = "abc-123" ORDER BY updated DESC"#
+        );
+
+        let watching = IssueFilter::from_options(
+            AssigneeFilter::All,
+            WatcherFilter::Watching,
+            StatusFilter::All,
+            OrderByFilter::UpdatedDesc,
+        );
+        assert_eq!(
+            watching.to_jql(),
+            "watcher = currentUser() ORDER BY updated DESC"
+        );
+
+        let field = |id: &str, kind| FieldMeta {
+            id: id.to_string(),
+            name: id.to_string(),
+            kind,
+            required: false,
+            has_default: false,
+            allowed_values: Vec::new(),
+        };
+        let fields = IssueFilter::from_options(
+            AssigneeFilter::All,
+            WatcherFilter::All,
+            StatusFilter::All,
+            OrderByFilter::UpdatedDesc,
+        )
+        .with_field(&field("customfield_10016", FieldKind::Number), "5")
+        .with_field(&field("customfield_10040", FieldKind::Text), "mobile");
+        assert_eq!(
+            fields.to_jql(),
+            r#"cf[10016] = 5 AND cf[10040] ~ "mobile" ORDER BY updated DESC"#
+        );
+    }
+
+    #[test]
+    fn sprint_days_remaining_round_up() {
+        let now = Utc::now();
+        let sprint = |end: Option<DateTime<Utc>>| Sprint {
+            id: 1,
+            name: "Sprint 1".to_string(),
+            state: SprintState::Active,
+            goal: None,
+            start_date: None,
+            end_date: end,
+        };
+
+        let hours = chrono::Duration::hours;
+        assert_eq!(sprint(Some(now + hours(49))).days_remaining(now), Some(3));
+        assert_eq!(sprint(Some(now + hours(48))).days_remaining(now), Some(2));
+        assert_eq!(sprint(Some(now + hours(1))).days_remaining(now), Some(1));
+        assert_eq!(sprint(Some(now - hours(1))).days_remaining(now), Some(0));
+        assert_eq!(sprint(Some(now - hours(30))).days_remaining(now), Some(-1));
+        assert_eq!(sprint(None).days_remaining(now), None);
+    }
 }
@@ -1,26 +1,107 @@
 use crate::domain::errors::Result;
-use crate::domain::models::{Board, BoardId, Issue, IssueFilter, Paginated, Worklog, WorklogEntry};
+use crate::domain::models::{
+    Attachment, Board, BoardConfiguration, BoardFilter, BoardId, ChangelogEntry, Comment,
+    FieldMeta, FieldValue, Issue, IssueFilter, IssueLinkType, IssueType, LinkDirection, NewIssue,
+    PageCursor, Paginated, SavedQuery, Sprint, SprintId, SprintState, Transition, User, Votes,
+    Watchers, Worklog, WorklogEntry,
+};
 use async_trait::async_trait;
+use futures::stream::BoxStream;
+use std::path::Path;
 
 #[async_trait]
 pub trait JiraRepository: Send + Sync {
-    async fn get_boards(&self) -> Result<Vec<Board>>;
+    /// Fetches a page of the boards visible to the user that match `filter`.
+    async fn get_boards(
+        &self,
+        filter: BoardFilter,
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Board>>;
+
+    /// Fetches the column layout of an Agile board.
+    async fn get_board_configuration(&self, board_id: BoardId) -> Result<BoardConfiguration>;
 
     /// Fetches a page of issues with filters.
     ///
     /// # Arguments
     /// * `board_id` - The ID of the board.
-    /// * `start_at` - The pagination offset (e.g., 0, 20, 40).
+    /// * `cursor` - The page to fetch, as returned in `Paginated::next`.
     /// * `max_results` - How many items to fetch (e.g., 20).
     /// * `filter` - Criteria for JQL generation.
     async fn get_issues_by_board(
         &self,
         board_id: BoardId,
-        start_at: u64,
+        cursor: PageCursor,
         max_results: u64,
         filter: IssueFilter,
     ) -> Result<Paginated<Issue>>;
 
+    /// Fetches a page of the board sprints in any of `states`, oldest first.
+    async fn get_sprints(
+        &self,
+        board_id: BoardId,
+        states: &[SprintState],
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Sprint>>;
+
+    /// Fetches a page of the issues in a sprint, with the same filters as the board.
+    async fn get_sprint_issues(
+        &self,
+        sprint_id: SprintId,
+        cursor: PageCursor,
+        max_results: u64,
+        filter: IssueFilter,
+    ) -> Result<Paginated<Issue>>;
+
+    /// Moves issues into a sprint (at most 50 per call).
+    async fn move_issues_to_sprint(&self, sprint_id: SprintId, issue_keys: &[String])
+    -> Result<()>;
+
+    /// Removes issues from their sprint, back to the board backlog (at most 50 per call).
+    async fn move_issues_to_backlog(&self, issue_keys: &[String]) -> Result<()>;
+
+    /// Fetches a single issue with the same fields as the issue lists.
+    async fn get_issue(&self, issue_key: &str) -> Result<Issue>;
+
+    /// Lists the issue types the current user can create in a project.
+    async fn get_create_issue_types(&self, project_key: &str) -> Result<Vec<IssueType>>;
+
+    /// Lists the fields of the create screen for an issue type in a project.
+    async fn get_create_fields(
+        &self,
+        project_key: &str,
+        issue_type_id: &str,
+    ) -> Result<Vec<FieldMeta>>;
+
+    /// Creates an issue and returns it as it would appear in a search.
+    async fn create_issue(&self, issue: NewIssue) -> Result<Issue>;
+
+    /// Lists the fields of an issue the current user can edit.
+    async fn get_edit_fields(&self, issue_key: &str) -> Result<Vec<FieldMeta>>;
+
+    /// Sets the given fields of an issue, clearing those paired with `None`.
+    async fn update_issue(
+        &self,
+        issue_key: &str,
+        changes: Vec<(FieldMeta, Option<FieldValue>)>,
+    ) -> Result<()>;
+
+    /// Runs an arbitrary JQL query across all projects.
+    ///
+    /// Depending on the deployment this pages with `nextPageToken` (Cloud) or
+    /// `startAt` (Server); callers just pass back the cursor they received.
+    async fn search_issues(
+        &self,
+        jql: &str,
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Issue>>;
+
+    /// Lists the Jira filters owned or favourited by the current user.
+    async fn get_my_filters(&self) -> Result<Vec<SavedQuery>>;
+
     async fn add_worklog(&self, worklog: Worklog) -> Result<()>;
     async fn get_worklogs(
         &self,
@@ -35,5 +116,84 @@ pub trait JiraRepository: Send + Sync {
         worklog: Worklog,
     ) -> Result<()>;
     async fn delete_worklog(&self, issue_key: &str, worklog_id: &str) -> Result<()>;
+
+    /// Fetches a page of the comments of an issue, oldest first.
+    async fn get_comments(
+        &self,
+        issue_key: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<Comment>>;
+    /// Adds a comment written in Markdown and returns it as stored by Jira.
+    async fn add_comment(&self, issue_key: &str, body: &str) -> Result<Comment>;
+    async fn update_comment(
+        &self,
+        issue_key: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment>;
+    async fn delete_comment(&self, issue_key: &str, comment_id: &str) -> Result<()>;
+
+    /// Fetches a page of the changelog of an issue, oldest changes first.
+    async fn get_changelog(
+        &self,
+        issue_key: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<ChangelogEntry>>;
+
+    /// Finds users by name or email, for @mentions.
+    async fn search_users(&self, query: &str) -> Result<Vec<User>>;
+    /// Finds the users an issue can be assigned to; an empty query lists them all.
+    async fn search_assignable_users(&self, issue_key: &str, query: &str) -> Result<Vec<User>>;
+    /// The user the client is authenticated as.
+    async fn get_current_user(&self) -> Result<User>;
+    /// Assigns an issue to a user, or unassigns it for `None`.
+    async fn assign_issue(&self, issue_key: &str, account_id: Option<&str>) -> Result<()>;
+
+    /// The custom fields the client was configured to fetch with the issues, in that order.
+    async fn get_custom_fields(&self) -> Result<Vec<FieldMeta>>;
+
+    async fn get_watchers(&self, issue_key: &str) -> Result<Watchers>;
+    async fn add_watcher(&self, issue_key: &str, account_id: &str) -> Result<()>;
+    async fn remove_watcher(&self, issue_key: &str, account_id: &str) -> Result<()>;
+    async fn get_votes(&self, issue_key: &str) -> Result<Votes>;
+    /// Votes for an issue as the authenticated user (Jira refuses votes on your own issues).
+    async fn add_vote(&self, issue_key: &str) -> Result<()>;
+    async fn remove_vote(&self, issue_key: &str) -> Result<()>;
+
+    async fn get_issue_link_types(&self) -> Result<Vec<IssueLinkType>>;
+    /// Links `issue_key` to `other_key`, reading `direction` from `issue_key`
+    /// (outward for "PROJ-1 blocks PROJ-2").
+    async fn link_issues(
+        &self,
+        issue_key: &str,
+        link_type: &IssueLinkType,
+        direction: LinkDirection,
+        other_key: &str,
+    ) -> Result<()>;
+    async fn delete_issue_link(&self, link_id: &str) -> Result<()>;
+
+    /// Streams the content of an attachment, chunk by chunk.
+    async fn download_attachment(
+        &self,
+        attachment: &Attachment,
+    ) -> Result<BoxStream<'static, Result<Vec<u8>>>>;
+    /// Uploads a local file to an issue, returning the attachments created.
+    async fn add_attachment(&self, issue_key: &str, path: &Path) -> Result<Vec<Attachment>>;
+
+    /// Lists the transitions the current user can apply to the issue.
+    async fn get_transitions(&self, issue_key: &str) -> Result<Vec<Transition>>;
     async fn transition_issue(&self, issue_key: &str, transition_id: &str) -> Result<()>;
 }
+
+/// Local storage for named JQL queries.
+#[async_trait]
+pub trait SavedQueryRepository: Send + Sync {
+    async fn load(&self) -> Result<Vec<SavedQuery>>;
+
+    /// Stores a query, replacing any existing query with the same name.
+    async fn save(&self, query: SavedQuery) -> Result<()>;
+
+    async fn delete(&self, name: &str) -> Result<()>;
+}
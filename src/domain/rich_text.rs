@@ -0,0 +1,600 @@
+use serde::{Deserialize, Serialize};
+
+/// A formatted document (issue description, comment body...).
+///
+/// This is the domain counterpart of Jira's Atlassian Document Format: it keeps
+/// the structure the UI needs to render while staying independent from the wire format.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RichText {
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Block {
+    Paragraph(Vec<Inline>),
+    Heading {
+        level: u8,
+        content: Vec<Inline>,
+    },
+    BulletList(Vec<ListItem>),
+    OrderedList {
+        start: u32,
+        items: Vec<ListItem>,
+    },
+    Code {
+        language: Option<String>,
+        text: String,
+    },
+    Blockquote(Vec<Block>),
+    Panel {
+        kind: PanelKind,
+        content: Vec<Block>,
+    },
+    Table(Vec<TableRow>),
+    Rule,
+}
+
+/// A list item holds nested blocks (usually a paragraph, possibly a sub-list).
+pub type ListItem = Vec<Block>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableRow {
+    pub cells: Vec<TableCell>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableCell {
+    pub is_header: bool,
+    pub content: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PanelKind {
+    Info,
+    Note,
+    Warning,
+    Error,
+    Success,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Inline {
+    Text { text: String, marks: Vec<Mark> },
+    Mention { account_id: String, text: String },
+    Emoji(String),
+    HardBreak,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Mark {
+    Strong,
+    Em,
+    Code,
+    Strike,
+    Underline,
+    Link(String),
+}
+
+/// Link target that marks a Markdown link as a user mention: `[@Name](mention:id)`.
+pub const MENTION_SCHEME: &str = "mention:";
+
+impl RichText {
+    /// Builds a document from plain text, one paragraph per blank-line separated chunk.
+    pub fn from_plain(text: &str) -> Self {
+        let blocks = text
+            .split("\n\n")
+            .map(str::trim_end)
+            .filter(|chunk| !chunk.trim().is_empty())
+            .map(|chunk| {
+                let mut inlines = Vec::new();
+                for (i, line) in chunk.lines().enumerate() {
+                    if i > 0 {
+                        inlines.push(Inline::HardBreak);
+                    }
+                    inlines.push(Inline::text(line));
+                }
+                Block::Paragraph(inlines)
+            })
+            .collect();
+        Self { blocks }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Flattens the document into the lightweight Markdown subset used for editing.
+    pub fn to_markdown(&self) -> String {
+        blocks_to_markdown(&self.blocks)
+    }
+
+    /// Parses the lightweight Markdown subset typed by the user.
+    ///
+    /// Supported: paragraphs (single newlines become hard breaks), `#` headings,
+    /// `-`/`*` and `1.` lists (nested by indentation), fenced code blocks, `>` quotes,
+    /// `|` tables, `---` rules, and inline `**strong**`, `*em*`, `` `code` ``,
+    /// `~~strike~~`, `[text](url)` links and `[@Name](mention:id)` mentions.
+    pub fn from_markdown(text: &str) -> Self {
+        let lines: Vec<&str> = text.lines().collect();
+        Self {
+            blocks: parse_blocks(&lines),
+        }
+    }
+}
+
+impl Inline {
+    pub fn text(text: impl Into<String>) -> Self {
+        Inline::Text {
+            text: text.into(),
+            marks: Vec::new(),
+        }
+    }
+}
+
+fn blocks_to_markdown(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(block_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn block_to_markdown(block: &Block) -> String {
+    match block {
+        Block::Paragraph(content) => inlines_to_markdown(content),
+        Block::Heading { level, content } => format!(
+            "{} {}",
+            "#".repeat((*level).clamp(1, 6) as usize),
+            inlines_to_markdown(content)
+        ),
+        Block::BulletList(items) => items
+            .iter()
+            .map(|item| list_item_to_markdown("- ", item))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::OrderedList { start, items } => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| list_item_to_markdown(&format!("{}. ", *start as usize + i), item))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Code { language, text } => format!(
+            "```{}\n{}\n```",
+            language.as_deref().unwrap_or_default(),
+            text
+        ),
+        Block::Blockquote(content) | Block::Panel { content, .. } => blocks_to_markdown(content)
+            .lines()
+            .map(|line| format!("> {}", line).trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Table(rows) => {
+            let mut lines = Vec::new();
+            for (i, row) in rows.iter().enumerate() {
+                let cells: Vec<String> = row
+                    .cells
+                    .iter()
+                    .map(|cell| blocks_to_markdown(&cell.content).replace('\n', " "))
+                    .collect();
+                lines.push(format!("| {} |", cells.join(" | ")));
+                if i == 0 && !row.cells.is_empty() && row.cells.iter().all(|c| c.is_header) {
+                    lines.push(format!("|{}|", vec!["---"; row.cells.len()].join("|")));
+                }
+            }
+            lines.join("\n")
+        }
+        Block::Rule => "---".to_string(),
+    }
+}
+
+fn list_item_to_markdown(bullet: &str, item: &ListItem) -> String {
+    // Blocks inside an item stay tight and are indented under the bullet
+    let indent = " ".repeat(bullet.len());
+    let body = item
+        .iter()
+        .map(block_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n");
+    if body.is_empty() {
+        return bullet.trim_end().to_string();
+    }
+    body.lines()
+        .enumerate()
+        .map(|(i, line)| match (i, line.is_empty()) {
+            (0, _) => format!("{}{}", bullet, line),
+            (_, true) => String::new(),
+            _ => format!("{}{}", indent, line),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn inlines_to_markdown(inlines: &[Inline]) -> String {
+    let mut out = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text { text, marks } => {
+                let mut rendered = text.clone();
+                for mark in marks {
+                    rendered = match mark {
+                        Mark::Strong => format!("**{}**", rendered),
+                        Mark::Em => format!("*{}*", rendered),
+                        Mark::Code => format!("`{}`", rendered),
+                        Mark::Strike => format!("~~{}~~", rendered),
+                        Mark::Underline => rendered,
+                        Mark::Link(href) => format!("[{}]({})", rendered, href),
+                    };
+                }
+                out.push_str(&rendered);
+            }
+            Inline::Mention { account_id, text } => {
+                let name = text.strip_prefix('@').unwrap_or(text);
+                out.push_str(&format!("[@{}]({}{})", name, MENTION_SCHEME, account_id));
+            }
+            Inline::Emoji(shortcode) => out.push_str(shortcode),
+            Inline::HardBreak => out.push('\n'),
+        }
+    }
+    out
+}
+
+// --- Markdown parsing ---
+
+fn parse_blocks(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+
+        if trimmed.is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if let Some(language) = trimmed.strip_prefix("```") {
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                code.push(lines[i]);
+                i += 1;
+            }
+            // Skip the closing fence (if any)
+            i += 1;
+            let language = language.trim();
+            blocks.push(Block::Code {
+                language: (!language.is_empty()).then(|| language.to_string()),
+                text: code.join("\n"),
+            });
+            continue;
+        }
+
+        if let Some((level, content)) = parse_heading(trimmed) {
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inlines(content),
+            });
+            i += 1;
+            continue;
+        }
+
+        if is_rule(trimmed) {
+            blocks.push(Block::Rule);
+            i += 1;
+            continue;
+        }
+
+        if trimmed.starts_with('>') {
+            let mut quoted = Vec::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                let inner = &lines[i].trim_start()[1..];
+                quoted.push(inner.strip_prefix(' ').unwrap_or(inner));
+                i += 1;
+            }
+            blocks.push(Block::Blockquote(parse_blocks(&quoted)));
+            continue;
+        }
+
+        if trimmed.starts_with('|') {
+            let start = i;
+            while i < lines.len() && lines[i].trim_start().starts_with('|') {
+                i += 1;
+            }
+            blocks.push(parse_table(&lines[start..i]));
+            continue;
+        }
+
+        if let Some(marker) = list_marker(line) {
+            let (block, consumed) = parse_list(&lines[i..], &marker);
+            blocks.push(block);
+            i += consumed;
+            continue;
+        }
+
+        // Paragraph: runs until a blank line or the start of another block
+        let mut inlines = Vec::new();
+        while i < lines.len() {
+            let current = lines[i].trim_start();
+            if current.is_empty() || (!inlines.is_empty() && starts_block(lines[i])) {
+                break;
+            }
+            if !inlines.is_empty() {
+                inlines.push(Inline::HardBreak);
+            }
+            inlines.extend(parse_inlines(current.trim_end()));
+            i += 1;
+        }
+        blocks.push(Block::Paragraph(inlines));
+    }
+
+    blocks
+}
+
+fn starts_block(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```")
+        || trimmed.starts_with('>')
+        || trimmed.starts_with('|')
+        || parse_heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || list_marker(line).is_some()
+}
+
+fn parse_heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&level) {
+        line[level..]
+            .strip_prefix(' ')
+            .map(|content| (level as u8, content.trim()))
+    } else {
+        None
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim_end();
+    line.len() >= 3
+        && ["-", "*", "_"]
+            .iter()
+            .any(|c| line.chars().all(|ch| ch.to_string() == *c))
+}
+
+struct ListMarker {
+    indent: usize,
+    /// Width of indentation plus marker, i.e. where the item content starts.
+    content_offset: usize,
+    /// `None` for bullet lists, the item number for ordered lists.
+    number: Option<u32>,
+}
+
+fn list_marker(line: &str) -> Option<ListMarker> {
+    let indent = indent_of(line);
+    let rest = &line[indent..];
+
+    if (rest.starts_with("- ") || rest.starts_with("* ")) && !is_rule(rest) {
+        return Some(ListMarker {
+            indent,
+            content_offset: indent + 2,
+            number: None,
+        });
+    }
+
+    let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && rest[digits..].starts_with(". ") {
+        return Some(ListMarker {
+            indent,
+            content_offset: indent + digits + 2,
+            number: rest[..digits].parse().ok(),
+        });
+    }
+
+    None
+}
+
+/// Bytes of leading spaces and tabs; other whitespace is content, so offsets stay on char
+/// boundaries.
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+/// Parses a list starting at `lines[0]`. Returns the block and the number of lines consumed.
+fn parse_list(lines: &[&str], first: &ListMarker) -> (Block, usize) {
+    let ordered = first.number.is_some();
+    let mut items = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let marker = match list_marker(lines[i]) {
+            Some(m) if m.indent == first.indent && m.number.is_some() == ordered => m,
+            _ => break,
+        };
+
+        let mut body = vec![&lines[i][marker.content_offset.min(lines[i].len())..]];
+        i += 1;
+
+        // Continuation lines are indented at least up to the item content
+        while i < lines.len() {
+            let line = lines[i];
+            let indent = indent_of(line);
+            if line.trim().is_empty() {
+                let continues = lines[i + 1..]
+                    .iter()
+                    .find(|l| !l.trim().is_empty())
+                    .is_some_and(|l| indent_of(l) >= marker.content_offset);
+                if !continues {
+                    break;
+                }
+                body.push("");
+            } else if indent >= marker.content_offset {
+                body.push(&line[marker.content_offset..]);
+            } else if indent > first.indent && list_marker(line).is_some() {
+                body.push(line.trim_start());
+            } else {
+                break;
+            }
+            i += 1;
+        }
+
+        items.push(parse_blocks(&body));
+    }
+
+    let block = match first.number {
+        Some(start) => Block::OrderedList { start, items },
+        None => Block::BulletList(items),
+    };
+    (block, i.max(1))
+}
+
+fn parse_table(lines: &[&str]) -> Block {
+    let split_row = |line: &str| -> Vec<String> {
+        let inner = line.trim().trim_start_matches('|').trim_end_matches('|');
+        inner.split('|').map(|c| c.trim().to_string()).collect()
+    };
+    let is_separator = |line: &str| {
+        let cells = split_row(line);
+        !cells.is_empty()
+            && cells
+                .iter()
+                .all(|c| !c.is_empty() && c.chars().all(|ch| ch == '-' || ch == ':'))
+    };
+
+    let has_header = lines.len() > 1 && is_separator(lines[1]);
+    let rows = lines
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !(has_header && *i == 1))
+        .map(|(i, line)| TableRow {
+            cells: split_row(line)
+                .into_iter()
+                .map(|cell| TableCell {
+                    is_header: has_header && i == 0,
+                    content: if cell.is_empty() {
+                        Vec::new()
+                    } else {
+                        vec![Block::Paragraph(parse_inlines(&cell))]
+                    },
+                })
+                .collect(),
+        })
+        .collect();
+
+    Block::Table(rows)
+}
+
+fn parse_inlines(text: &str) -> Vec<Inline> {
+    let mut out: Vec<Inline> = Vec::new();
+    let mut buffer = String::new();
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    let flush = |buffer: &mut String, out: &mut Vec<Inline>| {
+        if !buffer.is_empty() {
+            push_text(out, std::mem::take(buffer), Vec::new());
+        }
+    };
+
+    while i < chars.len() {
+        if chars[i] == '\\' && i + 1 < chars.len() {
+            buffer.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        if chars[i] == '`'
+            && let Some(end) = find_closing(&chars, i + 1, "`")
+        {
+            flush(&mut buffer, &mut out);
+            let code: String = chars[i + 1..end].iter().collect();
+            push_text(&mut out, code, vec![Mark::Code]);
+            i = end + 1;
+            continue;
+        }
+
+        let delimited = [("**", Mark::Strong), ("~~", Mark::Strike), ("*", Mark::Em)];
+        if let Some((delim, mark)) = delimited
+            .iter()
+            .find(|(d, _)| starts_at(&chars, i, d) && chars.get(i + d.len()) != Some(&' '))
+            && let Some(end) = find_closing(&chars, i + delim.len(), delim)
+            && end > i + delim.len()
+        {
+            flush(&mut buffer, &mut out);
+            let inner: String = chars[i + delim.len()..end].iter().collect();
+            for inline in parse_inlines(&inner) {
+                push_inline(&mut out, with_mark(inline, mark.clone()));
+            }
+            i = end + delim.len();
+            continue;
+        }
+
+        if chars[i] == '['
+            && let Some(close) = find_closing(&chars, i + 1, "](")
+            && let Some(end) = find_closing(&chars, close + 2, ")")
+        {
+            flush(&mut buffer, &mut out);
+            let label: String = chars[i + 1..close].iter().collect();
+            let href: String = chars[close + 2..end].iter().collect();
+            if let Some(account_id) = href.strip_prefix(MENTION_SCHEME) {
+                out.push(Inline::Mention {
+                    account_id: account_id.to_string(),
+                    text: label,
+                });
+                i = end + 1;
+                continue;
+            }
+            for inline in parse_inlines(&label) {
+                push_inline(&mut out, with_mark(inline, Mark::Link(href.clone())));
+            }
+            i = end + 1;
+            continue;
+        }
+
+        buffer.push(chars[i]);
+        i += 1;
+    }
+
+    flush(&mut buffer, &mut out);
+    out
+}
+
+/// Finds the index of the next `delim` in `chars` starting at `from`.
+fn find_closing(chars: &[char], from: usize, delim: &str) -> Option<usize> {
+    (from..chars.len()).find(|&j| starts_at(chars, j, delim))
+}
+
+/// Whether `chars` holds `delim` at index `at`.
+fn starts_at(chars: &[char], at: usize, delim: &str) -> bool {
+    let mut rest = chars[at..].iter();
+    delim.chars().all(|c| rest.next() == Some(&c))
+}
+
+fn with_mark(inline: Inline, mark: Mark) -> Inline {
+    match inline {
+        Inline::Text { text, mut marks } => {
+            marks.push(mark);
+            Inline::Text { text, marks }
+        }
+        other => other,
+    }
+}
+
+fn push_text(out: &mut Vec<Inline>, text: String, marks: Vec<Mark>) {
+    push_inline(out, Inline::Text { text, marks });
+}
+
+/// Appends an inline, merging it with the previous one when both carry the same marks.
+fn push_inline(out: &mut Vec<Inline>, inline: Inline) {
+    if let (
+        Some(Inline::Text { text, marks }),
+        Inline::Text {
+            text: next_text,
+            marks: next_marks,
+        },
+    ) = (out.last_mut(), &inline)
+        && marks == next_marks
+    {
+        text.push_str(next_text);
+        return;
+    }
+    out.push(inline);
+}
@@ -1,35 +1,637 @@
 use crate::domain::errors::{AppError, Result};
+use crate::domain::models::{AssigneeFilter, BoardId, OrderByFilter, StatusFilter, WatcherFilter};
+use crate::infrastructure::credentials;
+use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::env;
+use std::path::{Path, PathBuf};
+
+/// The kind of Jira installation the client talks to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JiraDeployment {
+    /// Atlassian Cloud: REST API v3 and ADF bodies.
+    #[default]
+    Cloud,
+    /// Server / Data Center: REST API v2 and wiki markup bodies.
+    Server,
+}
+
+impl JiraDeployment {
+    /// Parses a deployment name (`cloud`, `server` or `datacenter`).
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "cloud" => Ok(JiraDeployment::Cloud),
+            "server" | "datacenter" | "data-center" | "dc" => Ok(JiraDeployment::Server),
+            other => Err(AppError::ConfigError(format!(
+                "Unknown Jira deployment '{}' (expected 'cloud' or 'server')",
+                other
+            ))),
+        }
+    }
+
+    /// Version of the core REST API (`/rest/api/{version}`) for this deployment.
+    pub fn api_version(&self) -> u8 {
+        match self {
+            JiraDeployment::Cloud => 3,
+            JiraDeployment::Server => 2,
+        }
+    }
+
+    /// The usual authentication method for this deployment.
+    pub fn default_auth(&self) -> AuthMethod {
+        match self {
+            JiraDeployment::Cloud => AuthMethod::Basic,
+            JiraDeployment::Server => AuthMethod::Pat,
+        }
+    }
+}
+
+/// How requests are authenticated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMethod {
+    /// Basic auth with the account email and an API token (Cloud).
+    Basic,
+    /// `Authorization: Bearer` with a Personal Access Token (Server / Data Center).
+    Pat,
+}
+
+impl AuthMethod {
+    /// Parses an auth method name (`basic` or `pat`).
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "basic" => Ok(AuthMethod::Basic),
+            "pat" | "bearer" => Ok(AuthMethod::Pat),
+            other => Err(AppError::ConfigError(format!(
+                "Unknown auth method '{}' (expected 'basic' or 'pat')",
+                other
+            ))),
+        }
+    }
+}
 
 /// Holds the configuration required to authenticate with Jira.
 #[derive(Clone)]
 pub struct JiraConfig {
     pub base_url: String,
-    pub email: String,
+    pub deployment: JiraDeployment,
+    pub auth: AuthMethod,
+    /// Account email, only used by basic auth.
+    pub email: Option<String>,
+    /// API token for basic auth, Personal Access Token for PAT auth.
     pub api_token: String,
+    pub http: HttpConfig,
+    /// Names or IDs of the custom fields fetched with the issues.
+    pub custom_fields: Vec<String>,
 }
 
-impl JiraConfig {
-    /// Loads configuration from environment variables.
+/// Timeouts and retry behaviour of the HTTP client (`[profiles.<name>.http]`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpConfig {
+    /// Extra attempts after a transient failure (0 disables retries).
+    pub max_retries: u32,
+    /// Timeout of each attempt, in seconds.
+    pub timeout_secs: u64,
+    /// Delay before the first retry, doubled on every attempt, in milliseconds.
+    pub initial_backoff_ms: u64,
+    /// Upper bound of a single wait, including the one asked for by `Retry-After`.
+    pub max_backoff_secs: u64,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            timeout_secs: 30,
+            initial_backoff_ms: 500,
+            max_backoff_secs: 30,
+        }
+    }
+}
+
+/// Initial issue filter of a profile. Unset fields keep the application defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DefaultFilter {
+    pub assignee: Option<AssigneeFilter>,
+    pub watcher: Option<WatcherFilter>,
+    pub status: Option<StatusFilter>,
+    pub order_by: Option<OrderByFilter>,
+}
+
+/// A custom field fetched with the issues (`[[profiles.<name>.custom_fields]]`).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomFieldConfig {
+    /// Field name as shown in Jira (e.g. "Story Points") or its ID (`customfield_10016`).
+    pub field: String,
+    /// Show the field as a column of the backlog.
+    #[serde(default)]
+    pub column: bool,
+    /// Offer the field in the filter modal.
+    #[serde(default)]
+    pub filter: bool,
+}
+
+/// Where the API token of a profile is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialBackend {
+    /// `JIRA_API_TOKEN`, or `api_token` in plain text in the profile.
+    Env,
+    /// Output of `token_command` (e.g. `pass show jira`), written with `store_command`.
+    Command,
+    /// A file encrypted with a passphrase asked for at start-up.
+    EncryptedFile,
+}
+
+impl CredentialBackend {
+    /// Parses a backend name (`env`, `command` or `encrypted_file`).
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "env" => Ok(CredentialBackend::Env),
+            "command" => Ok(CredentialBackend::Command),
+            "encrypted_file" => Ok(CredentialBackend::EncryptedFile),
+            other => Err(AppError::ConfigError(format!(
+                "Unknown credentials backend '{}' (expected 'env', 'command' or 'encrypted_file')",
+                other
+            ))),
+        }
+    }
+
+    /// The value written to `config.toml`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CredentialBackend::Env => "env",
+            CredentialBackend::Command => "command",
+            CredentialBackend::EncryptedFile => "encrypted_file",
+        }
+    }
+}
+
+/// Credential settings of a profile.
+#[derive(Clone)]
+pub struct CredentialsConfig {
+    pub backend: CredentialBackend,
+    /// Token given by `JIRA_API_TOKEN` or `api_token`; it always takes precedence.
+    pub api_token: Option<String>,
+    pub token_command: Option<String>,
+    pub store_command: Option<String>,
+}
+
+/// Name given to the profile created by the login screen when none is configured.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// The resolved configuration of the selected profile.
+///
+/// Connection settings may still be incomplete (first run): the login screen fills them in.
+#[derive(Clone)]
+pub struct AppConfig {
+    /// Name of the profile in use, `None` when running from environment variables only.
+    pub profile: Option<String>,
+    pub base_url: Option<String>,
+    pub deployment: JiraDeployment,
+    pub auth: AuthMethod,
+    /// Account email, only used by basic auth.
+    pub email: Option<String>,
+    pub credentials: CredentialsConfig,
+    /// Board opened on start-up instead of the boards list.
+    pub default_board: Option<BoardId>,
+    pub default_filter: DefaultFilter,
+    pub http: HttpConfig,
+    /// Where attachments are downloaded to.
+    pub download_dir: PathBuf,
+    pub custom_fields: Vec<CustomFieldConfig>,
+}
+
+/// On-disk layout of `config.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    default_profile: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, ProfileConfig>,
+}
+
+/// One `[profiles.<name>]` table.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileConfig {
+    base_url: Option<String>,
+    deployment: Option<String>,
+    auth: Option<String>,
+    email: Option<String>,
+    credentials: Option<String>,
+    api_token: Option<String>,
+    token_command: Option<String>,
+    store_command: Option<String>,
+    default_board: Option<BoardId>,
+    #[serde(default)]
+    default_filter: DefaultFilter,
+    #[serde(default)]
+    http: HttpConfig,
+    download_dir: Option<String>,
+    #[serde(default)]
+    custom_fields: Vec<CustomFieldConfig>,
+}
+
+/// `$XDG_CONFIG_HOME/jira-tui`, falling back to `~/.config/jira-tui`.
+pub fn config_dir() -> Result<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))
+        .map(|dir| dir.join("jira-tui"))
+        .ok_or_else(|| AppError::ConfigError("Cannot locate config directory".to_string()))
+}
+
+impl AppConfig {
+    /// Path of the configuration file (`~/.config/jira-tui/config.toml`).
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(config_dir()?.join("config.toml"))
+    }
+
+    /// Loads the configuration for `profile` from `path`, with environment overrides.
+    ///
+    /// The profile is chosen from, in order: the `profile` argument (`--profile`),
+    /// `JIRA_PROFILE`, `default_profile` in the file, or the only profile defined.
+    /// A missing file is not an error: everything can then come from the environment.
     ///
-    /// # Variables required:
-    /// - `JIRA_BASE_URL`
-    /// - `JIRA_EMAIL`
-    /// - `JIRA_API_TOKEN`
-    pub fn from_env() -> Result<Self> {
-        let base_url = env::var("JIRA_BASE_URL")
-            .map_err(|_| AppError::ConfigError("JIRA_BASE_URL not set".to_string()))?;
-
-        let email = env::var("JIRA_EMAIL")
-            .map_err(|_| AppError::ConfigError("JIRA_EMAIL not set".to_string()))?;
-
-        let api_token = env::var("JIRA_API_TOKEN")
-            .map_err(|_| AppError::ConfigError("JIRA_API_TOKEN not set".to_string()))?;
-
-        Ok(Self {
-            base_url,
-            email,
+    /// # Environment overrides:
+    /// - `JIRA_BASE_URL`, `JIRA_DEPLOYMENT` (`cloud`/`server`), `JIRA_AUTH` (`basic`/`pat`)
+    /// - `JIRA_EMAIL`, `JIRA_API_TOKEN`, `JIRA_DEFAULT_BOARD`, `JIRA_DOWNLOAD_DIR`
+    pub fn load(path: &Path, profile: Option<&str>) -> Result<Self> {
+        let file = match std::fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| AppError::ConfigError(format!("Invalid {}: {}", path.display(), e)))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => ConfigFile::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let profile = profile
+            .map(str::to_string)
+            .or_else(|| env::var("JIRA_PROFILE").ok());
+        resolve(file, profile.as_deref(), |name| env::var(name).ok())
+    }
+
+    /// The profile name, or the one the login screen will create.
+    pub fn profile_name(&self) -> &str {
+        self.profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Describes the first connection setting that is still missing, if any.
+    pub fn missing_setting(&self) -> Option<&'static str> {
+        if self.base_url.is_none() {
+            Some("Missing Jira URL (base_url / JIRA_BASE_URL)")
+        } else if self.auth == AuthMethod::Basic && self.email.is_none() {
+            Some("Missing account email for basic auth (email / JIRA_EMAIL)")
+        } else {
+            None
+        }
+    }
+
+    /// Builds the client configuration once the API token is known.
+    pub fn jira_config(&self, api_token: String) -> Result<JiraConfig> {
+        if let Some(missing) = self.missing_setting() {
+            return Err(AppError::ConfigError(missing.to_string()));
+        }
+        Ok(JiraConfig {
+            base_url: self.base_url.clone().unwrap_or_default(),
+            deployment: self.deployment,
+            auth: self.auth,
+            email: self.email.clone(),
             api_token,
+            http: self.http,
+            custom_fields: self
+                .custom_fields
+                .iter()
+                .map(|field| field.field.clone())
+                .collect(),
         })
     }
+
+    /// Writes the connection settings of this profile to `path`, keeping the rest of the file.
+    ///
+    /// The profile becomes `default_profile` when none is set yet.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut values = vec![("credentials", self.credentials.backend.as_str())];
+        if let Some(base_url) = &self.base_url {
+            values.push(("base_url", base_url));
+        }
+        if let Some(email) = &self.email {
+            values.push(("email", email));
+        }
+        if self.deployment == JiraDeployment::Server {
+            values.push(("deployment", "server"));
+        }
+        write_profile_values(path, self.profile_name(), &values)
+    }
+}
+
+/// Sets string values in `[profiles.<profile>]`, preserving comments and formatting.
+///
+/// The file may hold an API token, so it is kept readable by the current user only.
+pub fn write_profile_values(path: &Path, profile: &str, values: &[(&str, &str)]) -> Result<()> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let mut doc: toml_edit::DocumentMut = content
+        .parse()
+        .map_err(|e| AppError::ConfigError(format!("Invalid {}: {}", path.display(), e)))?;
+
+    if !doc.contains_key("default_profile") {
+        doc["default_profile"] = toml_edit::value(profile);
+    }
+    let profiles = doc["profiles"].or_insert(toml_edit::table());
+    if let Some(profiles) = profiles.as_table_mut() {
+        profiles.set_implicit(true);
+    }
+    let table = profiles[profile].or_insert(toml_edit::table());
+    for (key, value) in values {
+        table[*key] = toml_edit::value(*value);
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    credentials::write_private(path, doc.to_string().as_bytes())
+}
+
+/// Expands a leading `~` to the home directory.
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix('~'), dirs::home_dir()) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            home.join(rest.trim_start_matches('/'))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+/// The user's downloads folder, falling back to the home directory.
+fn default_download_dir() -> PathBuf {
+    dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Picks the profile and applies the environment overrides on top of it.
+fn resolve(
+    file: ConfigFile,
+    profile: Option<&str>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<AppConfig> {
+    let name = match profile.map(str::to_string).or(file.default_profile) {
+        Some(name) => Some(name),
+        None if file.profiles.len() == 1 => file.profiles.keys().next().cloned(),
+        None => None,
+    };
+
+    let settings = match &name {
+        Some(name) => file.profiles.get(name).cloned().ok_or_else(|| {
+            let available: Vec<&str> = file.profiles.keys().map(String::as_str).collect();
+            AppError::ConfigError(if available.is_empty() {
+                format!("Profile '{}' not found: no profiles are defined", name)
+            } else {
+                format!(
+                    "Profile '{}' not found (available: {})",
+                    name,
+                    available.join(", ")
+                )
+            })
+        })?,
+        None => ProfileConfig::default(),
+    };
+
+    let var = |key: &str| env(key).filter(|value| !value.trim().is_empty());
+
+    let deployment = match var("JIRA_DEPLOYMENT").or(settings.deployment) {
+        Some(value) => JiraDeployment::parse(&value)?,
+        None => JiraDeployment::default(),
+    };
+
+    let auth = match var("JIRA_AUTH").or(settings.auth) {
+        Some(value) => AuthMethod::parse(&value)?,
+        None => deployment.default_auth(),
+    };
+
+    let backend = match &settings.credentials {
+        Some(value) => CredentialBackend::parse(value)?,
+        None if settings.token_command.is_some() => CredentialBackend::Command,
+        None => CredentialBackend::Env,
+    };
+    if backend == CredentialBackend::Command && settings.token_command.is_none() {
+        return Err(AppError::ConfigError(
+            "credentials = \"command\" requires token_command".to_string(),
+        ));
+    }
+
+    let default_board = match var("JIRA_DEFAULT_BOARD") {
+        Some(value) => Some(value.trim().parse().map_err(|_| {
+            AppError::ConfigError(format!("Invalid JIRA_DEFAULT_BOARD '{}'", value))
+        })?),
+        None => settings.default_board,
+    };
+
+    Ok(AppConfig {
+        profile: name,
+        base_url: var("JIRA_BASE_URL").or(settings.base_url),
+        deployment,
+        auth,
+        email: var("JIRA_EMAIL").or(settings.email),
+        credentials: CredentialsConfig {
+            backend,
+            api_token: var("JIRA_API_TOKEN").or(settings.api_token),
+            token_command: settings.token_command,
+            store_command: settings.store_command,
+        },
+        default_board,
+        default_filter: settings.default_filter,
+        http: settings.http,
+        download_dir: var("JIRA_DOWNLOAD_DIR")
+            .or(settings.download_dir)
+            .map_or_else(default_download_dir, |dir| expand_home(dir.trim())),
+        custom_fields: settings.custom_fields,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = r#"
+        default_profile = "work"
+
+        [profiles.work]
+        base_url = "https://acme.atlassian.net"
+        email = "me@acme.com"
+        api_token = "cloud-token"
+        default_board = 42
+
+        [profiles.work.default_filter]
+        assignee = "unassigned"
+        watcher = "watching"
+        status = "in_progress"
+
+        [[profiles.work.custom_fields]]
+        field = "Story Points"
+        column = true
+        filter = true
+
+        [[profiles.work.custom_fields]]
+        field = "customfield_10020"
+
+        [profiles.onprem]
+        base_url = "https://jira.acme.com"
+        deployment = "server"
+        api_token = "pat"
+
+        [profiles.onprem.http]
+        max_retries = 5
+        timeout_secs = 60
+    "#;
+
+    fn parse(content: &str) -> ConfigFile {
+        toml::from_str(content).expect("valid config")
+    }
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn uses_the_default_profile() {
+        let config = resolve(parse(FILE), None, no_env).unwrap();
+        assert_eq!(config.profile.as_deref(), Some("work"));
+        assert_eq!(config.auth, AuthMethod::Basic);
+        assert_eq!(config.credentials.backend, CredentialBackend::Env);
+        assert_eq!(config.default_board, Some(42));
+        assert_eq!(
+            config.default_filter.assignee,
+            Some(AssigneeFilter::Unassigned)
+        );
+        assert_eq!(config.default_filter.watcher, Some(WatcherFilter::Watching));
+        assert_eq!(config.default_filter.status, Some(StatusFilter::InProgress));
+        assert_eq!(
+            config.custom_fields[0],
+            CustomFieldConfig {
+                field: "Story Points".to_string(),
+                column: true,
+                filter: true,
+            }
+        );
+        assert!(!config.custom_fields[1].column);
+        assert_eq!(
+            config
+                .jira_config("token".to_string())
+                .unwrap()
+                .custom_fields,
+            vec!["Story Points", "customfield_10020"]
+        );
+    }
+
+    #[test]
+    fn selected_profile_derives_auth_from_deployment() {
+        let config = resolve(parse(FILE), Some("onprem"), no_env).unwrap();
+        assert_eq!(config.deployment, JiraDeployment::Server);
+        assert_eq!(config.auth, AuthMethod::Pat);
+        assert_eq!(config.email, None);
+        assert_eq!(config.missing_setting(), None);
+        assert_eq!(config.http.max_retries, 5);
+        assert_eq!(config.http.timeout_secs, 60);
+        assert_eq!(
+            config.http.initial_backoff_ms,
+            HttpConfig::default().initial_backoff_ms
+        );
+    }
+
+    #[test]
+    fn environment_overrides_the_profile() {
+        let env = |key: &str| match key {
+            "JIRA_API_TOKEN" => Some("from-env".to_string()),
+            "JIRA_DEFAULT_BOARD" => Some("7".to_string()),
+            "JIRA_DOWNLOAD_DIR" => Some("/tmp/jira".to_string()),
+            _ => None,
+        };
+        let config = resolve(parse(FILE), None, env).unwrap();
+        assert_eq!(config.download_dir, PathBuf::from("/tmp/jira"));
+        assert_eq!(config.credentials.api_token.as_deref(), Some("from-env"));
+        assert_eq!(
+            config.base_url.as_deref(),
+            Some("https://acme.atlassian.net")
+        );
+        assert_eq!(config.default_board, Some(7));
+    }
+
+    #[test]
+    fn works_without_a_config_file() {
+        let env = |key: &str| match key {
+            "JIRA_BASE_URL" => Some("https://x.atlassian.net".to_string()),
+            "JIRA_EMAIL" => Some("me@x.com".to_string()),
+            "JIRA_API_TOKEN" => Some("token".to_string()),
+            _ => None,
+        };
+        let config = resolve(ConfigFile::default(), None, env).unwrap();
+        assert_eq!(config.profile, None);
+        assert_eq!(config.deployment, JiraDeployment::Cloud);
+        assert!(config.jira_config("token".to_string()).is_ok());
+    }
+
+    #[test]
+    fn reports_unknown_profiles_and_missing_fields() {
+        let Err(err) = resolve(parse(FILE), Some("nope"), no_env) else {
+            panic!("unknown profile accepted");
+        };
+        assert!(err.to_string().contains("available: onprem, work"));
+
+        let config = resolve(ConfigFile::default(), None, no_env).unwrap();
+        assert!(config.missing_setting().unwrap().contains("JIRA_BASE_URL"));
+        assert!(config.jira_config("token".to_string()).is_err());
+    }
+
+    #[test]
+    fn token_command_selects_the_command_backend() {
+        let file = parse(
+            r#"
+            [profiles.pass]
+            base_url = "https://acme.atlassian.net"
+            email = "me@acme.com"
+            token_command = "pass show jira"
+            "#,
+        );
+        let config = resolve(file, None, no_env).unwrap();
+        assert_eq!(config.credentials.backend, CredentialBackend::Command);
+        assert_eq!(
+            config.credentials.token_command.as_deref(),
+            Some("pass show jira")
+        );
+    }
+
+    #[test]
+    fn saving_a_profile_keeps_the_rest_of_the_file() {
+        let dir = std::env::temp_dir().join(format!("jira-tui-config-{}", std::process::id()));
+        let path = dir.join("config.toml");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            &path,
+            "# my settings\n[profiles.old]\nbase_url = \"https://old\"\n",
+        )
+        .unwrap();
+
+        write_profile_values(
+            &path,
+            "new",
+            &[("base_url", "https://new"), ("api_token", "t")],
+        )
+        .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(content.starts_with("default_profile = \"new\""));
+        assert!(content.contains("# my settings"));
+        let config = resolve(parse(&content), None, no_env).unwrap();
+        assert_eq!(config.profile.as_deref(), Some("new"));
+        assert_eq!(config.base_url.as_deref(), Some("https://new"));
+        assert_eq!(config.credentials.api_token.as_deref(), Some("t"));
+    }
 }
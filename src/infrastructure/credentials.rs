@@ -0,0 +1,334 @@
+use crate::domain::errors::{AppError, Result};
+use crate::infrastructure::config::{self, AppConfig, CredentialBackend};
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// A place where the Jira API token is kept between runs.
+pub trait CredentialProvider {
+    /// Reads the stored token. Returns `Ok(None)` when nothing has been stored yet.
+    fn load(&self) -> Result<Option<String>>;
+
+    /// Stores `token`, replacing any previous one.
+    fn store(&self, token: &str) -> Result<()>;
+}
+
+/// Reads `JIRA_API_TOKEN` / `api_token` and stores the token in plain text in the profile.
+pub struct EnvCredentials {
+    token: Option<String>,
+    config_path: PathBuf,
+    profile: String,
+}
+
+impl EnvCredentials {
+    pub fn new(token: Option<String>, config_path: PathBuf, profile: String) -> Self {
+        Self {
+            token,
+            config_path,
+            profile,
+        }
+    }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn load(&self) -> Result<Option<String>> {
+        Ok(self.token.clone())
+    }
+
+    fn store(&self, token: &str) -> Result<()> {
+        config::write_profile_values(&self.config_path, &self.profile, &[("api_token", token)])
+    }
+}
+
+/// Delegates to external commands, e.g. `pass show jira` / `pass insert -m jira`.
+///
+/// The token is the first line printed by `token_command`; `store_command` receives it on stdin.
+pub struct CommandCredentials {
+    token_command: String,
+    store_command: Option<String>,
+}
+
+impl CommandCredentials {
+    pub fn new(token_command: String, store_command: Option<String>) -> Self {
+        Self {
+            token_command,
+            store_command,
+        }
+    }
+}
+
+/// Builds a command that runs `command_line` through the platform shell.
+fn shell(command_line: &str) -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", command_line]);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.args(["-c", command_line]);
+        command
+    }
+}
+
+impl CredentialProvider for CommandCredentials {
+    fn load(&self) -> Result<Option<String>> {
+        let output = shell(&self.token_command).stderr(Stdio::piped()).output()?;
+        if !output.status.success() {
+            return Err(AppError::ConfigError(format!(
+                "token_command '{}' failed: {}",
+                self.token_command,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .next()
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(str::to_string))
+    }
+
+    fn store(&self, token: &str) -> Result<()> {
+        let store_command = self.store_command.as_deref().ok_or_else(|| {
+            AppError::ConfigError(
+                "Set store_command in config.toml to save tokens with the command backend"
+                    .to_string(),
+            )
+        })?;
+
+        let mut child = shell(store_command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "{}", token)?;
+        }
+        let output = child.wait_with_output()?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(AppError::ConfigError(format!(
+                "store_command '{}' failed: {}",
+                store_command,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+}
+
+/// Magic header of encrypted credential files (followed by a format version byte).
+const MAGIC: &[u8; 4] = b"JTUI";
+const VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// Keeps the token in a local file encrypted with XChaCha20-Poly1305.
+///
+/// The key is derived from the user's passphrase with Argon2id. File layout:
+/// `JTUI | version | salt (16) | nonce (24) | ciphertext`.
+pub struct EncryptedFileCredentials {
+    path: PathBuf,
+    passphrase: String,
+}
+
+impl EncryptedFileCredentials {
+    pub fn new(path: PathBuf, passphrase: String) -> Self {
+        Self { path, passphrase }
+    }
+
+    /// `~/.config/jira-tui/credentials/<profile>.enc`.
+    pub fn default_path(profile: &str) -> Result<PathBuf> {
+        Ok(config::config_dir()?
+            .join("credentials")
+            .join(format!("{}.enc", profile)))
+    }
+
+    /// Whether a token has already been stored (and must be unlocked with the passphrase).
+    pub fn exists(path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn cipher(&self, salt: &[u8]) -> Result<XChaCha20Poly1305> {
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(self.passphrase.as_bytes(), salt, &mut key)
+            .map_err(|e| AppError::Unknown(format!("Key derivation failed: {}", e)))?;
+        Ok(XChaCha20Poly1305::new(Key::from_slice(&key)))
+    }
+}
+
+impl CredentialProvider for EncryptedFileCredentials {
+    fn load(&self) -> Result<Option<String>> {
+        let data = match std::fs::read(&self.path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let header = MAGIC.len() + 1;
+        if data.len() < header + SALT_LEN + NONCE_LEN
+            || &data[..MAGIC.len()] != MAGIC
+            || data[MAGIC.len()] != VERSION
+        {
+            return Err(AppError::ConfigError(format!(
+                "{} is not a jira-tui credentials file",
+                self.path.display()
+            )));
+        }
+
+        let (salt, rest) = data[header..].split_at(SALT_LEN);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher(salt)?
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| AppError::ConfigError("Wrong passphrase".to_string()))?;
+
+        String::from_utf8(plaintext)
+            .map(Some)
+            .map_err(|_| AppError::ConfigError("Corrupted credentials file".to_string()))
+    }
+
+    fn store(&self, token: &str) -> Result<()> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher(&salt)?
+            .encrypt(&nonce, token.as_bytes())
+            .map_err(|e| AppError::Unknown(format!("Encryption failed: {}", e)))?;
+
+        let mut data =
+            Vec::with_capacity(MAGIC.len() + 1 + SALT_LEN + NONCE_LEN + ciphertext.len());
+        data.extend_from_slice(MAGIC);
+        data.push(VERSION);
+        data.extend_from_slice(&salt);
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&ciphertext);
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write_private(&self.path, &data)
+    }
+}
+
+/// Writes a file readable by the current user only, tightening the mode of an existing one.
+pub(crate) fn write_private(path: &Path, data: &[u8]) -> Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // The mode above only applies to newly created files
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(data)?;
+    Ok(())
+}
+
+/// Builds the provider configured for the profile.
+///
+/// `passphrase` is only used by the encrypted file backend.
+pub fn provider_for(
+    config: &AppConfig,
+    config_path: &Path,
+    passphrase: Option<String>,
+) -> Result<Box<dyn CredentialProvider>> {
+    let credentials = &config.credentials;
+    Ok(match credentials.backend {
+        CredentialBackend::Env => Box::new(EnvCredentials::new(
+            credentials.api_token.clone(),
+            config_path.to_path_buf(),
+            config.profile_name().to_string(),
+        )),
+        CredentialBackend::Command => Box::new(CommandCredentials::new(
+            credentials.token_command.clone().unwrap_or_default(),
+            credentials.store_command.clone(),
+        )),
+        CredentialBackend::EncryptedFile => Box::new(EncryptedFileCredentials::new(
+            EncryptedFileCredentials::default_path(config.profile_name())?,
+            passphrase.unwrap_or_default(),
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("jira-tui-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn encrypted_file_round_trip() {
+        let path = temp_path("token.enc");
+        let store = EncryptedFileCredentials::new(path.clone(), "correct horse".to_string());
+        assert_eq!(store.load().unwrap(), None);
+
+        store.store("secret-token").unwrap();
+        assert!(EncryptedFileCredentials::exists(&path));
+        assert!(
+            !std::fs::read(&path)
+                .unwrap()
+                .windows(6)
+                .any(|w| w == b"secret")
+        );
+        assert_eq!(store.load().unwrap().as_deref(), Some("secret-token"));
+
+        let wrong = EncryptedFileCredentials::new(path.clone(), "battery staple".to_string());
+        let err = wrong.load().unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.to_string().contains("Wrong passphrase"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn private_files_are_readable_by_the_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = temp_path("config.toml");
+        std::fs::write(&path, "").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let store = EnvCredentials::new(None, path.clone(), "work".to_string());
+        store.store("secret-token").unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(content.contains("secret-token"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn command_backend_reads_and_writes_through_the_shell() {
+        let path = temp_path("token.txt");
+        let store = CommandCredentials::new(
+            format!("cat {}", path.display()),
+            Some(format!("cat > {}", path.display())),
+        );
+
+        store.store("from-command").unwrap();
+        assert_eq!(store.load().unwrap().as_deref(), Some("from-command"));
+        std::fs::remove_file(&path).unwrap();
+
+        let err = store.load().unwrap_err();
+        assert!(err.to_string().contains("token_command"));
+
+        let read_only = CommandCredentials::new("echo token".to_string(), None);
+        assert_eq!(read_only.load().unwrap().as_deref(), Some("token"));
+        assert!(read_only.store("x").is_err());
+    }
+}
@@ -0,0 +1,282 @@
+use crate::domain::rich_text::{Block, Inline, Mark, PanelKind, RichText};
+use serde_json::{Value, json};
+
+/// Encodes user-typed Markdown as an ADF document, ready to be sent to API v3.
+pub fn markdown_to_adf(text: &str) -> Value {
+    to_adf(&RichText::from_markdown(text))
+}
+
+/// Encodes a rich text document as an Atlassian Document Format JSON value.
+pub fn to_adf(doc: &RichText) -> Value {
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": encode_blocks(&doc.blocks),
+    })
+}
+
+fn encode_blocks(blocks: &[Block]) -> Vec<Value> {
+    blocks.iter().map(encode_block).collect()
+}
+
+fn encode_block(block: &Block) -> Value {
+    match block {
+        Block::Paragraph(content) => json!({
+            "type": "paragraph",
+            "content": encode_inlines(content),
+        }),
+        Block::Heading { level, content } => json!({
+            "type": "heading",
+            "attrs": { "level": level },
+            "content": encode_inlines(content),
+        }),
+        Block::BulletList(items) => json!({
+            "type": "bulletList",
+            "content": encode_list_items(items),
+        }),
+        Block::OrderedList { start, items } => json!({
+            "type": "orderedList",
+            "attrs": { "order": start },
+            "content": encode_list_items(items),
+        }),
+        Block::Code { language, text } => {
+            let mut node = json!({ "type": "codeBlock" });
+            if let Some(language) = language {
+                node["attrs"] = json!({ "language": language });
+            }
+            // ADF rejects empty text nodes
+            node["content"] = if text.is_empty() {
+                json!([])
+            } else {
+                json!([{ "type": "text", "text": text }])
+            };
+            node
+        }
+        Block::Blockquote(content) => json!({
+            "type": "blockquote",
+            "content": encode_blocks(content),
+        }),
+        Block::Panel { kind, content } => {
+            let panel_type = match kind {
+                PanelKind::Info => "info",
+                PanelKind::Note => "note",
+                PanelKind::Warning => "warning",
+                PanelKind::Error => "error",
+                PanelKind::Success => "success",
+            };
+            json!({
+                "type": "panel",
+                "attrs": { "panelType": panel_type },
+                "content": encode_blocks(content),
+            })
+        }
+        Block::Table(rows) => {
+            let rows: Vec<Value> = rows
+                .iter()
+                .map(|row| {
+                    let cells: Vec<Value> = row
+                        .cells
+                        .iter()
+                        .map(|cell| {
+                            json!({
+                                "type": if cell.is_header { "tableHeader" } else { "tableCell" },
+                                "content": non_empty_blocks(&cell.content),
+                            })
+                        })
+                        .collect();
+                    json!({ "type": "tableRow", "content": cells })
+                })
+                .collect();
+            json!({ "type": "table", "content": rows })
+        }
+        Block::Rule => json!({ "type": "rule" }),
+    }
+}
+
+fn encode_list_items(items: &[Vec<Block>]) -> Vec<Value> {
+    items
+        .iter()
+        .map(|item| json!({ "type": "listItem", "content": non_empty_blocks(item) }))
+        .collect()
+}
+
+/// List items and table cells must hold at least one block.
+fn non_empty_blocks(blocks: &[Block]) -> Vec<Value> {
+    if blocks.is_empty() {
+        vec![json!({ "type": "paragraph", "content": [] })]
+    } else {
+        encode_blocks(blocks)
+    }
+}
+
+fn encode_inlines(inlines: &[Inline]) -> Vec<Value> {
+    inlines.iter().filter_map(encode_inline).collect()
+}
+
+fn encode_inline(inline: &Inline) -> Option<Value> {
+    match inline {
+        Inline::Text { text, .. } if text.is_empty() => None,
+        Inline::Text { text, marks } => {
+            let mut node = json!({ "type": "text", "text": text });
+            if !marks.is_empty() {
+                node["marks"] = marks.iter().map(encode_mark).collect();
+            }
+            Some(node)
+        }
+        Inline::Mention { account_id, text } => Some(json!({
+            "type": "mention",
+            "attrs": { "id": account_id, "text": text },
+        })),
+        Inline::Emoji(shortcode) => Some(json!({
+            "type": "emoji",
+            "attrs": { "shortName": shortcode, "text": shortcode },
+        })),
+        Inline::HardBreak => Some(json!({ "type": "hardBreak" })),
+    }
+}
+
+fn encode_mark(mark: &Mark) -> Value {
+    match mark {
+        Mark::Strong => json!({ "type": "strong" }),
+        Mark::Em => json!({ "type": "em" }),
+        Mark::Code => json!({ "type": "code" }),
+        Mark::Strike => json!({ "type": "strike" }),
+        Mark::Underline => json!({ "type": "underline" }),
+        Mark::Link(href) => json!({ "type": "link", "attrs": { "href": href } }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::rich_text::{TableCell, TableRow};
+    use crate::infrastructure::jira::dtos::AdfNodeDto;
+
+    /// Encodes to ADF, decodes it back and returns the resulting document.
+    fn round_trip(doc: &RichText) -> RichText {
+        let adf = to_adf(doc);
+        let node: AdfNodeDto = serde_json::from_value(adf).expect("valid ADF");
+        node.into()
+    }
+
+    fn assert_markdown_round_trip(markdown: &str) {
+        let doc = RichText::from_markdown(markdown);
+        let decoded = round_trip(&doc);
+        assert_eq!(decoded, doc);
+        assert_eq!(decoded.to_markdown(), markdown);
+    }
+
+    #[test]
+    fn paragraphs_and_hard_breaks() {
+        assert_markdown_round_trip("First line\nsecond line\n\nAnother paragraph");
+    }
+
+    #[test]
+    fn inline_marks_and_links() {
+        assert_markdown_round_trip(
+            "Some **bold**, *italic*, `code`, ~~gone~~ and a [link](https://example.com)",
+        );
+        assert_markdown_round_trip("**[bold link](https://example.com)**");
+    }
+
+    #[test]
+    fn mentions() {
+        assert_markdown_round_trip("ping [@Ana Lopez](mention:abc-123) please");
+        assert_eq!(
+            RichText::from_markdown("[@Ana](mention:abc-123)").blocks,
+            vec![Block::Paragraph(vec![Inline::Mention {
+                account_id: "abc-123".to_string(),
+                text: "@Ana".to_string(),
+            }])]
+        );
+    }
+
+    #[test]
+    fn headings() {
+        assert_markdown_round_trip("# Title\n\n### Section\n\nBody");
+    }
+
+    #[test]
+    fn nested_lists() {
+        assert_markdown_round_trip("- one\n- two\n  - nested\n  - items\n- three");
+        assert_markdown_round_trip("3. third\n4. fourth\n   - mixed");
+        // Non-ASCII whitespace is not indentation
+        let doc = RichText::from_markdown("- a\n\u{3000}b");
+        assert!(matches!(
+            doc.blocks.as_slice(),
+            [Block::BulletList(_), Block::Paragraph(_)]
+        ));
+    }
+
+    #[test]
+    fn code_blocks_keep_whitespace() {
+        assert_markdown_round_trip("```rust\nfn main() {\n    println!(\"*hi*\");\n}\n```");
+        assert_markdown_round_trip("```\nplain\n```");
+    }
+
+    #[test]
+    fn quotes_tables_and_rules() {
+        assert_markdown_round_trip("> quoted **text**\n>\n> second");
+        assert_markdown_round_trip("| Name | Value |\n|---|---|\n| a | `b` |");
+        assert_markdown_round_trip("above\n\n---\n\nbelow");
+    }
+
+    #[test]
+    fn special_characters_are_not_markup() {
+        let doc = RichText::from_markdown("2 * 3 = 6 and \\*literal\\* with \"quotes\"");
+        assert_eq!(
+            doc.blocks,
+            vec![Block::Paragraph(vec![Inline::text(
+                "2 * 3 = 6 and *literal* with \"quotes\""
+            )])]
+        );
+        assert_eq!(round_trip(&doc), doc);
+    }
+
+    #[test]
+    fn non_markdown_nodes_survive_round_trip() {
+        let doc = RichText {
+            blocks: vec![Block::Panel {
+                kind: PanelKind::Warning,
+                content: vec![Block::Paragraph(vec![
+                    Inline::Mention {
+                        account_id: "abc-123".to_string(),
+                        text: "@Ana".to_string(),
+                    },
+                    Inline::text(" please check "),
+                    Inline::Emoji(":smile:".to_string()),
+                ])],
+            }],
+        };
+        assert_eq!(round_trip(&doc), doc);
+    }
+
+    #[test]
+    fn empty_containers_get_a_placeholder_paragraph() {
+        let doc = RichText {
+            blocks: vec![Block::Table(vec![TableRow {
+                cells: vec![TableCell {
+                    is_header: false,
+                    content: vec![],
+                }],
+            }])],
+        };
+        let adf = to_adf(&doc);
+        assert_eq!(
+            adf["content"][0]["content"][0]["content"][0]["content"][0]["type"],
+            "paragraph"
+        );
+    }
+
+    #[test]
+    fn list_items_are_wrapped_in_paragraphs() {
+        let adf = markdown_to_adf("- item");
+        assert_eq!(adf["content"][0]["type"], "bulletList");
+        assert_eq!(adf["content"][0]["content"][0]["type"], "listItem");
+        assert_eq!(
+            adf["content"][0]["content"][0]["content"][0]["type"],
+            "paragraph"
+        );
+        assert_eq!(adf["version"], 1);
+    }
+}
@@ -1,12 +1,28 @@
 use crate::domain::errors::{AppError, Result};
-use crate::domain::models::{Board, BoardId, Issue, IssueFilter, Paginated, Worklog, WorklogEntry};
+use crate::domain::models::{
+    Attachment, Board, BoardConfiguration, BoardFilter, BoardId, ChangelogEntry, Comment,
+    FieldKind, FieldMeta, FieldValue, Issue, IssueFilter, IssueLinkType, IssueType, LinkDirection,
+    NewIssue, PageCursor, Paginated, SavedQuery, Sprint, SprintId, SprintState, Transition, User,
+    Votes, Watchers, Worklog, WorklogEntry,
+};
 use crate::domain::repositories::JiraRepository;
-use crate::infrastructure::config::JiraConfig;
+use crate::infrastructure::config::{AuthMethod, HttpConfig, JiraConfig, JiraDeployment};
 use crate::infrastructure::jira::dtos::{
-    BoardResponseDto, IssueSearchResponseDto, WorklogResponseDto,
+    AttachmentDto, BoardConfigurationDto, BoardResponseDto, ChangelogPageDto, CommentDto,
+    CommentsResponseDto, CreateMetaFieldsDto, CreateMetaIssueTypesDto, CreatedIssueDto,
+    EditMetaDto, ErrorResponseDto, ExpandedChangelogDto, FieldDto, FilterDto, IssueDto,
+    IssueLinkTypesResponseDto, IssueSearchResponseDto, JqlSearchResponseDto, SprintResponseDto,
+    TransitionsResponseDto, UserDto, VotesDto, WatchesDto, WorklogResponseDto,
 };
+use crate::infrastructure::jira::{adf, retry, wiki};
 use async_trait::async_trait;
-use reqwest::{Client, StatusCode};
+use futures::stream::BoxStream;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::OnceCell;
 
 /// Converts seconds to Jira time format (e.g., "1h 30m", "2h", "45m")
 fn format_time_spent(seconds: u64) -> String {
@@ -21,11 +37,72 @@ fn format_time_spent(seconds: u64) -> String {
     }
 }
 
+/// Turns a failed response into the matching [`AppError`], keeping Jira's explanation.
+async fn error_from_response(response: Response) -> AppError {
+    let status = response.status();
+    let retry_after = retry::retry_after(response.headers());
+    let body = response.text().await.unwrap_or_default();
+    api_error(status, retry_after, &body)
+}
+
+fn api_error(status: StatusCode, retry_after: Option<Duration>, body: &str) -> AppError {
+    let dto: ErrorResponseDto = serde_json::from_str(body).unwrap_or_default();
+    let mut messages = dto.error_messages;
+    messages.extend(dto.message);
+
+    // Single-line summary for the variants that carry one message
+    let summary = if messages.is_empty() && dto.errors.is_empty() {
+        status
+            .canonical_reason()
+            .unwrap_or("Unexpected response")
+            .to_string()
+    } else {
+        messages
+            .iter()
+            .chain(dto.errors.values())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+
+    match status {
+        StatusCode::BAD_REQUEST => AppError::Validation {
+            messages,
+            field_errors: dto.errors,
+        },
+        StatusCode::UNAUTHORIZED => AppError::Unauthorized,
+        StatusCode::FORBIDDEN => AppError::Forbidden(summary),
+        StatusCode::NOT_FOUND => AppError::NotFound(summary),
+        StatusCode::CONFLICT => AppError::Conflict(summary),
+        StatusCode::TOO_MANY_REQUESTS => AppError::RateLimited {
+            retry_after_secs: retry_after.map(|wait| wait.as_secs()),
+        },
+        status if status.is_server_error() => AppError::ServerError {
+            status: status.as_u16(),
+            message: summary,
+        },
+        status => AppError::ApiError(format!("{}: {}", status, summary)),
+    }
+}
+
+/// Issue fields requested from the search endpoints.
+const ISSUE_FIELDS: &str = "summary,description,status,priority,assignee,created,updated,\
+    issuetype,parent,subtasks,issuelinks,attachment,labels,watches,votes";
+
 pub struct JiraClient {
     client: Client,
     base_url: String,
-    email: String,
+    deployment: JiraDeployment,
+    auth: AuthMethod,
+    email: Option<String>,
     api_token: String,
+    http: HttpConfig,
+    /// Set once the enhanced `/search/jql` endpoint turns out to be unavailable
+    /// (Jira Server / Data Center), so later searches go straight to `/search`.
+    legacy_search: AtomicBool,
+    /// Custom fields named in the configuration, and their metadata once resolved.
+    custom_field_names: Vec<String>,
+    custom_fields: OnceCell<Vec<FieldMeta>>,
 }
 
 impl JiraClient {
@@ -38,27 +115,328 @@ impl JiraClient {
 
         let client = Client::builder()
             .default_headers(headers)
+            .connect_timeout(Duration::from_secs(config.http.timeout_secs))
             .build()
             .map_err(|e| AppError::ConfigError(e.to_string()))?;
 
         Ok(Self {
             client,
             base_url: config.base_url.trim_end_matches('/').to_string(),
+            deployment: config.deployment,
+            auth: config.auth,
             email: config.email,
             api_token: config.api_token,
+            http: config.http,
+            legacy_search: AtomicBool::new(config.deployment == JiraDeployment::Server),
+            custom_field_names: config.custom_fields,
+            custom_fields: OnceCell::new(),
         })
     }
+
+    /// Builds the URL of a core REST API resource for the configured API version.
+    fn api_url(&self, path: &str) -> String {
+        format!(
+            "{}/rest/api/{}/{}",
+            self.base_url,
+            self.deployment.api_version(),
+            path
+        )
+    }
+
+    /// Starts an authenticated request (basic auth or Bearer Personal Access Token).
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.authenticate(
+            self.client
+                .request(method, url)
+                .timeout(Duration::from_secs(self.http.timeout_secs)),
+        )
+    }
+
+    /// Starts an authenticated file transfer. Only connecting is timed out: a large file may
+    /// take longer than any API call.
+    fn transfer_request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.authenticate(self.client.request(method, url))
+    }
+
+    fn authenticate(&self, builder: RequestBuilder) -> RequestBuilder {
+        match self.auth {
+            AuthMethod::Basic => builder.basic_auth(
+                self.email.as_deref().unwrap_or_default(),
+                Some(&self.api_token),
+            ),
+            AuthMethod::Pat => builder.bearer_auth(&self.api_token),
+        }
+    }
+
+    /// Sends a request built with [`Self::request`], retrying transient failures.
+    async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        retry::execute(&self.client, request.build()?, &self.http).await
+    }
+
+    /// Encodes user-typed Markdown in the rich text format of the deployment.
+    fn rich_text_body(&self, markdown: &str) -> serde_json::Value {
+        match self.deployment {
+            JiraDeployment::Cloud => adf::markdown_to_adf(markdown),
+            JiraDeployment::Server => serde_json::Value::String(wiki::markdown_to_wiki(markdown)),
+        }
+    }
+
+    /// Builds the JSON body shared by worklog creation and update.
+    fn worklog_payload(&self, worklog: &Worklog) -> serde_json::Value {
+        let started = worklog
+            .started_at
+            .format("%Y-%m-%dT%H:%M:%S%.3f%z")
+            .to_string();
+
+        let mut payload = serde_json::json!({
+            "timeSpentSeconds": worklog.time_spent_seconds,
+            "started": started,
+        });
+
+        if let Some(comment_text) = &worklog.comment
+            && !comment_text.trim().is_empty()
+        {
+            payload["comment"] = self.rich_text_body(comment_text);
+        }
+
+        payload
+    }
+
+    /// Encodes a field value the way the issue create and edit endpoints expect it.
+    fn field_payload(&self, meta: &FieldMeta, value: &FieldValue) -> serde_json::Value {
+        use serde_json::json;
+
+        match value {
+            FieldValue::Text(text) => json!(text),
+            FieldValue::RichText(markdown) => self.rich_text_body(markdown),
+            FieldValue::Number(number) => json!(number),
+            FieldValue::Choice(id) => json!({ "id": id }),
+            FieldValue::Option(option) => json!({ "id": option.id }),
+            FieldValue::Choices(ids) => {
+                json!(ids.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>())
+            }
+            FieldValue::Options(options) => json!(
+                options
+                    .iter()
+                    .map(|option| json!({ "id": option.id }))
+                    .collect::<Vec<_>>()
+            ),
+            FieldValue::Labels(labels) => json!(labels),
+            FieldValue::User(user) => self.user_payload(user),
+            FieldValue::Account(user) => self.user_payload(&user.account_id),
+            FieldValue::Date(date) => json!(date.format("%Y-%m-%d").to_string()),
+            // The Epic Link custom field takes the bare key, `parent` an object
+            FieldValue::IssueKey(key) if meta.id == "parent" => json!({ "key": key }),
+            FieldValue::IssueKey(key) => json!(key),
+        }
+    }
+
+    /// Refers to a user by account ID on Jira Cloud, by username on Jira Server.
+    fn user_payload(&self, user: &str) -> serde_json::Value {
+        match self.deployment {
+            JiraDeployment::Cloud => serde_json::json!({ "accountId": user }),
+            JiraDeployment::Server => serde_json::json!({ "name": user }),
+        }
+    }
+
+    /// Encodes an emptied field: lists become empty, anything else is cleared with `null`.
+    fn cleared_field_payload(meta: &FieldMeta) -> serde_json::Value {
+        match meta.kind {
+            FieldKind::Labels | FieldKind::MultiChoice => serde_json::json!([]),
+            _ => serde_json::Value::Null,
+        }
+    }
+
+    /// The configured custom fields, looked up by name or ID in the field list on first use.
+    /// Names that match no field are logged once and left out, so a typo or a field renamed in
+    /// Jira does not stop the issues from loading.
+    async fn resolved_custom_fields(&self) -> Result<&[FieldMeta]> {
+        if self.custom_field_names.is_empty() {
+            return Ok(&[]);
+        }
+        let fields = self
+            .custom_fields
+            .get_or_try_init(|| async {
+                let all = self.get_fields().await?;
+                let resolved = self
+                    .custom_field_names
+                    .iter()
+                    .filter_map(|wanted| {
+                        let field = all
+                            .iter()
+                            .find(|f| f.id == *wanted || f.name.eq_ignore_ascii_case(wanted));
+                        if field.is_none() {
+                            log::warn!("Unknown custom field '{}' in the configuration", wanted);
+                        }
+                        field.cloned()
+                    })
+                    .collect();
+                Ok::<_, AppError>(resolved)
+            })
+            .await?;
+        Ok(fields)
+    }
+
+    /// Every system and custom field of the instance.
+    async fn get_fields(&self) -> Result<Vec<FieldMeta>> {
+        let url = self.api_url("field");
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get fields: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: Vec<FieldDto> = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse fields: {}", e)))?;
+                Ok(dto.into_iter().map(Into::into).collect())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    /// The `fields` parameter of issue requests: the fields shown by the app and `custom_fields`.
+    fn issue_fields(custom_fields: &[FieldMeta]) -> String {
+        let mut fields = ISSUE_FIELDS.to_string();
+        for field in custom_fields {
+            fields.push(',');
+            fields.push_str(&field.id);
+        }
+        fields
+    }
+
+    /// Runs a search against the enhanced Cloud endpoint, paging with `nextPageToken`.
+    ///
+    /// Returns `Ok(None)` when the endpoint does not exist on this deployment.
+    async fn search_issues_by_token(
+        &self,
+        jql: &str,
+        cursor: &PageCursor,
+        max_results: u64,
+    ) -> Result<Option<Paginated<Issue>>> {
+        let url = self.api_url("search/jql");
+        let custom_fields = self.resolved_custom_fields().await?;
+
+        let mut query = vec![
+            ("jql", jql.to_string()),
+            ("maxResults", max_results.to_string()),
+            ("fields", Self::issue_fields(custom_fields)),
+        ];
+        if let Some(token) = cursor.as_token() {
+            query.push(("nextPageToken", token.to_string()));
+        }
+
+        let request = self.request(Method::GET, &url).query(&query);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to search issues: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: JqlSearchResponseDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse issues: {}", e)))?;
+
+                let issues: Vec<Issue> = dto
+                    .issues
+                    .into_iter()
+                    .map(|issue| issue.into_issue(custom_fields))
+                    .collect();
+                let next_page_token = if dto.is_last.unwrap_or(false) {
+                    None
+                } else {
+                    dto.next_page_token
+                };
+
+                Ok(Some(Paginated::with_token(
+                    issues,
+                    cursor.as_token().is_none(),
+                    next_page_token,
+                )))
+            }
+            StatusCode::NOT_FOUND | StatusCode::GONE => Ok(None),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    /// Runs a search against the offset-based endpoint still used by Jira Server.
+    async fn search_issues_by_offset(
+        &self,
+        jql: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<Issue>> {
+        let url = self.api_url("search");
+        let custom_fields = self.resolved_custom_fields().await?;
+
+        let request = self.request(Method::GET, &url).query(&[
+            ("jql", jql.to_string()),
+            ("startAt", start_at.to_string()),
+            ("maxResults", max_results.to_string()),
+            ("fields", Self::issue_fields(custom_fields)),
+        ]);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to search issues: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: IssueSearchResponseDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse issues: {}", e)))?;
+
+                let issues: Vec<Issue> = dto
+                    .issues
+                    .into_iter()
+                    .map(|issue| issue.into_issue(custom_fields))
+                    .collect();
+
+                Ok(Paginated::new(issues, dto.total, dto.start_at))
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
 }
 
 #[async_trait]
 impl JiraRepository for JiraClient {
-    async fn get_boards(&self) -> Result<Vec<Board>> {
-        let url = format!("{}/rest/agile/1.0/board?maxResults=100", self.base_url);
+    async fn get_boards(
+        &self,
+        filter: BoardFilter,
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Board>> {
+        let url = format!("{}/rest/agile/1.0/board", self.base_url);
+        let start_at = cursor.as_offset().unwrap_or(0);
+
+        let mut query = vec![
+            ("startAt", start_at.to_string()),
+            ("maxResults", max_results.to_string()),
+        ];
+        if let Some(name) = &filter.name {
+            query.push(("name", name.clone()));
+        }
+        if let Some(project) = &filter.project_key {
+            query.push(("projectKeyOrId", project.clone()));
+        }
+        if let Some(board_type) = filter.board_type.to_query() {
+            query.push(("type", board_type.to_string()));
+        }
+
+        let request = self.request(Method::GET, &url).query(&query);
         let response = self
-            .client
-            .get(&url)
-            .basic_auth(&self.email, Some(&self.api_token))
-            .send()
+            .send(request)
             .await
             .map_err(|e| AppError::ApiError(e.to_string()))?;
 
@@ -67,54 +445,65 @@ impl JiraRepository for JiraClient {
                 .json()
                 .await
                 .map_err(|e| AppError::ApiError(format!("Parse error: {}", e)))?;
-            Ok(dto.values.into_iter().map(Into::into).collect())
+            // Older Server versions omit `isLast`: a short page is the last one
+            let is_last = dto
+                .is_last
+                .unwrap_or((dto.values.len() as u64) < dto.max_results);
+            let boards = dto.values.into_iter().map(Into::into).collect();
+            Ok(Paginated::with_is_last(boards, dto.start_at, is_last))
         } else {
-            Err(AppError::ApiError(format!("Error: {}", response.status())))
+            Err(error_from_response(response).await)
+        }
+    }
+
+    async fn get_board_configuration(&self, board_id: BoardId) -> Result<BoardConfiguration> {
+        let url = format!(
+            "{}/rest/agile/1.0/board/{}/configuration",
+            self.base_url, board_id
+        );
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get board configuration: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: BoardConfigurationDto = response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse board configuration: {}", e))
+                })?;
+                Ok(dto.into())
+            }
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Board {} not found", board_id)))
+            }
+            _ => Err(error_from_response(response).await),
         }
     }
 
     async fn get_issues_by_board(
         &self,
         board_id: BoardId,
-        start_at: u64,
+        cursor: PageCursor,
         max_results: u64,
         filter: IssueFilter,
     ) -> Result<Paginated<Issue>> {
-        let mut jql_parts = Vec::new();
-        if let Some(assignee) = &filter.assignee {
-            jql_parts.push(format!("assignee = {}", assignee));
-        }
-        if let Some(status) = &filter.status {
-            jql_parts.push(format!("status = \"{}\"", status));
-        }
-        let jql_query = if jql_parts.is_empty() {
-            String::new()
-        } else {
-            jql_parts.join(" AND ")
-        };
-
-        let final_jql = if let Some(order) = &filter.order_by {
-            if jql_query.is_empty() {
-                format!("ORDER BY {}", order)
-            } else {
-                format!("{} ORDER BY {}", jql_query, order)
-            }
-        } else {
-            jql_query
-        };
+        let final_jql = filter.to_jql();
+        // The Agile API only supports offset pagination
+        let start_at = cursor.as_offset().unwrap_or(0);
 
         let url = format!("{}/rest/agile/1.0/board/{}/issue", self.base_url, board_id);
+        // The Agile API returns every field, custom ones included
+        let custom_fields = self.resolved_custom_fields().await?;
 
+        let request = self.request(Method::GET, &url).query(&[
+            ("startAt", start_at.to_string()),
+            ("maxResults", max_results.to_string()),
+            ("jql", final_jql),
+        ]);
         let response = self
-            .client
-            .get(&url)
-            .basic_auth(&self.email, Some(&self.api_token))
-            .query(&[
-                ("startAt", start_at.to_string()),
-                ("maxResults", max_results.to_string()),
-                ("jql", final_jql),
-            ])
-            .send()
+            .send(request)
             .await
             .map_err(|e| AppError::ApiError(e.to_string()))?;
 
@@ -125,227 +514,2231 @@ impl JiraRepository for JiraClient {
                     .await
                     .map_err(|e| AppError::ApiError(format!("Failed to parse issues: {}", e)))?;
 
-                let issues: Vec<Issue> = dto.issues.into_iter().map(Into::into).collect();
+                let issues: Vec<Issue> = dto
+                    .issues
+                    .into_iter()
+                    .map(|issue| issue.into_issue(custom_fields))
+                    .collect();
 
-                Ok(Paginated::new(
-                    issues,
-                    dto.total,
-                    dto.start_at,
-                    dto.max_results,
-                ))
+                Ok(Paginated::new(issues, dto.total, dto.start_at))
             }
             StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
             StatusCode::NOT_FOUND => {
                 Err(AppError::NotFound(format!("Board {} not found", board_id)))
             }
-            _ => Err(AppError::ApiError(format!(
-                "Jira API Error: {}",
-                response.status()
-            ))),
+            _ => Err(error_from_response(response).await),
         }
     }
 
-    async fn add_worklog(&self, worklog: Worklog) -> Result<()> {
-        let url = format!(
-            "{}/rest/api/3/issue/{}/worklog",
-            self.base_url, worklog.issue_key
-        );
-
-        let started = worklog
-            .started_at
-            .format("%Y-%m-%dT%H:%M:%S%.3f%z")
-            .to_string();
-
-        let mut payload = serde_json::json!({
-            "timeSpentSeconds": worklog.time_spent_seconds,
-            "started": started,
-        });
-
-        if let Some(comment_text) = worklog.comment
-            && !comment_text.is_empty()
-        {
-            payload["comment"] = serde_json::json!({
-                "type": "doc",
-                "version": 1,
-                "content": [{
-                    "type": "paragraph",
-                    "content": [{
-                        "type": "text",
-                        "text": comment_text
-                    }]
-                }]
-            });
-        }
-
-        log::debug!("Worklog URL: {}", url);
-        log::debug!(
-            "Worklog payload: {}",
-            serde_json::to_string_pretty(&payload).unwrap()
-        );
+    async fn get_sprints(
+        &self,
+        board_id: BoardId,
+        states: &[SprintState],
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Sprint>> {
+        let url = format!("{}/rest/agile/1.0/board/{}/sprint", self.base_url, board_id);
+        let start_at = cursor.as_offset().unwrap_or(0);
+        let states: Vec<&str> = states.iter().map(SprintState::to_query).collect();
 
+        let request = self.request(Method::GET, &url).query(&[
+            ("startAt", start_at.to_string()),
+            ("maxResults", max_results.to_string()),
+            ("state", states.join(",")),
+        ]);
         let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.email, Some(&self.api_token))
-            .json(&payload)
-            .send()
+            .send(request)
             .await
-            .map_err(|e| AppError::ApiError(format!("Failed to add worklog: {}", e)))?;
+            .map_err(|e| AppError::ApiError(format!("Failed to get sprints: {}", e)))?;
 
         match response.status() {
-            StatusCode::CREATED | StatusCode::OK => Ok(()),
-            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
-            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
-                "Issue {} not found",
-                worklog.issue_key
-            ))),
-            _ => Err(AppError::ApiError(format!(
-                "Failed to add worklog: {}",
-                response.status()
-            ))),
+            StatusCode::OK => {
+                let dto: SprintResponseDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse sprints: {}", e)))?;
+                let is_last = dto
+                    .is_last
+                    .unwrap_or((dto.values.len() as u64) < dto.max_results);
+                let sprints = dto.values.into_iter().map(Into::into).collect();
+                Ok(Paginated::with_is_last(sprints, dto.start_at, is_last))
+            }
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Board {} not found", board_id)))
+            }
+            _ => Err(error_from_response(response).await),
         }
     }
 
-    async fn get_worklogs(
+    async fn get_sprint_issues(
         &self,
-        issue_key: &str,
-        start_at: u64,
+        sprint_id: SprintId,
+        cursor: PageCursor,
         max_results: u64,
-    ) -> Result<Paginated<WorklogEntry>> {
-        let url = format!("{}/rest/api/3/issue/{}/worklog", self.base_url, issue_key);
+        filter: IssueFilter,
+    ) -> Result<Paginated<Issue>> {
+        let url = format!(
+            "{}/rest/agile/1.0/sprint/{}/issue",
+            self.base_url, sprint_id
+        );
+        let start_at = cursor.as_offset().unwrap_or(0);
+        let custom_fields = self.resolved_custom_fields().await?;
 
+        let request = self.request(Method::GET, &url).query(&[
+            ("startAt", start_at.to_string()),
+            ("maxResults", max_results.to_string()),
+            ("jql", filter.to_jql()),
+        ]);
         let response = self
-            .client
-            .get(&url)
-            .basic_auth(&self.email, Some(&self.api_token))
-            .query(&[
-                ("startAt", start_at.to_string()),
-                ("maxResults", max_results.to_string()),
-            ])
-            .send()
+            .send(request)
             .await
-            .map_err(|e| AppError::ApiError(format!("Failed to get worklogs: {}", e)))?;
+            .map_err(|e| AppError::ApiError(e.to_string()))?;
 
         match response.status() {
             StatusCode::OK => {
-                let dto: WorklogResponseDto = response
+                let dto: IssueSearchResponseDto = response
                     .json()
                     .await
-                    .map_err(|e| AppError::ApiError(format!("Failed to parse worklogs: {}", e)))?;
-
-                let worklogs: Vec<WorklogEntry> = dto
-                    .worklogs
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse issues: {}", e)))?;
+                let issues: Vec<Issue> = dto
+                    .issues
                     .into_iter()
-                    .map(|w| w.to_worklog_entry(issue_key.to_string()))
+                    .map(|issue| issue.into_issue(custom_fields))
                     .collect();
-
-                Ok(Paginated::new(
-                    worklogs,
-                    dto.total,
-                    dto.start_at,
-                    dto.max_results,
-                ))
-            }
-            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
-            StatusCode::NOT_FOUND => {
-                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+                Ok(Paginated::new(issues, dto.total, dto.start_at))
             }
-            _ => Err(AppError::ApiError(format!(
-                "Failed to get worklogs: {}",
-                response.status()
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Sprint {} not found",
+                sprint_id
             ))),
+            _ => Err(error_from_response(response).await),
         }
     }
 
-    async fn update_worklog(
+    async fn move_issues_to_sprint(
         &self,
-        issue_key: &str,
-        worklog_id: &str,
-        worklog: Worklog,
+        sprint_id: SprintId,
+        issue_keys: &[String],
     ) -> Result<()> {
         let url = format!(
-            "{}/rest/api/3/issue/{}/worklog/{}",
-            self.base_url, issue_key, worklog_id
+            "{}/rest/agile/1.0/sprint/{}/issue",
+            self.base_url, sprint_id
         );
+        let payload = serde_json::json!({ "issues": issue_keys });
 
-        let started = worklog
-            .started_at
-            .format("%Y-%m-%dT%H:%M:%S%.3f%z")
-            .to_string();
-
-        let mut payload = serde_json::json!({
-            "timeSpentSeconds": worklog.time_spent_seconds,
-            "started": started,
-        });
-
-        if let Some(comment_text) = worklog.comment
-            && !comment_text.is_empty()
-        {
-            payload["comment"] = serde_json::json!({
-                "type": "doc",
-                "version": 1,
-                "content": [{
-                    "type": "paragraph",
-                    "content": [{
-                        "type": "text",
-                        "text": comment_text
-                    }]
-                }]
-            });
-        }
-
+        let request = self.request(Method::POST, &url).json(&payload);
         let response = self
-            .client
-            .put(&url)
-            .basic_auth(&self.email, Some(&self.api_token))
-            .json(&payload)
-            .send()
+            .send(request)
             .await
-            .map_err(|e| AppError::ApiError(format!("Failed to update worklog: {}", e)))?;
+            .map_err(|e| AppError::ApiError(format!("Failed to move issues: {}", e)))?;
 
         match response.status() {
-            StatusCode::OK => Ok(()),
-            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
             StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
-                "Worklog {} not found",
-                worklog_id
-            ))),
-            _ => Err(AppError::ApiError(format!(
-                "Failed to update worklog: {}",
-                response.status()
+                "Sprint {} not found",
+                sprint_id
             ))),
+            _ => Err(error_from_response(response).await),
         }
     }
 
-    async fn delete_worklog(&self, issue_key: &str, worklog_id: &str) -> Result<()> {
-        let url = format!(
-            "{}/rest/api/3/issue/{}/worklog/{}",
-            self.base_url, issue_key, worklog_id
-        );
+    async fn move_issues_to_backlog(&self, issue_keys: &[String]) -> Result<()> {
+        let url = format!("{}/rest/agile/1.0/backlog/issue", self.base_url);
+        let payload = serde_json::json!({ "issues": issue_keys });
 
+        let request = self.request(Method::POST, &url).json(&payload);
         let response = self
-            .client
-            .delete(&url)
-            .basic_auth(&self.email, Some(&self.api_token))
-            .send()
+            .send(request)
             .await
-            .map_err(|e| AppError::ApiError(format!("Failed to delete worklog: {}", e)))?;
+            .map_err(|e| AppError::ApiError(format!("Failed to move issues: {}", e)))?;
 
         match response.status() {
             StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
-            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
-            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
-                "Worklog {} not found",
-                worklog_id
-            ))),
-            _ => Err(AppError::ApiError(format!(
-                "Failed to delete worklog: {}",
-                response.status()
-            ))),
+            _ => Err(error_from_response(response).await),
         }
     }
 
-    async fn transition_issue(&self, _issue_key: &str, _transition_id: &str) -> Result<()> {
-        Err(AppError::Unknown("Not implemented yet".to_string()))
+    async fn get_issue(&self, issue_key: &str) -> Result<Issue> {
+        let url = self.api_url(&format!("issue/{}", issue_key));
+
+        let custom_fields = self.resolved_custom_fields().await?;
+
+        let request = self
+            .request(Method::GET, &url)
+            .query(&[("fields", Self::issue_fields(custom_fields))]);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get issue: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: IssueDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse issue: {}", e)))?;
+                Ok(dto.into_issue(custom_fields))
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_create_issue_types(&self, project_key: &str) -> Result<Vec<IssueType>> {
+        let url = self.api_url(&format!("issue/createmeta/{}/issuetypes", project_key));
+        let mut issue_types = Vec::new();
+        let mut start_at = 0;
+
+        loop {
+            let request = self
+                .request(Method::GET, &url)
+                .query(&[("startAt", start_at.to_string())]);
+            let response = self
+                .send(request)
+                .await
+                .map_err(|e| AppError::ApiError(format!("Failed to get issue types: {}", e)))?;
+
+            let dto: CreateMetaIssueTypesDto = match response.status() {
+                StatusCode::OK => response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse issue types: {}", e))
+                })?,
+                StatusCode::UNAUTHORIZED => return Err(AppError::Unauthorized),
+                StatusCode::NOT_FOUND => {
+                    return Err(AppError::NotFound(format!(
+                        "Project {} not found",
+                        project_key
+                    )));
+                }
+                _ => return Err(error_from_response(response).await),
+            };
+
+            let next = dto.page.next_start(dto.issue_types.len());
+            issue_types.extend(dto.issue_types.into_iter().map(IssueType::from));
+            match next {
+                Some(next) => start_at = next,
+                None => return Ok(issue_types),
+            }
+        }
+    }
+
+    async fn get_create_fields(
+        &self,
+        project_key: &str,
+        issue_type_id: &str,
+    ) -> Result<Vec<FieldMeta>> {
+        let url = self.api_url(&format!(
+            "issue/createmeta/{}/issuetypes/{}",
+            project_key, issue_type_id
+        ));
+        let mut fields = Vec::new();
+        let mut start_at = 0;
+
+        loop {
+            let request = self
+                .request(Method::GET, &url)
+                .query(&[("startAt", start_at.to_string())]);
+            let response = self
+                .send(request)
+                .await
+                .map_err(|e| AppError::ApiError(format!("Failed to get create fields: {}", e)))?;
+
+            let dto: CreateMetaFieldsDto = match response.status() {
+                StatusCode::OK => response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse create fields: {}", e))
+                })?,
+                StatusCode::UNAUTHORIZED => return Err(AppError::Unauthorized),
+                StatusCode::NOT_FOUND => {
+                    return Err(AppError::NotFound(format!(
+                        "Issue type {} not found in {}",
+                        issue_type_id, project_key
+                    )));
+                }
+                _ => return Err(error_from_response(response).await),
+            };
+
+            let next = dto.page.next_start(dto.fields.len());
+            fields.extend(dto.fields.into_iter().map(FieldMeta::from));
+            match next {
+                Some(next) => start_at = next,
+                None => return Ok(fields),
+            }
+        }
+    }
+
+    async fn create_issue(&self, issue: NewIssue) -> Result<Issue> {
+        let url = self.api_url("issue");
+
+        let mut fields = serde_json::json!({
+            "project": { "key": issue.project_key },
+            "issuetype": { "id": issue.issue_type_id },
+        });
+        for (meta, value) in &issue.fields {
+            fields[meta.id.as_str()] = self.field_payload(meta, value);
+        }
+        let payload = serde_json::json!({ "fields": fields });
+
+        let request = self.request(Method::POST, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to create issue: {}", e)))?;
+
+        match response.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                let created: CreatedIssueDto = response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse created issue: {}", e))
+                })?;
+                // The issue exists now: failing to read it back is not a failed creation
+                match self.get_issue(&created.key).await {
+                    Ok(issue) => Ok(issue),
+                    Err(e) => {
+                        log::warn!("Could not read back {}: {}", created.key, e);
+                        Ok(issue.into_created(created.key))
+                    }
+                }
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_edit_fields(&self, issue_key: &str) -> Result<Vec<FieldMeta>> {
+        let url = self.api_url(&format!("issue/{}/editmeta", issue_key));
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get edit fields: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: EditMetaDto = response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse edit fields: {}", e))
+                })?;
+                Ok(dto.into_fields())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn update_issue(
+        &self,
+        issue_key: &str,
+        changes: Vec<(FieldMeta, Option<FieldValue>)>,
+    ) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}", issue_key));
+
+        let mut fields = serde_json::json!({});
+        for (meta, value) in &changes {
+            fields[meta.id.as_str()] = match value {
+                Some(value) => self.field_payload(meta, value),
+                None => Self::cleared_field_payload(meta),
+            };
+        }
+        let payload = serde_json::json!({ "fields": fields });
+
+        let request = self.request(Method::PUT, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to update issue: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn search_issues(
+        &self,
+        jql: &str,
+        cursor: PageCursor,
+        max_results: u64,
+    ) -> Result<Paginated<Issue>> {
+        if let PageCursor::Offset(start_at) = cursor {
+            return self
+                .search_issues_by_offset(jql, start_at, max_results)
+                .await;
+        }
+
+        if !self.legacy_search.load(Ordering::Relaxed) {
+            if let Some(page) = self
+                .search_issues_by_token(jql, &cursor, max_results)
+                .await?
+            {
+                return Ok(page);
+            }
+            log::info!("Enhanced JQL search unavailable, falling back to offset search");
+            self.legacy_search.store(true, Ordering::Relaxed);
+        }
+
+        self.search_issues_by_offset(jql, 0, max_results).await
+    }
+
+    async fn get_my_filters(&self) -> Result<Vec<SavedQuery>> {
+        let url = self.api_url(match self.deployment {
+            // `filter/my` only exists on Cloud
+            JiraDeployment::Cloud => "filter/my",
+            JiraDeployment::Server => "filter/favourite",
+        });
+
+        let request = self
+            .request(Method::GET, &url)
+            .query(&[("includeFavourites", "true")]);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get filters: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: Vec<FilterDto> = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse filters: {}", e)))?;
+                Ok(dto.into_iter().map(Into::into).collect())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn add_worklog(&self, worklog: Worklog) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/worklog", worklog.issue_key));
+
+        let payload = self.worklog_payload(&worklog);
+
+        log::debug!("Worklog URL: {}", url);
+        log::debug!(
+            "Worklog payload: {}",
+            serde_json::to_string_pretty(&payload).unwrap()
+        );
+
+        let request = self.request(Method::POST, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to add worklog: {}", e)))?;
+
+        match response.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Issue {} not found",
+                worklog.issue_key
+            ))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_worklogs(
+        &self,
+        issue_key: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<WorklogEntry>> {
+        let url = self.api_url(&format!("issue/{}/worklog", issue_key));
+
+        let request = self.request(Method::GET, &url).query(&[
+            ("startAt", start_at.to_string()),
+            ("maxResults", max_results.to_string()),
+        ]);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get worklogs: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: WorklogResponseDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse worklogs: {}", e)))?;
+
+                let worklogs: Vec<WorklogEntry> = dto
+                    .worklogs
+                    .into_iter()
+                    .map(|w| w.to_worklog_entry(issue_key.to_string()))
+                    .collect();
+
+                Ok(Paginated::new(worklogs, dto.total, dto.start_at))
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn update_worklog(
+        &self,
+        issue_key: &str,
+        worklog_id: &str,
+        worklog: Worklog,
+    ) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/worklog/{}", issue_key, worklog_id));
+
+        let payload = self.worklog_payload(&worklog);
+
+        let request = self.request(Method::PUT, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to update worklog: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Worklog {} not found",
+                worklog_id
+            ))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn delete_worklog(&self, issue_key: &str, worklog_id: &str) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/worklog/{}", issue_key, worklog_id));
+
+        let request = self.request(Method::DELETE, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to delete worklog: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Worklog {} not found",
+                worklog_id
+            ))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_comments(
+        &self,
+        issue_key: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<Comment>> {
+        let url = self.api_url(&format!("issue/{}/comment", issue_key));
+
+        let request = self.request(Method::GET, &url).query(&[
+            ("startAt", start_at.to_string()),
+            ("maxResults", max_results.to_string()),
+        ]);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get comments: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: CommentsResponseDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse comments: {}", e)))?;
+                let comments: Vec<Comment> = dto.comments.into_iter().map(Into::into).collect();
+                Ok(Paginated::new(comments, dto.total, dto.start_at))
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_changelog(
+        &self,
+        issue_key: &str,
+        start_at: u64,
+        max_results: u64,
+    ) -> Result<Paginated<ChangelogEntry>> {
+        // Jira Server has no changelog endpoint: the whole history comes with the issue
+        let request = match self.deployment {
+            JiraDeployment::Cloud => self
+                .request(
+                    Method::GET,
+                    &self.api_url(&format!("issue/{}/changelog", issue_key)),
+                )
+                .query(&[
+                    ("startAt", start_at.to_string()),
+                    ("maxResults", max_results.to_string()),
+                ]),
+            JiraDeployment::Server => self
+                .request(Method::GET, &self.api_url(&format!("issue/{}", issue_key)))
+                .query(&[("expand", "changelog"), ("fields", "created")]),
+        };
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get history: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let parse_error = |e: reqwest::Error| {
+                    AppError::ApiError(format!("Failed to parse history: {}", e))
+                };
+                match self.deployment {
+                    JiraDeployment::Cloud => {
+                        let dto: ChangelogPageDto = response.json().await.map_err(parse_error)?;
+                        let entries = dto.values.into_iter().map(Into::into).collect();
+                        Ok(Paginated::new(entries, dto.total, dto.start_at))
+                    }
+                    JiraDeployment::Server => {
+                        let dto: ExpandedChangelogDto =
+                            response.json().await.map_err(parse_error)?;
+                        let entries: Vec<ChangelogEntry> = dto
+                            .changelog
+                            .histories
+                            .into_iter()
+                            .map(Into::into)
+                            .collect();
+                        let total = entries.len() as u64;
+                        Ok(Paginated::new(entries, total, 0))
+                    }
+                }
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn add_comment(&self, issue_key: &str, body: &str) -> Result<Comment> {
+        let url = self.api_url(&format!("issue/{}/comment", issue_key));
+
+        let payload = serde_json::json!({ "body": self.rich_text_body(body) });
+
+        let request = self.request(Method::POST, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to add comment: {}", e)))?;
+
+        match response.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                let dto: CommentDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse comment: {}", e)))?;
+                Ok(dto.into())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn update_comment(
+        &self,
+        issue_key: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment> {
+        let url = self.api_url(&format!("issue/{}/comment/{}", issue_key, comment_id));
+
+        let payload = serde_json::json!({ "body": self.rich_text_body(body) });
+
+        let request = self.request(Method::PUT, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to update comment: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: CommentDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse comment: {}", e)))?;
+                Ok(dto.into())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Comment {} not found",
+                comment_id
+            ))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn delete_comment(&self, issue_key: &str, comment_id: &str) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/comment/{}", issue_key, comment_id));
+
+        let request = self.request(Method::DELETE, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to delete comment: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Comment {} not found",
+                comment_id
+            ))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn search_users(&self, query: &str) -> Result<Vec<User>> {
+        let url = self.api_url("user/search");
+
+        // Jira Server only matches on `username`, which also covers display names and emails.
+        let param = match self.deployment {
+            JiraDeployment::Cloud => "query",
+            JiraDeployment::Server => "username",
+        };
+        let request = self
+            .request(Method::GET, &url)
+            .query(&[(param, query), ("maxResults", "10")]);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to search users: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let users: Vec<UserDto> = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse users: {}", e)))?;
+                Ok(users.into_iter().map(Into::into).collect())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn search_assignable_users(&self, issue_key: &str, query: &str) -> Result<Vec<User>> {
+        let url = self.api_url("user/assignable/search");
+
+        let mut params = vec![("issueKey", issue_key), ("maxResults", "50")];
+        if !query.is_empty() {
+            params.push(match self.deployment {
+                JiraDeployment::Cloud => ("query", query),
+                JiraDeployment::Server => ("username", query),
+            });
+        }
+        let request = self.request(Method::GET, &url).query(&params);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to search users: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let users: Vec<UserDto> = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse users: {}", e)))?;
+                Ok(users.into_iter().map(Into::into).collect())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_current_user(&self) -> Result<User> {
+        let url = self.api_url("myself");
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get current user: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let user: UserDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse user: {}", e)))?;
+                Ok(user.into())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn assign_issue(&self, issue_key: &str, account_id: Option<&str>) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/assignee", issue_key));
+
+        // `null` unassigns the issue
+        let payload = match self.deployment {
+            JiraDeployment::Cloud => serde_json::json!({ "accountId": account_id }),
+            JiraDeployment::Server => serde_json::json!({ "name": account_id }),
+        };
+
+        let request = self.request(Method::PUT, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to assign issue: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_custom_fields(&self) -> Result<Vec<FieldMeta>> {
+        Ok(self.resolved_custom_fields().await?.to_vec())
+    }
+
+    async fn get_watchers(&self, issue_key: &str) -> Result<Watchers> {
+        let url = self.api_url(&format!("issue/{}/watchers", issue_key));
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get watchers: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: WatchesDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse watchers: {}", e)))?;
+                Ok(dto.into())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn add_watcher(&self, issue_key: &str, account_id: &str) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/watchers", issue_key));
+
+        // The body is the bare account ID (username on Jira Server) as a JSON string
+        let request = self.request(Method::POST, &url).json(&account_id);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to watch issue: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn remove_watcher(&self, issue_key: &str, account_id: &str) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/watchers", issue_key));
+
+        let param = match self.deployment {
+            JiraDeployment::Cloud => "accountId",
+            JiraDeployment::Server => "username",
+        };
+        let request = self
+            .request(Method::DELETE, &url)
+            .query(&[(param, account_id)]);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to unwatch issue: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_votes(&self, issue_key: &str) -> Result<Votes> {
+        let url = self.api_url(&format!("issue/{}/votes", issue_key));
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get votes: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: VotesDto = response
+                    .json()
+                    .await
+                    .map_err(|e| AppError::ApiError(format!("Failed to parse votes: {}", e)))?;
+                Ok(dto.into())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn add_vote(&self, issue_key: &str) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/votes", issue_key));
+
+        let request = self.request(Method::POST, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to vote: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn remove_vote(&self, issue_key: &str) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/votes", issue_key));
+
+        let request = self.request(Method::DELETE, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to remove vote: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_issue_link_types(&self) -> Result<Vec<IssueLinkType>> {
+        let url = self.api_url("issueLinkType");
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get link types: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: IssueLinkTypesResponseDto = response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse link types: {}", e))
+                })?;
+                Ok(dto.issue_link_types.into_iter().map(Into::into).collect())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn link_issues(
+        &self,
+        issue_key: &str,
+        link_type: &IssueLinkType,
+        direction: LinkDirection,
+        other_key: &str,
+    ) -> Result<()> {
+        let url = self.api_url("issueLink");
+
+        // Jira reads the outward description from the `inwardIssue`:
+        // inwardIssue A + outwardIssue B is "A blocks B"
+        let (inward, outward) = match direction {
+            LinkDirection::Outward => (issue_key, other_key),
+            LinkDirection::Inward => (other_key, issue_key),
+        };
+        let payload = serde_json::json!({
+            "type": { "name": link_type.name },
+            "inwardIssue": { "key": inward },
+            "outwardIssue": { "key": outward },
+        });
+
+        let request = self.request(Method::POST, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to link issues: {}", e)))?;
+
+        match response.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Issue {} or {} not found",
+                issue_key, other_key
+            ))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn delete_issue_link(&self, link_id: &str) -> Result<()> {
+        let url = self.api_url(&format!("issueLink/{}", link_id));
+
+        let request = self.request(Method::DELETE, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to delete link: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!("Link {} not found", link_id))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn download_attachment(
+        &self,
+        attachment: &Attachment,
+    ) -> Result<BoxStream<'static, Result<Vec<u8>>>> {
+        let request = self.transfer_request(Method::GET, &attachment.content_url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to download attachment: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let chunks = futures::stream::try_unfold(response, |mut response| async move {
+                    let chunk = response.chunk().await.map_err(|e| {
+                        AppError::ApiError(format!("Failed to download attachment: {}", e))
+                    })?;
+                    Ok(chunk.map(|bytes| (bytes.to_vec(), response)))
+                });
+                Ok(Box::pin(chunks))
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(AppError::NotFound(format!(
+                "Attachment {} not found",
+                attachment.filename
+            ))),
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn add_attachment(&self, issue_key: &str, path: &Path) -> Result<Vec<Attachment>> {
+        let url = self.api_url(&format!("issue/{}/attachments", issue_key));
+
+        let content = tokio::fs::read(path).await?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "attachment".to_string());
+        let form = Form::new().part("file", Part::bytes(content).file_name(filename));
+
+        // Jira rejects multipart uploads without this header (XSRF check)
+        let request = self
+            .transfer_request(Method::POST, &url)
+            .header("X-Atlassian-Token", "no-check")
+            .multipart(form);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to upload attachment: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dtos: Vec<AttachmentDto> = response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse attachments: {}", e))
+                })?;
+                Ok(dtos.into_iter().map(Into::into).collect())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn get_transitions(&self, issue_key: &str) -> Result<Vec<Transition>> {
+        let url = self.api_url(&format!("issue/{}/transitions", issue_key));
+
+        let request = self.request(Method::GET, &url);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to get transitions: {}", e)))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let dto: TransitionsResponseDto = response.json().await.map_err(|e| {
+                    AppError::ApiError(format!("Failed to parse transitions: {}", e))
+                })?;
+                Ok(dto.transitions.into_iter().map(Into::into).collect())
+            }
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+
+    async fn transition_issue(&self, issue_key: &str, transition_id: &str) -> Result<()> {
+        let url = self.api_url(&format!("issue/{}/transitions", issue_key));
+
+        let payload = serde_json::json!({
+            "transition": { "id": transition_id }
+        });
+
+        let request = self.request(Method::POST, &url).json(&payload);
+        let response = self
+            .send(request)
+            .await
+            .map_err(|e| AppError::ApiError(format!("Failed to transition issue: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(AppError::Unauthorized),
+            StatusCode::NOT_FOUND => {
+                Err(AppError::NotFound(format!("Issue {} not found", issue_key)))
+            }
+            _ => Err(error_from_response(response).await),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{FieldChange, FieldKind, FieldOption, IssueLevel, IssueStatus};
+    use futures::TryStreamExt;
+    use wiremock::matchers::{
+        body_json, header, method, path, query_param, query_param_is_missing,
+    };
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client(server: &MockServer) -> JiraClient {
+        JiraClient::new(JiraConfig {
+            base_url: server.uri(),
+            deployment: JiraDeployment::Cloud,
+            auth: AuthMethod::Pat,
+            email: None,
+            api_token: "token".to_string(),
+            http: HttpConfig {
+                initial_backoff_ms: 1,
+                ..HttpConfig::default()
+            },
+            custom_fields: Vec::new(),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn requests_go_through_the_retrying_executor() {
+        let server = MockServer::start().await;
+        Mock::given(path("/rest/api/3/issue/PROJ-1/transitions"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/transitions"))
+            .and(header("Authorization", "Bearer token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "transitions": [{ "id": "31", "name": "Done", "to": { "name": "Done" } }]
+            })))
+            .mount(&server)
+            .await;
+
+        let transitions = client(&server).get_transitions("PROJ-1").await.unwrap();
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].id, "31");
+    }
+
+    #[tokio::test]
+    async fn board_columns_are_mapped_to_status_ids() {
+        let server = MockServer::start().await;
+        Mock::given(path("/rest/agile/1.0/board/7/configuration"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 7,
+                "name": "Team board",
+                "columnConfig": {
+                    "columns": [
+                        { "name": "Backlog", "statuses": [] },
+                        { "name": "In Progress", "statuses": [
+                            { "id": "3", "self": "https://jira/rest/api/2/status/3" },
+                            { "id": "10001", "self": "https://jira/rest/api/2/status/10001" }
+                        ] }
+                    ],
+                    "constraintType": "issueCount"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let configuration = client(&server).get_board_configuration(7).await.unwrap();
+        assert_eq!(configuration.board_id, 7);
+        assert_eq!(configuration.columns.len(), 2);
+        assert!(configuration.columns[0].status_ids.is_empty());
+        assert_eq!(configuration.columns[1].name, "In Progress");
+        assert_eq!(configuration.columns[1].status_ids, vec!["3", "10001"]);
+    }
+
+    #[tokio::test]
+    async fn sprints_are_listed_and_issues_moved_between_them() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/agile/1.0/board/7/sprint"))
+            .and(query_param("state", "active,future"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "maxResults": 50,
+                "startAt": 0,
+                "isLast": true,
+                "values": [{
+                    "id": 12,
+                    "name": "Sprint 12",
+                    "state": "active",
+                    "goal": "Ship the board view",
+                    "startDate": "2026-10-05T08:00:00.000Z",
+                    "endDate": "2026-10-19T17:00:00.000+02:00"
+                }, { "id": 13, "name": "Sprint 13", "state": "future", "goal": "" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/agile/1.0/sprint/13/issue"))
+            .and(body_json(serde_json::json!({ "issues": ["PROJ-1"] })))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/agile/1.0/backlog/issue"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let page = client
+            .get_sprints(
+                7,
+                &[SprintState::Active, SprintState::Future],
+                PageCursor::First,
+                50,
+            )
+            .await
+            .unwrap();
+        assert!(page.next.is_none());
+        let active = &page.items[0];
+        assert_eq!(active.state, SprintState::Active);
+        assert_eq!(active.goal.as_deref(), Some("Ship the board view"));
+        assert_eq!(
+            active.end_date.unwrap().to_rfc3339(),
+            "2026-10-19T15:00:00+00:00"
+        );
+        assert_eq!(page.items[1].goal, None);
+        assert_eq!(page.items[1].start_date, None);
+
+        let keys = ["PROJ-1".to_string()];
+        client.move_issues_to_sprint(13, &keys).await.unwrap();
+        client.move_issues_to_backlog(&keys).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn issues_carry_their_type_and_hierarchy() {
+        let server = MockServer::start().await;
+        Mock::given(path("/rest/agile/1.0/board/7/issue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 0,
+                "maxResults": 20,
+                "total": 2,
+                "issues": [{
+                    "key": "PROJ-2",
+                    "fields": {
+                        "summary": "Story",
+                        "status": { "name": "To Do" },
+                        "created": "2026-10-01T10:00:00.000+0000",
+                        "updated": "2026-10-01T10:00:00.000+0000",
+                        "issuetype": { "name": "Story", "subtask": false },
+                        "subtasks": [{ "key": "PROJ-3" }],
+                        "epic": { "id": 1, "key": "PROJ-1", "name": "Epic" }
+                    }
+                }, {
+                    "key": "PROJ-1",
+                    "fields": {
+                        "summary": "Epic",
+                        "status": { "name": "To Do" },
+                        "created": "2026-10-01T10:00:00.000+0000",
+                        "updated": "2026-10-01T10:00:00.000+0000",
+                        "issuetype": { "name": "Epic", "subtask": false }
+                    }
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let page = client(&server)
+            .get_issues_by_board(7, PageCursor::First, 20, IssueFilter::default())
+            .await
+            .unwrap();
+        let story = &page.items[0];
+        assert_eq!(story.issue_type.level, IssueLevel::Standard);
+        assert_eq!(story.parent_key.as_deref(), Some("PROJ-1"));
+        assert_eq!(story.subtask_keys, vec!["PROJ-3"]);
+        assert_eq!(page.items[1].issue_type.level, IssueLevel::Epic);
+    }
+
+    #[tokio::test]
+    async fn custom_fields_are_resolved_once_and_read_by_kind() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/field"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "summary", "name": "Summary", "schema": { "type": "string" } },
+                { "id": "customfield_10016", "name": "Story Points",
+                  "schema": { "type": "number", "custom": "float" } },
+                { "id": "customfield_10030", "name": "Team", "schema": { "type": "option" } },
+                { "id": "customfield_10031", "name": "Platforms",
+                  "schema": { "type": "array", "items": "option" } },
+                { "id": "customfield_10032", "name": "Reviewer", "schema": { "type": "user" } },
+                { "id": "customfield_10033", "name": "Release date", "schema": { "type": "date" } }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1"))
+            .and(query_param(
+                "fields",
+                format!(
+                    "{},customfield_10016,customfield_10030,customfield_10031,\
+                     customfield_10032,customfield_10033",
+                    ISSUE_FIELDS
+                ),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "PROJ-1",
+                "fields": {
+                    "summary": "Story",
+                    "status": { "name": "To Do" },
+                    "created": "2026-10-01T10:00:00.000+0000",
+                    "updated": "2026-10-01T10:00:00.000+0000",
+                    "customfield_10016": 5.0,
+                    "customfield_10030": { "id": "7", "value": "Core" },
+                    "customfield_10031": [{ "id": "8", "value": "iOS" }],
+                    "customfield_10032": { "displayName": "Ana Lopez", "accountId": "abc-123" },
+                    "customfield_10033": null
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = JiraClient::new(JiraConfig {
+            base_url: server.uri(),
+            deployment: JiraDeployment::Cloud,
+            auth: AuthMethod::Pat,
+            email: None,
+            api_token: "token".to_string(),
+            http: HttpConfig::default(),
+            custom_fields: [
+                "story points",
+                "Team",
+                "Platforms",
+                "Reviewer",
+                "customfield_10033",
+            ]
+            .map(String::from)
+            .to_vec(),
+        })
+        .unwrap();
+        let fields = client.get_custom_fields().await.unwrap();
+        assert_eq!(fields[0].name, "Story Points");
+        assert_eq!(fields[4].kind, FieldKind::Date);
+
+        let issue = client.get_issue("PROJ-1").await.unwrap();
+        let option = |id: &str, name: &str| FieldOption {
+            id: id.to_string(),
+            name: name.to_string(),
+        };
+        assert_eq!(
+            issue.fields.get("customfield_10016"),
+            Some(&FieldValue::Number(5.0))
+        );
+        assert_eq!(
+            issue.fields.get("customfield_10030"),
+            Some(&FieldValue::Option(option("7", "Core")))
+        );
+        assert_eq!(
+            issue.fields.get("customfield_10031"),
+            Some(&FieldValue::Options(vec![option("8", "iOS")]))
+        );
+        assert_eq!(
+            issue.fields.get("customfield_10032"),
+            Some(&FieldValue::Account(User {
+                account_id: "abc-123".to_string(),
+                display_name: "Ana Lopez".to_string(),
+            }))
+        );
+        // Empty fields are left out
+        assert!(!issue.fields.contains_key("customfield_10033"));
+    }
+
+    #[tokio::test]
+    async fn unknown_custom_fields_are_skipped() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/field"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "customfield_10016", "name": "Story Points", "schema": { "type": "number" } }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1"))
+            .and(query_param(
+                "fields",
+                format!("{},customfield_10016", ISSUE_FIELDS),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "PROJ-1",
+                "fields": {
+                    "summary": "Story",
+                    "status": { "name": "To Do" },
+                    "created": "2026-10-01T10:00:00.000+0000",
+                    "updated": "2026-10-01T10:00:00.000+0000",
+                    "customfield_10016": 3
+                }
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = JiraClient::new(JiraConfig {
+            base_url: server.uri(),
+            deployment: JiraDeployment::Cloud,
+            auth: AuthMethod::Pat,
+            email: None,
+            api_token: "token".to_string(),
+            http: HttpConfig::default(),
+            custom_fields: vec!["Severity".to_string(), "Story Points".to_string()],
+        })
+        .unwrap();
+        for _ in 0..2 {
+            let issue = client.get_issue("PROJ-1").await.unwrap();
+            assert_eq!(
+                issue.fields.get("customfield_10016"),
+                Some(&FieldValue::Number(3.0))
+            );
+        }
+        let fields = client.get_custom_fields().await.unwrap();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "Story Points");
+    }
+
+    #[tokio::test]
+    async fn links_are_read_from_each_end_and_created_the_jira_way() {
+        let server = MockServer::start().await;
+        let blocks = serde_json::json!({
+            "id": "10000", "name": "Blocks", "inward": "is blocked by", "outward": "blocks"
+        });
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "PROJ-1",
+                "fields": {
+                    "summary": "Login",
+                    "status": { "name": "To Do" },
+                    "created": "2026-10-01T10:00:00.000+0000",
+                    "updated": "2026-10-01T10:00:00.000+0000",
+                    "issuelinks": [{
+                        "id": "1",
+                        "type": blocks,
+                        "outwardIssue": {
+                            "key": "PROJ-2",
+                            "fields": { "summary": "Signup", "status": { "name": "Done" } }
+                        }
+                    }, {
+                        "id": "2",
+                        "type": blocks,
+                        "inwardIssue": { "key": "PROJ-3" }
+                    }]
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issueLinkType"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "issueLinkTypes": [blocks] })),
+            )
+            .mount(&server)
+            .await;
+        // "PROJ-1 is blocked by PROJ-4" has PROJ-4 as the inward issue
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issueLink"))
+            .and(body_json(serde_json::json!({
+                "type": { "name": "Blocks" },
+                "inwardIssue": { "key": "PROJ-4" },
+                "outwardIssue": { "key": "PROJ-1" }
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/rest/api/3/issueLink/1"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let issue = client.get_issue("PROJ-1").await.unwrap();
+        assert_eq!(issue.links.len(), 2);
+        assert_eq!(issue.links[0].description(), "blocks");
+        assert_eq!(issue.links[0].issue_key, "PROJ-2");
+        assert_eq!(issue.links[0].issue_summary, "Signup");
+        assert_eq!(issue.links[0].issue_status, IssueStatus::Done);
+        assert_eq!(issue.links[1].direction, LinkDirection::Inward);
+        assert_eq!(issue.links[1].description(), "is blocked by");
+
+        let link_types = client.get_issue_link_types().await.unwrap();
+        client
+            .link_issues("PROJ-1", &link_types[0], LinkDirection::Inward, "PROJ-4")
+            .await
+            .unwrap();
+        client.delete_issue_link("1").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn attachments_are_downloaded_and_uploaded() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "PROJ-1",
+                "fields": {
+                    "summary": "Crash",
+                    "status": { "name": "To Do" },
+                    "created": "2026-10-01T10:00:00.000+0000",
+                    "updated": "2026-10-01T10:00:00.000+0000",
+                    "attachment": [{
+                        "id": "10",
+                        "filename": "crash.log",
+                        "author": { "displayName": "Ana Lopez" },
+                        "created": "2026-10-01T11:00:00.000+0000",
+                        "size": 11,
+                        "mimeType": "text/plain",
+                        "content": format!("{}/rest/api/3/attachment/content/10", server.uri())
+                    }]
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/attachment/content/10"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("stack trace"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/PROJ-1/attachments"))
+            .and(header("X-Atlassian-Token", "no-check"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "id": "11",
+                    "filename": "screenshot.png",
+                    "created": "2026-10-02T09:00:00.000+0000",
+                    "size": 4,
+                    "mimeType": "image/png",
+                    "content": format!("{}/rest/api/3/attachment/content/11", server.uri())
+                }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let issue = client.get_issue("PROJ-1").await.unwrap();
+        let attachment = &issue.attachments[0];
+        assert_eq!(attachment.filename, "crash.log");
+        assert_eq!(attachment.size, 11);
+        assert_eq!(attachment.author, "Ana Lopez");
+        assert_eq!(attachment.mime_type, "text/plain");
+
+        let chunks: Vec<Vec<u8>> = client
+            .download_attachment(attachment)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.concat(), b"stack trace");
+
+        let file = std::env::temp_dir().join(format!("screenshot-{}.png", std::process::id()));
+        std::fs::write(&file, b"\x89PNG").unwrap();
+        let added = client.add_attachment("PROJ-1", &file).await;
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(added.unwrap()[0].filename, "screenshot.png");
+    }
+
+    #[tokio::test]
+    async fn create_fields_are_typed_and_encoded() {
+        let server = MockServer::start().await;
+        Mock::given(path("/rest/api/3/issue/createmeta/PROJ/issuetypes/10001"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 3,
+                "fields": [{
+                    "fieldId": "summary",
+                    "name": "Summary",
+                    "required": true,
+                    "schema": { "type": "string", "system": "summary" }
+                }, {
+                    "fieldId": "priority",
+                    "name": "Priority",
+                    "required": false,
+                    "hasDefaultValue": true,
+                    "schema": { "type": "priority", "system": "priority" },
+                    "allowedValues": [{ "id": "2", "name": "High" }]
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(path("/rest/api/3/issue/createmeta/PROJ/issuetypes/10001"))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 2,
+                "maxResults": 2,
+                "total": 3,
+                "fields": [{
+                    "fieldId": "description",
+                    "name": "Description",
+                    "required": false,
+                    "schema": { "type": "string", "system": "description" }
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue"))
+            .and(body_json(serde_json::json!({
+                "fields": {
+                    "project": { "key": "PROJ" },
+                    "issuetype": { "id": "10001" },
+                    "summary": "New",
+                    "priority": { "id": "2" }
+                }
+            })))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(serde_json::json!({ "id": "100", "key": "PROJ-9" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-9"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "PROJ-9",
+                "fields": {
+                    "summary": "New",
+                    "status": { "name": "To Do" },
+                    "created": "2026-10-01T10:00:00.000+0000",
+                    "updated": "2026-10-01T10:00:00.000+0000"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let fields = client.get_create_fields("PROJ", "10001").await.unwrap();
+        let kinds: Vec<_> = fields.iter().map(|f| f.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![FieldKind::Text, FieldKind::Choice, FieldKind::RichText]
+        );
+        assert!(fields[0].needs_value());
+        assert!(!fields[1].needs_value());
+
+        let issue = client
+            .create_issue(NewIssue {
+                project_key: "PROJ".to_string(),
+                issue_type_id: "10001".to_string(),
+                fields: vec![
+                    (fields[0].clone(), FieldValue::Text("New".to_string())),
+                    (fields[1].clone(), FieldValue::Choice("2".to_string())),
+                ],
+            })
+            .await
+            .unwrap();
+        assert_eq!(issue.key, "PROJ-9");
+    }
+
+    fn search_issue(key: &str) -> serde_json::Value {
+        serde_json::json!({
+            "key": key,
+            "fields": {
+                "summary": format!("Summary of {}", key),
+                "status": { "name": "To Do" },
+                "created": "2026-10-01T10:00:00.000+0000",
+                "updated": "2026-10-01T10:00:00.000+0000"
+            }
+        })
+    }
+
+    fn keys(page: &Paginated<Issue>) -> Vec<&str> {
+        page.items.iter().map(|i| i.key.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn searches_follow_the_page_token_until_the_last_page() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(query_param("jql", "project = PROJ"))
+            .and(query_param_is_missing("nextPageToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [search_issue("PROJ-1")],
+                "nextPageToken": "page-2",
+                "isLast": false
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(query_param("nextPageToken", "page-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [search_issue("PROJ-2")],
+                "nextPageToken": "page-3",
+                "isLast": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let first = client
+            .search_issues("project = PROJ", PageCursor::First, 1)
+            .await
+            .unwrap();
+        assert_eq!(keys(&first), vec!["PROJ-1"]);
+        assert!(first.is_first_page);
+        assert_eq!(first.total, None);
+        assert_eq!(first.next, Some(PageCursor::Token("page-2".to_string())));
+
+        let second = client
+            .search_issues("project = PROJ", first.next.unwrap(), 1)
+            .await
+            .unwrap();
+        assert_eq!(keys(&second), vec!["PROJ-2"]);
+        assert!(!second.is_first_page);
+        // `isLast` wins over a token Jira still sends
+        assert_eq!(second.next, None);
+    }
+
+    #[tokio::test]
+    async fn searches_fall_back_to_offsets_without_the_token_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/search/jql"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+        for (api, start_at, key) in [(3, 0, "PROJ-1"), (3, 1, "PROJ-2"), (2, 0, "PROJ-1")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/rest/api/{}/search", api)))
+                .and(query_param("startAt", start_at.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "startAt": start_at,
+                    "maxResults": 1,
+                    "total": 2,
+                    "issues": [search_issue(key)]
+                })))
+                .mount(&server)
+                .await;
+        }
+
+        // Cloud instances without the enhanced search remember it after the first 404
+        let cloud = client(&server);
+        let first = cloud
+            .search_issues("project = PROJ", PageCursor::First, 1)
+            .await
+            .unwrap();
+        assert_eq!(keys(&first), vec!["PROJ-1"]);
+        assert_eq!(first.total, Some(2));
+        assert_eq!(first.next, Some(PageCursor::Offset(1)));
+        let second = cloud
+            .search_issues("project = PROJ", first.next.unwrap(), 1)
+            .await
+            .unwrap();
+        assert_eq!(keys(&second), vec!["PROJ-2"]);
+        assert_eq!(second.next, None);
+        let again = cloud
+            .search_issues("project = PROJ", PageCursor::First, 1)
+            .await
+            .unwrap();
+        assert_eq!(keys(&again), vec!["PROJ-1"]);
+
+        // Server goes straight to the offset search of API v2
+        let server_client = JiraClient::new(JiraConfig {
+            base_url: server.uri(),
+            deployment: JiraDeployment::Server,
+            auth: AuthMethod::Pat,
+            email: None,
+            api_token: "token".to_string(),
+            http: HttpConfig::default(),
+            custom_fields: Vec::new(),
+        })
+        .unwrap();
+        let page = server_client
+            .search_issues("project = PROJ", PageCursor::First, 1)
+            .await
+            .unwrap();
+        assert!(page.is_first_page);
+        assert_eq!(page.next, Some(PageCursor::Offset(1)));
+    }
+
+    #[tokio::test]
+    async fn created_issues_are_reported_even_when_they_cannot_be_read_back() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(serde_json::json!({ "id": "100", "key": "PROJ-9" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-9"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let summary = FieldMeta {
+            id: "summary".to_string(),
+            name: "Summary".to_string(),
+            kind: FieldKind::Text,
+            required: true,
+            has_default: false,
+            allowed_values: Vec::new(),
+        };
+        let issue = client(&server)
+            .create_issue(NewIssue {
+                project_key: "PROJ".to_string(),
+                issue_type_id: "10001".to_string(),
+                fields: vec![(summary, FieldValue::Text("New".to_string()))],
+            })
+            .await
+            .unwrap();
+        assert_eq!(issue.key, "PROJ-9");
+        assert_eq!(issue.summary, "New");
+        assert_eq!(issue.issue_type.id, "10001");
+    }
+
+    #[tokio::test]
+    async fn edits_send_only_the_changed_fields() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/editmeta"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "fields": {
+                    "labels": {
+                        "name": "Labels",
+                        "required": false,
+                        "schema": { "type": "array", "items": "string", "system": "labels" }
+                    },
+                    "priority": {
+                        "name": "Priority",
+                        "required": false,
+                        "schema": { "type": "priority", "system": "priority" },
+                        "allowedValues": [{ "id": "1", "name": "Highest" }]
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/PROJ-1"))
+            .and(body_json(serde_json::json!({
+                "fields": { "labels": ["ui", "bug"], "priority": null }
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let fields = client.get_edit_fields("PROJ-1").await.unwrap();
+        assert_eq!(fields[0].id, "labels");
+        assert_eq!(fields[0].kind, FieldKind::Labels);
+        assert_eq!(fields[1].allowed_values[0].name, "Highest");
+
+        client
+            .update_issue(
+                "PROJ-1",
+                vec![
+                    (
+                        fields[0].clone(),
+                        Some(FieldValue::Labels(vec![
+                            "ui".to_string(),
+                            "bug".to_string(),
+                        ])),
+                    ),
+                    (fields[1].clone(), None),
+                ],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn comments_are_paged_and_written_as_adf() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/comment"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 0,
+                "total": 3,
+                "comments": [{
+                    "id": "10",
+                    "author": { "displayName": "Ana Lopez", "accountId": "abc-123" },
+                    "body": {
+                        "type": "doc",
+                        "content": [{
+                            "type": "paragraph",
+                            "content": [{ "type": "text", "text": "Looks good" }]
+                        }]
+                    },
+                    "created": "2024-05-01T10:00:00.000+0000",
+                    "updated": "2024-05-01T10:00:00.000+0000"
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/PROJ-1/comment"))
+            .and(body_json(serde_json::json!({
+                "body": {
+                    "type": "doc",
+                    "version": 1,
+                    "content": [{
+                        "type": "paragraph",
+                        "content": [
+                            { "type": "mention", "attrs": { "id": "abc-123", "text": "@Ana" } },
+                            { "type": "text", "text": " done" }
+                        ]
+                    }]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": "11",
+                "author": { "displayName": "Me", "accountId": "me" },
+                "created": "2024-05-02T10:00:00.000+0000",
+                "updated": "2024-05-02T10:00:00.000+0000"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/rest/api/3/issue/PROJ-1/comment/10"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/user/search"))
+            .and(query_param("query", "an"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "displayName": "Ana Lopez", "accountId": "abc-123" }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let page = client.get_comments("PROJ-1", 0, 20).await.unwrap();
+        assert_eq!(page.items[0].author, "Ana Lopez");
+        assert_eq!(page.items[0].body.to_markdown(), "Looks good");
+        assert_eq!(page.next, Some(PageCursor::Offset(1)));
+
+        let added = client
+            .add_comment("PROJ-1", "[@Ana](mention:abc-123) done")
+            .await
+            .unwrap();
+        assert_eq!(added.id, "11");
+
+        client.delete_comment("PROJ-1", "10").await.unwrap();
+
+        let users = client.search_users("an").await.unwrap();
+        assert_eq!(
+            users,
+            vec![User {
+                account_id: "abc-123".to_string(),
+                display_name: "Ana Lopez".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn issues_are_watched_and_voted_as_the_current_user() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/PROJ-1/watchers"))
+            .and(body_json(serde_json::json!("abc-123")))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/rest/api/3/issue/PROJ-1/watchers"))
+            .and(query_param("accountId", "abc-123"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/watchers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "watchCount": 1,
+                "isWatching": true,
+                "watchers": [{ "displayName": "Ana Lopez", "accountId": "abc-123" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/PROJ-1/votes"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/rest/api/3/issue/PROJ-1/votes"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/votes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "votes": 3,
+                "hasVoted": true,
+                "voters": []
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        client.add_watcher("PROJ-1", "abc-123").await.unwrap();
+        client.remove_watcher("PROJ-1", "abc-123").await.unwrap();
+        let watchers = client.get_watchers("PROJ-1").await.unwrap();
+        assert_eq!(watchers.count, 1);
+        assert!(watchers.is_watching);
+        assert_eq!(watchers.users[0].display_name, "Ana Lopez");
+
+        client.add_vote("PROJ-1").await.unwrap();
+        client.remove_vote("PROJ-1").await.unwrap();
+        assert_eq!(
+            client.get_votes("PROJ-1").await.unwrap(),
+            Votes {
+                count: 3,
+                has_voted: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn history_is_paged_on_cloud_and_expanded_on_server() {
+        let server = MockServer::start().await;
+        let history = serde_json::json!([{
+            "id": "100",
+            "author": { "displayName": "Ana Lopez", "accountId": "abc-123" },
+            "created": "2024-05-01T10:00:00.000+0000",
+            "items": [
+                { "field": "status", "fromString": "To Do", "toString": "In Progress" },
+                { "field": "labels", "fromString": "", "toString": "ui" }
+            ]
+        }]);
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-1/changelog"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 0,
+                "total": 2,
+                "values": history
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/issue/PROJ-1"))
+            .and(query_param("expand", "changelog"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "PROJ-1",
+                "changelog": { "histories": history }
+            })))
+            .mount(&server)
+            .await;
+
+        let page = client(&server)
+            .get_changelog("PROJ-1", 0, 50)
+            .await
+            .unwrap();
+        assert_eq!(page.next, Some(PageCursor::Offset(1)));
+        let entry = &page.items[0];
+        assert_eq!(entry.author, "Ana Lopez");
+        assert_eq!(
+            entry.changes[0],
+            FieldChange {
+                field: "status".to_string(),
+                from: Some("To Do".to_string()),
+                to: Some("In Progress".to_string()),
+            }
+        );
+        assert_eq!(entry.changes[1].from, None);
+
+        let server_client = JiraClient::new(JiraConfig {
+            base_url: server.uri(),
+            deployment: JiraDeployment::Server,
+            auth: AuthMethod::Pat,
+            email: None,
+            api_token: "token".to_string(),
+            http: HttpConfig::default(),
+            custom_fields: Vec::new(),
+        })
+        .unwrap();
+        let page = server_client.get_changelog("PROJ-1", 0, 50).await.unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.next, None);
+    }
+
+    #[tokio::test]
+    async fn issues_are_assigned_by_account_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/user/assignable/search"))
+            .and(query_param("issueKey", "PROJ-1"))
+            .and(query_param("query", "ana"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "displayName": "Ana Lopez", "accountId": "abc-123" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "displayName": "Me", "accountId": "me-1"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/PROJ-1/assignee"))
+            .and(body_json(serde_json::json!({ "accountId": "abc-123" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/PROJ-2/assignee"))
+            .and(body_json(serde_json::json!({ "accountId": null })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let users = client
+            .search_assignable_users("PROJ-1", "ana")
+            .await
+            .unwrap();
+        assert_eq!(users[0].account_id, "abc-123");
+        assert_eq!(client.get_current_user().await.unwrap().account_id, "me-1");
+
+        client
+            .assign_issue("PROJ-1", Some("abc-123"))
+            .await
+            .unwrap();
+        client.assign_issue("PROJ-2", None).await.unwrap();
+    }
+
+    #[test]
+    fn error_bodies_become_structured_errors() {
+        let body = r#"{"errorMessages":["Issue type is required"],"errors":{"summary":"You must specify a summary."}}"#;
+        let AppError::Validation {
+            messages,
+            field_errors,
+        } = api_error(StatusCode::BAD_REQUEST, None, body)
+        else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(messages, vec!["Issue type is required"]);
+        assert_eq!(field_errors["summary"], "You must specify a summary.");
+
+        let forbidden = api_error(
+            StatusCode::FORBIDDEN,
+            None,
+            r#"{"errorMessages":["You cannot edit this issue"]}"#,
+        );
+        assert!(
+            matches!(forbidden, AppError::Forbidden(ref m) if m == "You cannot edit this issue")
+        );
+
+        let limited = api_error(
+            StatusCode::TOO_MANY_REQUESTS,
+            Some(Duration::from_secs(7)),
+            "",
+        );
+        assert!(matches!(
+            limited,
+            AppError::RateLimited {
+                retry_after_secs: Some(7)
+            }
+        ));
+    }
+
+    #[test]
+    fn unparseable_bodies_fall_back_to_the_status() {
+        let error = api_error(StatusCode::BAD_GATEWAY, None, "<html>Bad gateway</html>");
+        assert!(matches!(
+            error,
+            AppError::ServerError { status: 502, ref message } if message == "Bad Gateway"
+        ));
+
+        let error = api_error(StatusCode::CONFLICT, None, r#"{"message":"Board locked"}"#);
+        assert_eq!(error.to_string(), "Conflict: Board locked");
     }
 }
@@ -1,5 +1,13 @@
-use crate::domain::models::{Board, Issue, IssueStatus, WorklogEntry};
+use crate::domain::models::{
+    Attachment, Board, BoardColumn, BoardConfiguration, ChangelogEntry, Comment, FieldChange,
+    FieldKind, FieldMeta, FieldOption, FieldValue, Issue, IssueLevel, IssueLink, IssueLinkType,
+    IssueStatus, IssueType, LinkDirection, SavedQuery, SavedQuerySource, Sprint, SprintState,
+    Transition, User, Votes, Watchers, WorklogEntry,
+};
+use crate::domain::rich_text::{Block, Inline, Mark, PanelKind, RichText, TableCell, TableRow};
+use crate::infrastructure::jira::wiki;
 use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
 
 // --- BOARDS ---
 
@@ -29,6 +37,91 @@ pub struct ProjectLocationDto {
     pub project_key: Option<String>,
 }
 
+#[derive(Deserialize)]
+pub struct SprintResponseDto {
+    #[serde(rename = "maxResults")]
+    pub max_results: u64,
+    #[serde(rename = "startAt")]
+    pub start_at: u64,
+    #[serde(rename = "isLast")]
+    pub is_last: Option<bool>,
+    pub values: Vec<SprintDto>,
+}
+
+#[derive(Deserialize)]
+pub struct SprintDto {
+    pub id: u64,
+    pub name: String,
+    pub state: String,
+    pub goal: Option<String>,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<String>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<String>,
+}
+
+impl From<SprintDto> for Sprint {
+    fn from(dto: SprintDto) -> Self {
+        let date = |value: Option<String>| {
+            value
+                .and_then(|v| chrono::DateTime::parse_from_rfc3339(&v).ok())
+                .map(|d| d.with_timezone(&chrono::Utc))
+        };
+
+        Sprint {
+            id: dto.id,
+            name: dto.name,
+            // Unknown states only show up on odd plugins; treat them as not started
+            state: SprintState::from_api(&dto.state).unwrap_or(SprintState::Future),
+            goal: dto.goal.filter(|goal| !goal.trim().is_empty()),
+            start_date: date(dto.start_date),
+            end_date: date(dto.end_date),
+        }
+    }
+}
+
+/// Response of `/rest/agile/1.0/board/{id}/configuration`.
+#[derive(Deserialize)]
+pub struct BoardConfigurationDto {
+    pub id: u64,
+    #[serde(rename = "columnConfig")]
+    pub column_config: ColumnConfigDto,
+}
+
+#[derive(Deserialize)]
+pub struct ColumnConfigDto {
+    pub columns: Vec<ColumnDto>,
+}
+
+#[derive(Deserialize)]
+pub struct ColumnDto {
+    pub name: String,
+    #[serde(default)]
+    pub statuses: Vec<ColumnStatusDto>,
+}
+
+#[derive(Deserialize)]
+pub struct ColumnStatusDto {
+    pub id: String,
+}
+
+impl From<BoardConfigurationDto> for BoardConfiguration {
+    fn from(dto: BoardConfigurationDto) -> Self {
+        BoardConfiguration {
+            board_id: dto.id,
+            columns: dto
+                .column_config
+                .columns
+                .into_iter()
+                .map(|column| BoardColumn {
+                    name: column.name,
+                    status_ids: column.statuses.into_iter().map(|s| s.id).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
 impl From<BoardDto> for Board {
     fn from(dto: BoardDto) -> Self {
         let project_key = dto
@@ -45,6 +138,233 @@ impl From<BoardDto> for Board {
     }
 }
 
+// --- ATLASSIAN DOCUMENT FORMAT ---
+
+/// A node of an Atlassian Document Format tree.
+///
+/// ADF is a recursive structure where every node shares the same shape, so a single
+/// struct covers documents, blocks and inline nodes. Unknown node types are tolerated
+/// and rendered through their children (or dropped when they have none).
+#[derive(Deserialize, Debug, Clone)]
+pub struct AdfNodeDto {
+    #[serde(rename = "type")]
+    pub node_type: String,
+    #[serde(default)]
+    pub content: Vec<AdfNodeDto>,
+    pub text: Option<String>,
+    #[serde(default)]
+    pub marks: Vec<AdfMarkDto>,
+    pub attrs: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AdfMarkDto {
+    #[serde(rename = "type")]
+    pub mark_type: String,
+    pub attrs: Option<serde_json::Value>,
+}
+
+/// Rich text fields come back as ADF from API v3 but as wiki markup strings from
+/// the Agile API and API v2 (Server / Data Center).
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum RichTextDto {
+    Adf(AdfNodeDto),
+    Wiki(String),
+}
+
+impl From<RichTextDto> for RichText {
+    fn from(dto: RichTextDto) -> Self {
+        match dto {
+            RichTextDto::Adf(node) => node.into(),
+            RichTextDto::Wiki(text) => wiki::from_wiki(&text),
+        }
+    }
+}
+
+impl From<AdfNodeDto> for RichText {
+    fn from(node: AdfNodeDto) -> Self {
+        let blocks = if node.node_type == "doc" {
+            adf_blocks(node.content)
+        } else {
+            adf_blocks(vec![node])
+        };
+        RichText { blocks }
+    }
+}
+
+impl AdfNodeDto {
+    fn attr_str(&self, name: &str) -> Option<String> {
+        self.attrs
+            .as_ref()
+            .and_then(|a| a.get(name))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    }
+
+    fn attr_u64(&self, name: &str) -> Option<u64> {
+        self.attrs
+            .as_ref()
+            .and_then(|a| a.get(name))
+            .and_then(|v| v.as_u64())
+    }
+}
+
+fn adf_blocks(nodes: Vec<AdfNodeDto>) -> Vec<Block> {
+    nodes.into_iter().flat_map(adf_block).collect()
+}
+
+fn adf_block(node: AdfNodeDto) -> Vec<Block> {
+    match node.node_type.as_str() {
+        "paragraph" => vec![Block::Paragraph(adf_inlines(node.content))],
+        "heading" => {
+            let level = node.attr_u64("level").unwrap_or(1).clamp(1, 6) as u8;
+            vec![Block::Heading {
+                level,
+                content: adf_inlines(node.content),
+            }]
+        }
+        "bulletList" => vec![Block::BulletList(adf_list_items(node.content))],
+        "orderedList" => {
+            let start = node.attr_u64("order").unwrap_or(1) as u32;
+            vec![Block::OrderedList {
+                start,
+                items: adf_list_items(node.content),
+            }]
+        }
+        "codeBlock" => {
+            let language = node.attr_str("language");
+            let text = node
+                .content
+                .into_iter()
+                .filter_map(|n| n.text)
+                .collect::<String>();
+            vec![Block::Code { language, text }]
+        }
+        "blockquote" => vec![Block::Blockquote(adf_blocks(node.content))],
+        "panel" => {
+            let kind = match node.attr_str("panelType").as_deref() {
+                Some("note") => PanelKind::Note,
+                Some("warning") => PanelKind::Warning,
+                Some("error") => PanelKind::Error,
+                Some("success") => PanelKind::Success,
+                _ => PanelKind::Info,
+            };
+            vec![Block::Panel {
+                kind,
+                content: adf_blocks(node.content),
+            }]
+        }
+        "table" => {
+            let rows = node
+                .content
+                .into_iter()
+                .filter(|row| row.node_type == "tableRow")
+                .map(|row| TableRow {
+                    cells: row
+                        .content
+                        .into_iter()
+                        .map(|cell| TableCell {
+                            is_header: cell.node_type == "tableHeader",
+                            content: adf_blocks(cell.content),
+                        })
+                        .collect(),
+                })
+                .collect();
+            vec![Block::Table(rows)]
+        }
+        "rule" => vec![Block::Rule],
+        // Inline nodes at block level get wrapped into a paragraph
+        "text" | "mention" | "emoji" | "hardBreak" | "inlineCard" => {
+            vec![Block::Paragraph(adf_inlines(vec![node]))]
+        }
+        // Containers we do not model (expand, layouts, media...) are flattened
+        _ => adf_blocks(node.content),
+    }
+}
+
+fn adf_list_items(nodes: Vec<AdfNodeDto>) -> Vec<Vec<Block>> {
+    nodes
+        .into_iter()
+        .map(|item| {
+            if item.node_type == "listItem" {
+                adf_blocks(item.content)
+            } else {
+                adf_block(item)
+            }
+        })
+        .collect()
+}
+
+fn adf_inlines(nodes: Vec<AdfNodeDto>) -> Vec<Inline> {
+    nodes.into_iter().flat_map(adf_inline).collect()
+}
+
+fn adf_inline(node: AdfNodeDto) -> Vec<Inline> {
+    match node.node_type.as_str() {
+        "text" => {
+            let marks = node
+                .marks
+                .iter()
+                .filter_map(|m| match m.mark_type.as_str() {
+                    "strong" => Some(Mark::Strong),
+                    "em" => Some(Mark::Em),
+                    "code" => Some(Mark::Code),
+                    "strike" => Some(Mark::Strike),
+                    "underline" => Some(Mark::Underline),
+                    "link" => m
+                        .attrs
+                        .as_ref()
+                        .and_then(|a| a.get("href"))
+                        .and_then(|v| v.as_str())
+                        .map(|href| Mark::Link(href.to_string())),
+                    _ => None,
+                })
+                .collect();
+            vec![Inline::Text {
+                text: node.text.unwrap_or_default(),
+                marks,
+            }]
+        }
+        "mention" => vec![Inline::Mention {
+            account_id: node.attr_str("id").unwrap_or_default(),
+            text: node.attr_str("text").unwrap_or_default(),
+        }],
+        "emoji" => {
+            let shortcode = node
+                .attr_str("text")
+                .or_else(|| node.attr_str("shortName"))
+                .unwrap_or_default();
+            vec![Inline::Emoji(shortcode)]
+        }
+        "hardBreak" => vec![Inline::HardBreak],
+        "inlineCard" => {
+            let url = node.attr_str("url").unwrap_or_default();
+            vec![Inline::Text {
+                text: url.clone(),
+                marks: vec![Mark::Link(url)],
+            }]
+        }
+        "date" => {
+            let text = node
+                .attr_str("timestamp")
+                .and_then(|ts| ts.parse::<i64>().ok())
+                .and_then(chrono::DateTime::from_timestamp_millis)
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            vec![Inline::text(text)]
+        }
+        "status" => vec![Inline::Text {
+            text: node.attr_str("text").unwrap_or_default(),
+            marks: vec![Mark::Strong],
+        }],
+        _ => match node.text {
+            Some(text) => vec![Inline::text(text)],
+            None => adf_inlines(node.content),
+        },
+    }
+}
+
 // --- ISSUES ---
 
 #[derive(Deserialize)]
@@ -57,6 +377,17 @@ pub struct IssueSearchResponseDto {
     pub issues: Vec<IssueDto>,
 }
 
+/// Response of the enhanced `/rest/api/3/search/jql` endpoint (token pagination, no total).
+#[derive(Deserialize)]
+pub struct JqlSearchResponseDto {
+    #[serde(default)]
+    pub issues: Vec<IssueDto>,
+    #[serde(rename = "nextPageToken")]
+    pub next_page_token: Option<String>,
+    #[serde(rename = "isLast")]
+    pub is_last: Option<bool>,
+}
+
 #[derive(Deserialize)]
 pub struct IssueDto {
     pub key: String,
@@ -66,16 +397,181 @@ pub struct IssueDto {
 #[derive(Deserialize)]
 pub struct IssueFieldsDto {
     pub summary: String,
-    pub description: Option<String>,
+    pub description: Option<RichTextDto>,
     pub status: StatusDto,
     pub priority: Option<PriorityDto>,
     pub assignee: Option<UserDto>,
     pub created: String,
     pub updated: String,
+    pub issuetype: Option<IssueTypeDto>,
+    pub parent: Option<IssueRefDto>,
+    #[serde(default)]
+    pub subtasks: Vec<IssueRefDto>,
+    #[serde(default)]
+    pub issuelinks: Vec<IssueLinkDto>,
+    #[serde(default)]
+    pub attachment: Vec<AttachmentDto>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub watches: Option<WatchesDto>,
+    pub votes: Option<VotesDto>,
+    /// Epic of the issue, only sent by the Agile API of Jira Server (Cloud uses `parent`).
+    pub epic: Option<IssueRefDto>,
+    /// Every other field, custom fields included, read once their kind is known
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+pub struct IssueTypeDto {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+    /// 1 for epics, 0 for standard types, -1 for subtasks (Cloud only)
+    #[serde(rename = "hierarchyLevel")]
+    pub hierarchy_level: Option<i32>,
+}
+
+impl From<IssueTypeDto> for IssueType {
+    fn from(dto: IssueTypeDto) -> Self {
+        let level = match dto.hierarchy_level {
+            Some(level) if level > 0 => IssueLevel::Epic,
+            Some(level) if level < 0 => IssueLevel::Subtask,
+            Some(_) => IssueLevel::Standard,
+            // Jira Server has no hierarchy levels: epics are recognised by name
+            None if dto.subtask => IssueLevel::Subtask,
+            None if dto.name.eq_ignore_ascii_case("epic") => IssueLevel::Epic,
+            None => IssueLevel::Standard,
+        };
+        IssueType {
+            id: dto.id,
+            name: dto.name,
+            level,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct IssueRefDto {
+    pub key: String,
+}
+
+#[derive(Deserialize)]
+pub struct AttachmentDto {
+    pub id: String,
+    pub filename: String,
+    pub author: Option<UserDto>,
+    pub created: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(rename = "mimeType")]
+    pub mime_type: Option<String>,
+    pub content: String,
+}
+
+impl From<AttachmentDto> for Attachment {
+    fn from(dto: AttachmentDto) -> Self {
+        Attachment {
+            id: dto.id,
+            filename: dto.filename,
+            size: dto.size,
+            mime_type: dto
+                .mime_type
+                .unwrap_or_else(|| "application/octet-stream".to_string()),
+            author: dto
+                .author
+                .map_or_else(|| "Anonymous".to_string(), |a| a.display_name),
+            created_at: chrono::DateTime::parse_from_str(&dto.created, "%Y-%m-%dT%H:%M:%S%.3f%z")
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .unwrap_or_else(|_| chrono::Utc::now()),
+            content_url: dto.content,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct IssueLinkTypesResponseDto {
+    #[serde(rename = "issueLinkTypes")]
+    pub issue_link_types: Vec<IssueLinkTypeDto>,
+}
+
+#[derive(Deserialize)]
+pub struct IssueLinkTypeDto {
+    pub id: String,
+    pub name: String,
+    pub inward: String,
+    pub outward: String,
+}
+
+impl From<IssueLinkTypeDto> for IssueLinkType {
+    fn from(dto: IssueLinkTypeDto) -> Self {
+        IssueLinkType {
+            id: dto.id,
+            name: dto.name,
+            inward: dto.inward,
+            outward: dto.outward,
+        }
+    }
+}
+
+/// A link as listed in `issuelinks`: only the issue at the other end is set.
+#[derive(Deserialize)]
+pub struct IssueLinkDto {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub link_type: IssueLinkTypeDto,
+    #[serde(rename = "inwardIssue")]
+    pub inward_issue: Option<LinkedIssueDto>,
+    #[serde(rename = "outwardIssue")]
+    pub outward_issue: Option<LinkedIssueDto>,
+}
+
+#[derive(Deserialize)]
+pub struct LinkedIssueDto {
+    pub key: String,
+    pub fields: Option<LinkedIssueFieldsDto>,
+}
+
+#[derive(Deserialize)]
+pub struct LinkedIssueFieldsDto {
+    #[serde(default)]
+    pub summary: String,
+    pub status: Option<StatusDto>,
+}
+
+impl IssueLinkDto {
+    /// The link seen from the issue holding it; `None` for a link without its other end.
+    fn into_link(self) -> Option<IssueLink> {
+        // The outward issue is the one this issue points to with the outward description
+        let (direction, other) = match (self.outward_issue, self.inward_issue) {
+            (Some(other), _) => (LinkDirection::Outward, other),
+            (None, Some(other)) => (LinkDirection::Inward, other),
+            (None, None) => return None,
+        };
+        let fields = other.fields;
+        Some(IssueLink {
+            id: self.id,
+            link_type: self.link_type.into(),
+            direction,
+            issue_key: other.key,
+            issue_summary: fields
+                .as_ref()
+                .map(|f| f.summary.clone())
+                .unwrap_or_default(),
+            issue_status: fields
+                .and_then(|f| f.status)
+                .map_or(IssueStatus::Other(String::new()), |s| {
+                    IssueStatus::from_name(&s.name)
+                }),
+        })
+    }
 }
 
 #[derive(Deserialize)]
 pub struct StatusDto {
+    pub id: Option<String>,
     pub name: String,
 }
 
@@ -88,16 +584,81 @@ pub struct PriorityDto {
 pub struct UserDto {
     #[serde(rename = "displayName")]
     pub display_name: String,
+    /// Jira Cloud identifier
+    #[serde(rename = "accountId")]
+    pub account_id: Option<String>,
+    /// Jira Server identifier
+    pub name: Option<String>,
+}
+
+impl From<UserDto> for User {
+    fn from(dto: UserDto) -> Self {
+        User {
+            account_id: dto.account_id.or(dto.name).unwrap_or_default(),
+            display_name: dto.display_name,
+        }
+    }
+}
+
+// --- WATCHERS & VOTES ---
+
+/// `watches` field of an issue, and body of the watchers endpoint (which adds the list).
+#[derive(Deserialize)]
+pub struct WatchesDto {
+    #[serde(rename = "watchCount")]
+    pub watch_count: u64,
+    #[serde(rename = "isWatching")]
+    pub is_watching: bool,
+    #[serde(default)]
+    pub watchers: Vec<UserDto>,
+}
+
+impl From<WatchesDto> for Watchers {
+    fn from(dto: WatchesDto) -> Self {
+        Watchers {
+            count: dto.watch_count,
+            is_watching: dto.is_watching,
+            users: dto.watchers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// `votes` field of an issue, and body of the votes endpoint.
+#[derive(Deserialize)]
+pub struct VotesDto {
+    pub votes: u64,
+    #[serde(rename = "hasVoted")]
+    pub has_voted: bool,
+}
+
+impl From<VotesDto> for Votes {
+    fn from(dto: VotesDto) -> Self {
+        Votes {
+            count: dto.votes,
+            has_voted: dto.has_voted,
+        }
+    }
 }
 
 impl From<IssueDto> for Issue {
     fn from(dto: IssueDto) -> Self {
-        let status = match dto.fields.status.name.to_lowercase().as_str() {
-            "to do" | "new" | "open" => IssueStatus::Todo,
-            "in progress" | "in review" => IssueStatus::InProgress,
-            "done" | "closed" | "resolved" => IssueStatus::Done,
-            _ => IssueStatus::Other(dto.fields.status.name),
-        };
+        dto.into_issue(&[])
+    }
+}
+
+impl IssueDto {
+    /// Converts the issue, reading the values of `custom_fields` from the other fields.
+    pub fn into_issue(mut self, custom_fields: &[FieldMeta]) -> Issue {
+        let fields = custom_fields
+            .iter()
+            .filter_map(|meta| {
+                let value = self.fields.other.remove(&meta.id)?;
+                Some((meta.id.clone(), field_value(&meta.kind, value)?))
+            })
+            .collect();
+        let dto = self;
+
+        let status = IssueStatus::from_name(&dto.fields.status.name);
 
         let created_at =
             chrono::DateTime::parse_from_str(&dto.fields.created, "%Y-%m-%dT%H:%M:%S.%f%z")
@@ -112,9 +673,28 @@ impl From<IssueDto> for Issue {
         Issue {
             key: dto.key,
             summary: dto.fields.summary,
-            description: dto.fields.description,
+            description: dto
+                .fields
+                .description
+                .map(RichText::from)
+                .filter(|d| !d.is_empty()),
             status,
-            assignee: dto.fields.assignee.map(|u| u.display_name),
+            status_id: dto.fields.status.id,
+            issue_type: dto.fields.issuetype.map(Into::into).unwrap_or_default(),
+            parent_key: dto.fields.parent.or(dto.fields.epic).map(|p| p.key),
+            subtask_keys: dto.fields.subtasks.into_iter().map(|s| s.key).collect(),
+            links: dto
+                .fields
+                .issuelinks
+                .into_iter()
+                .filter_map(IssueLinkDto::into_link)
+                .collect(),
+            attachments: dto.fields.attachment.into_iter().map(Into::into).collect(),
+            watchers: dto.fields.watches.map(Into::into).unwrap_or_default(),
+            votes: dto.fields.votes.map(Into::into).unwrap_or_default(),
+            labels: dto.fields.labels,
+            fields,
+            assignee: dto.fields.assignee.map(Into::into),
             priority: dto.fields.priority.map(|p| p.name),
             created_at,
             updated_at,
@@ -122,6 +702,50 @@ impl From<IssueDto> for Issue {
     }
 }
 
+// --- TRANSITIONS ---
+
+#[derive(Deserialize)]
+pub struct TransitionsResponseDto {
+    pub transitions: Vec<TransitionDto>,
+}
+
+#[derive(Deserialize)]
+pub struct TransitionDto {
+    pub id: String,
+    pub name: String,
+    pub to: StatusDto,
+}
+
+impl From<TransitionDto> for Transition {
+    fn from(dto: TransitionDto) -> Self {
+        Transition {
+            id: dto.id,
+            name: dto.name,
+            to_status: IssueStatus::from_name(&dto.to.name),
+            to_status_id: dto.to.id,
+        }
+    }
+}
+
+// --- FILTERS ---
+
+#[derive(Deserialize)]
+pub struct FilterDto {
+    pub id: String,
+    pub name: String,
+    pub jql: Option<String>,
+}
+
+impl From<FilterDto> for SavedQuery {
+    fn from(dto: FilterDto) -> Self {
+        SavedQuery {
+            name: dto.name,
+            jql: dto.jql.unwrap_or_default(),
+            source: SavedQuerySource::JiraFilter(dto.id),
+        }
+    }
+}
+
 // --- WORKLOGS ---
 
 #[derive(Deserialize)]
@@ -141,53 +765,20 @@ pub struct WorklogDto {
     pub issue_id: String,
     #[serde(rename = "timeSpentSeconds")]
     pub time_spent_seconds: u64,
-    pub comment: Option<CommentDto>,
+    pub comment: Option<RichTextDto>,
     pub started: String,
     pub author: UserDto,
     pub created: String,
     pub updated: String,
 }
 
-#[derive(Deserialize)]
-pub struct CommentDto {
-    pub content: Option<Vec<ContentBlockDto>>,
-}
-
-#[derive(Deserialize)]
-pub struct ContentBlockDto {
-    pub content: Option<Vec<ContentTextDto>>,
-}
-
-#[derive(Deserialize)]
-pub struct ContentTextDto {
-    pub text: Option<String>,
-}
-
 impl WorklogDto {
     pub fn to_worklog_entry(self, issue_key: String) -> WorklogEntry {
-        let comment = self.comment.and_then(|c| {
-            c.content.and_then(|blocks| {
-                let texts: Vec<String> = blocks
-                    .into_iter()
-                    .filter_map(|block| {
-                        block.content.and_then(|content| {
-                            let texts: Vec<String> =
-                                content.into_iter().filter_map(|ct| ct.text).collect();
-                            if texts.is_empty() {
-                                None
-                            } else {
-                                Some(texts.join(" "))
-                            }
-                        })
-                    })
-                    .collect();
-                if texts.is_empty() {
-                    None
-                } else {
-                    Some(texts.join("\n"))
-                }
-            })
-        });
+        let comment = self
+            .comment
+            .map(RichText::from)
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_markdown());
 
         let started_at = chrono::DateTime::parse_from_str(&self.started, "%Y-%m-%dT%H:%M:%S%.3f%z")
             .map(|dt| dt.with_timezone(&chrono::Utc))
@@ -213,3 +804,352 @@ impl WorklogDto {
         }
     }
 }
+
+// --- COMMENTS ---
+
+#[derive(Deserialize)]
+pub struct CommentsResponseDto {
+    #[serde(rename = "startAt")]
+    pub start_at: u64,
+    pub total: u64,
+    pub comments: Vec<CommentDto>,
+}
+
+#[derive(Deserialize)]
+pub struct CommentDto {
+    pub id: String,
+    pub author: Option<UserDto>,
+    pub body: Option<RichTextDto>,
+    pub created: String,
+    pub updated: String,
+}
+
+impl From<CommentDto> for Comment {
+    fn from(dto: CommentDto) -> Self {
+        let parse = |timestamp: &str| {
+            chrono::DateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.3f%z")
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .unwrap_or_else(|_| chrono::Utc::now())
+        };
+
+        Comment {
+            id: dto.id,
+            author: dto
+                .author
+                .map_or_else(|| "Anonymous".to_string(), |a| a.display_name),
+            body: dto.body.map(RichText::from).unwrap_or_default(),
+            created_at: parse(&dto.created),
+            updated_at: parse(&dto.updated),
+        }
+    }
+}
+
+// --- CHANGELOG ---
+
+/// Page of `issue/{key}/changelog` (Jira Cloud).
+#[derive(Deserialize)]
+pub struct ChangelogPageDto {
+    #[serde(rename = "startAt")]
+    pub start_at: u64,
+    pub total: u64,
+    pub values: Vec<ChangelogEntryDto>,
+}
+
+/// `issue/{key}?expand=changelog` (Jira Server, which has no changelog endpoint).
+#[derive(Deserialize)]
+pub struct ExpandedChangelogDto {
+    pub changelog: ExpandedHistoriesDto,
+}
+
+#[derive(Deserialize)]
+pub struct ExpandedHistoriesDto {
+    #[serde(default)]
+    pub histories: Vec<ChangelogEntryDto>,
+}
+
+#[derive(Deserialize)]
+pub struct ChangelogEntryDto {
+    pub id: String,
+    pub author: Option<UserDto>,
+    pub created: String,
+    #[serde(default)]
+    pub items: Vec<ChangelogItemDto>,
+}
+
+#[derive(Deserialize)]
+pub struct ChangelogItemDto {
+    pub field: String,
+    /// Display value; the raw `from`/`to` hold IDs
+    #[serde(rename = "fromString")]
+    pub from_string: Option<String>,
+    #[serde(rename = "toString")]
+    pub to_string: Option<String>,
+}
+
+impl From<ChangelogEntryDto> for ChangelogEntry {
+    fn from(dto: ChangelogEntryDto) -> Self {
+        let non_empty = |value: Option<String>| value.filter(|v| !v.is_empty());
+        ChangelogEntry {
+            id: dto.id,
+            author: dto
+                .author
+                .map_or_else(|| "Anonymous".to_string(), |a| a.display_name),
+            created_at: chrono::DateTime::parse_from_str(&dto.created, "%Y-%m-%dT%H:%M:%S%.3f%z")
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .unwrap_or_else(|_| chrono::Utc::now()),
+            changes: dto
+                .items
+                .into_iter()
+                .map(|item| FieldChange {
+                    field: item.field,
+                    from: non_empty(item.from_string),
+                    to: non_empty(item.to_string),
+                })
+                .collect(),
+        }
+    }
+}
+
+// --- CREATE / EDIT METADATA ---
+
+/// Response of `issue/createmeta/{project}/issuetypes` (`values` on Jira Server).
+#[derive(Deserialize)]
+pub struct CreateMetaIssueTypesDto {
+    #[serde(rename = "issueTypes", alias = "values", default)]
+    pub issue_types: Vec<IssueTypeDto>,
+    #[serde(flatten)]
+    pub page: MetaPageDto,
+}
+
+/// Response of `issue/createmeta/{project}/issuetypes/{id}` (`values` on Jira Server).
+#[derive(Deserialize)]
+pub struct CreateMetaFieldsDto {
+    #[serde(alias = "values", default)]
+    pub fields: Vec<FieldMetaDto>,
+    #[serde(flatten)]
+    pub page: MetaPageDto,
+}
+
+#[derive(Deserialize)]
+pub struct MetaPageDto {
+    #[serde(rename = "startAt", default)]
+    pub start_at: u64,
+    pub total: Option<u64>,
+    #[serde(rename = "isLast")]
+    pub is_last: Option<bool>,
+}
+
+impl MetaPageDto {
+    /// Offset of the next page, if `count` items did not exhaust the list.
+    pub fn next_start(&self, count: usize) -> Option<u64> {
+        let end = self.start_at + count as u64;
+        let more = match (self.is_last, self.total) {
+            (Some(is_last), _) => !is_last,
+            (None, Some(total)) => end < total,
+            (None, None) => false,
+        };
+        (more && count > 0).then_some(end)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct FieldMetaDto {
+    /// Missing from `editmeta`, where the fields are keyed by ID instead
+    #[serde(rename = "fieldId", default)]
+    pub field_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(rename = "hasDefaultValue", default)]
+    pub has_default_value: bool,
+    pub schema: FieldSchemaDto,
+    #[serde(rename = "allowedValues", default)]
+    pub allowed_values: Vec<AllowedValueDto>,
+}
+
+#[derive(Deserialize)]
+pub struct FieldSchemaDto {
+    #[serde(rename = "type")]
+    pub field_type: String,
+    pub items: Option<String>,
+    pub system: Option<String>,
+    pub custom: Option<String>,
+}
+
+/// An allowed value: priorities and components have a `name`, select options a `value`.
+#[derive(Deserialize)]
+pub struct AllowedValueDto {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub value: Option<String>,
+}
+
+impl FieldSchemaDto {
+    /// How a field of this schema is edited and read.
+    pub fn kind(&self, has_options: bool) -> FieldKind {
+        let system = self.system.as_deref().unwrap_or_default();
+        let custom = self.custom.as_deref().unwrap_or_default();
+
+        match (self.field_type.as_str(), self.items.as_deref()) {
+            _ if system == "parent" || custom.ends_with(":gh-epic-link") => FieldKind::IssueKey,
+            ("string", _)
+                if system == "description"
+                    || system == "environment"
+                    || custom.ends_with(":textarea") =>
+            {
+                FieldKind::RichText
+            }
+            ("string", _) if has_options => FieldKind::Choice,
+            ("string", _) => FieldKind::Text,
+            ("number", _) => FieldKind::Number,
+            ("user", _) => FieldKind::User,
+            ("array", Some(_)) if has_options => FieldKind::MultiChoice,
+            ("array", Some("string")) => FieldKind::Labels,
+            _ if has_options => FieldKind::Choice,
+            // The field list has no allowed values, only the option types
+            ("option", _) => FieldKind::Choice,
+            ("array", Some("option")) => FieldKind::MultiChoice,
+            ("date", _) => FieldKind::Date,
+            (other, _) => FieldKind::Unsupported(other.to_string()),
+        }
+    }
+}
+
+impl From<FieldMetaDto> for FieldMeta {
+    fn from(dto: FieldMetaDto) -> Self {
+        let kind = dto.schema.kind(!dto.allowed_values.is_empty());
+
+        FieldMeta {
+            id: dto.field_id,
+            name: dto.name,
+            kind,
+            required: dto.required,
+            has_default: dto.has_default_value,
+            allowed_values: dto
+                .allowed_values
+                .into_iter()
+                .filter_map(|v| {
+                    let name = v.name.or(v.value)?;
+                    Some(FieldOption {
+                        id: v.id.unwrap_or_else(|| name.clone()),
+                        name,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// An entry of the `field` endpoint, which lists every system and custom field.
+#[derive(Deserialize)]
+pub struct FieldDto {
+    pub id: String,
+    pub name: String,
+    /// Missing for a few system fields that cannot be searched or shown
+    pub schema: Option<FieldSchemaDto>,
+}
+
+impl From<FieldDto> for FieldMeta {
+    fn from(dto: FieldDto) -> Self {
+        let kind = match &dto.schema {
+            Some(schema) => schema.kind(false),
+            None => FieldKind::Unsupported("unknown".to_string()),
+        };
+        FieldMeta {
+            id: dto.id,
+            name: dto.name,
+            kind,
+            required: false,
+            has_default: false,
+            allowed_values: Vec::new(),
+        }
+    }
+}
+
+/// Reads a field value the way its kind is sent by Jira; `None` for empty or unreadable values.
+fn field_value(kind: &FieldKind, value: serde_json::Value) -> Option<FieldValue> {
+    use serde_json::Value;
+
+    // Select options carry a `value`, most other objects a `name`
+    let option = |value: Value| -> Option<FieldOption> {
+        let name = value
+            .get("value")
+            .or_else(|| value.get("name"))?
+            .as_str()?
+            .to_string();
+        let id = value.get("id").and_then(Value::as_str).unwrap_or(&name);
+        Some(FieldOption {
+            id: id.to_string(),
+            name,
+        })
+    };
+
+    match (kind, value) {
+        (_, Value::Null) => None,
+        (FieldKind::Number, Value::Number(number)) => number.as_f64().map(FieldValue::Number),
+        (FieldKind::Choice, value) => option(value).map(FieldValue::Option),
+        (FieldKind::MultiChoice, Value::Array(values)) => {
+            let options: Vec<FieldOption> = values.into_iter().filter_map(option).collect();
+            (!options.is_empty()).then_some(FieldValue::Options(options))
+        }
+        (FieldKind::Labels, Value::Array(values)) => {
+            let labels: Vec<String> = values
+                .into_iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect();
+            (!labels.is_empty()).then_some(FieldValue::Labels(labels))
+        }
+        (FieldKind::User, value) => serde_json::from_value::<UserDto>(value)
+            .ok()
+            .map(|user| FieldValue::Account(user.into())),
+        (FieldKind::Date, Value::String(text)) => {
+            chrono::NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+                .ok()
+                .map(FieldValue::Date)
+        }
+        (FieldKind::IssueKey, Value::String(key)) => Some(FieldValue::IssueKey(key)),
+        // Text, and the kinds this client cannot edit, are shown as they come
+        (_, Value::String(text)) if !text.is_empty() => Some(FieldValue::Text(text)),
+        (_, Value::Number(number)) => number.as_f64().map(FieldValue::Number),
+        _ => None,
+    }
+}
+
+/// Response of `issue/{key}/editmeta`: the fields the current user can edit.
+#[derive(Deserialize)]
+pub struct EditMetaDto {
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldMetaDto>,
+}
+
+impl EditMetaDto {
+    pub fn into_fields(self) -> Vec<FieldMeta> {
+        self.fields
+            .into_iter()
+            .map(|(id, mut field)| {
+                field.field_id = id;
+                field.into()
+            })
+            .collect()
+    }
+}
+
+/// Response of `POST issue`.
+#[derive(Deserialize)]
+pub struct CreatedIssueDto {
+    pub key: String,
+}
+
+// --- ERRORS ---
+
+/// Body of a failed request: `{"errorMessages": [...], "errors": {"field": "message"}}`.
+///
+/// Some endpoints (Agile API, gateways) answer with a single `message` instead.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct ErrorResponseDto {
+    #[serde(rename = "errorMessages")]
+    pub error_messages: Vec<String>,
+    pub errors: BTreeMap<String, String>,
+    pub message: Option<String>,
+}
@@ -1,2 +1,5 @@
+pub mod adf;
 pub mod client;
 pub mod dtos;
+pub mod retry;
+pub mod wiki;
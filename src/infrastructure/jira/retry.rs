@@ -0,0 +1,265 @@
+use crate::infrastructure::config::HttpConfig;
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, Method, Request, Response, StatusCode};
+use std::time::Duration;
+
+/// Sends `request`, retrying transient failures according to `config`.
+///
+/// Idempotent methods (GET, PUT, DELETE, ...) are retried on connection errors, timeouts,
+/// 429 and 502/503/504. Other methods (POST) are only retried when the request certainly
+/// was not processed: the connection could not be established, or Jira answered 429.
+/// Waits grow exponentially with full jitter; a `Retry-After` header takes precedence.
+///
+/// Once the retries are exhausted the last response (or error) is returned as-is, so callers
+/// keep mapping status codes to errors themselves.
+pub async fn execute(
+    client: &Client,
+    request: Request,
+    config: &HttpConfig,
+) -> reqwest::Result<Response> {
+    let idempotent = is_idempotent(request.method());
+    let mut attempt = 0;
+
+    loop {
+        // Requests with streaming bodies cannot be cloned, hence not retried
+        let retry_copy = if attempt < config.max_retries {
+            request.try_clone()
+        } else {
+            None
+        };
+        let Some(current) = retry_copy else {
+            return client.execute(request).await;
+        };
+
+        let outcome = client.execute(current).await;
+        let delay = match &outcome {
+            Ok(response) => response_delay(response, idempotent, config, attempt),
+            Err(error) => error_delay(error, idempotent, config, attempt),
+        };
+        let Some(delay) = delay else {
+            return outcome;
+        };
+
+        log::warn!(
+            "{} {} failed ({}), retrying in {:?} ({}/{})",
+            request.method(),
+            request.url().path(),
+            match &outcome {
+                Ok(response) => response.status().to_string(),
+                Err(error) => error.to_string(),
+            },
+            delay,
+            attempt + 1,
+            config.max_retries
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// How long to wait before retrying after `response`, or `None` to return it.
+fn response_delay(
+    response: &Response,
+    idempotent: bool,
+    config: &HttpConfig,
+    attempt: u32,
+) -> Option<Duration> {
+    let status = response.status();
+    let retryable = match status {
+        // Rate limited: the request was rejected before doing anything
+        StatusCode::TOO_MANY_REQUESTS => true,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => {
+            idempotent
+        }
+        _ => false,
+    };
+    if !retryable {
+        return None;
+    }
+
+    let max_wait = Duration::from_secs(config.max_backoff_secs);
+    match retry_after(response.headers()) {
+        // Retrying earlier than asked would only be rejected again
+        Some(wait) if wait > max_wait => None,
+        Some(wait) => Some(wait),
+        None => Some(backoff(config, attempt)),
+    }
+}
+
+/// How long to wait before retrying after a transport error, or `None` to give up.
+fn error_delay(
+    error: &reqwest::Error,
+    idempotent: bool,
+    config: &HttpConfig,
+    attempt: u32,
+) -> Option<Duration> {
+    // A failed connection means nothing reached the server, so even a POST is safe
+    let retryable =
+        error.is_connect() || (idempotent && (error.is_timeout() || error.is_request()));
+    retryable.then(|| backoff(config, attempt))
+}
+
+/// Exponential backoff with full jitter: a random wait in `[0, initial * 2^attempt]`.
+fn backoff(config: &HttpConfig, attempt: u32) -> Duration {
+    let cap = Duration::from_millis(config.initial_backoff_ms)
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(Duration::from_secs(config.max_backoff_secs));
+    let cap_ms = cap.as_millis() as u64;
+    Duration::from_millis(rand::thread_rng().gen_range(0..=cap_ms))
+}
+
+/// Parses `Retry-After`, given either in seconds or as an HTTP date.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.signed_duration_since(chrono::Utc::now());
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn config(max_retries: u32) -> HttpConfig {
+        HttpConfig {
+            max_retries,
+            timeout_secs: 1,
+            initial_backoff_ms: 1,
+            max_backoff_secs: 2,
+        }
+    }
+
+    async fn send(server: &MockServer, method: Method, config: &HttpConfig) -> Response {
+        let client = Client::new();
+        let request = client
+            .request(method, format!("{}/resource", server.uri()))
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        execute(&client, request, config).await.unwrap()
+    }
+
+    /// Answers with `template` `times` times, then 200.
+    async fn failing(server: &MockServer, template: ResponseTemplate, times: u64) {
+        Mock::given(path("/resource"))
+            .respond_with(template)
+            .up_to_n_times(times)
+            .with_priority(1)
+            .mount(server)
+            .await;
+        Mock::given(path("/resource"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn retries_idempotent_requests_on_server_errors() {
+        let server = MockServer::start().await;
+        failing(&server, ResponseTemplate::new(503), 2).await;
+
+        let response = send(&server, Method::GET, &config(3)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn returns_the_last_response_when_retries_run_out() {
+        let server = MockServer::start().await;
+        failing(&server, ResponseTemplate::new(502), 10).await;
+
+        let response = send(&server, Method::DELETE, &config(2)).await;
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn post_is_retried_on_rate_limit_only() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/resource"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        failing(&server, ResponseTemplate::new(503), 1).await;
+
+        // 429 is retried, the following 503 is returned as-is
+        let response = send(&server, Method::POST, &config(3)).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_after_beyond_the_limit_is_not_waited_for() {
+        let server = MockServer::start().await;
+        failing(
+            &server,
+            ResponseTemplate::new(429).insert_header("Retry-After", "3600"),
+            1,
+        )
+        .await;
+
+        let response = send(&server, Method::GET, &config(3)).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn timed_out_attempts_are_retried() {
+        let server = MockServer::start().await;
+        failing(
+            &server,
+            ResponseTemplate::new(200).set_delay(Duration::from_secs(1)),
+            1,
+        )
+        .await;
+
+        let response = send(&server, Method::GET, &config(1)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn parses_retry_after_in_seconds_and_as_a_date() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("12"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(12)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let config = HttpConfig {
+            initial_backoff_ms: 1000,
+            max_backoff_secs: 5,
+            ..HttpConfig::default()
+        };
+        for attempt in 0..40 {
+            assert!(backoff(&config, attempt) <= Duration::from_secs(5));
+        }
+    }
+}
@@ -0,0 +1,684 @@
+use crate::domain::rich_text::{Block, Inline, Mark, PanelKind, RichText, TableCell, TableRow};
+
+/// Encodes user-typed Markdown as Jira wiki markup, ready to be sent to API v2.
+pub fn markdown_to_wiki(text: &str) -> String {
+    to_wiki(&RichText::from_markdown(text))
+}
+
+/// Encodes a rich text document as Jira wiki markup (the text format of Server / Data Center).
+pub fn to_wiki(doc: &RichText) -> String {
+    encode_blocks(&doc.blocks)
+}
+
+/// Parses Jira wiki markup, as returned by API v2 and the Agile API.
+///
+/// Supported: `h1.`-`h6.` headings, `*`/`#` lists (nested by repeating the marker),
+/// `{code}`/`{noformat}`, `{quote}` and `bq.`, `{panel}`/`{info}`/`{note}`/`{warning}`/`{tip}`,
+/// `||`/`|` tables, `----` rules, and inline `*strong*`, `_em_`, `{{code}}`, `-strike-`,
+/// `+underline+`, `[text|url]` links and `[~user]` mentions. Anything else is kept as text.
+pub fn from_wiki(text: &str) -> RichText {
+    let lines: Vec<&str> = text.lines().collect();
+    RichText {
+        blocks: parse_blocks(&lines),
+    }
+}
+
+// --- Encoding ---
+
+fn encode_blocks(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(encode_block)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn encode_block(block: &Block) -> String {
+    match block {
+        Block::Paragraph(content) => encode_inlines(content),
+        Block::Heading { level, content } => format!("h{}. {}", level, encode_inlines(content)),
+        Block::BulletList(items) => encode_list(items, "*"),
+        Block::OrderedList { items, .. } => encode_list(items, "#"),
+        Block::Code { language, text } => match language {
+            Some(language) => format!("{{code:{}}}\n{}\n{{code}}", language, text),
+            None => format!("{{code}}\n{}\n{{code}}", text),
+        },
+        Block::Blockquote(content) => format!("{{quote}}\n{}\n{{quote}}", encode_blocks(content)),
+        Block::Panel { kind, content } => {
+            // Wiki markup has no dedicated error panel
+            let macro_name = match kind {
+                PanelKind::Info => "info",
+                PanelKind::Note => "note",
+                PanelKind::Warning | PanelKind::Error => "warning",
+                PanelKind::Success => "tip",
+            };
+            format!(
+                "{{{name}}}\n{}\n{{{name}}}",
+                encode_blocks(content),
+                name = macro_name
+            )
+        }
+        Block::Table(rows) => rows
+            .iter()
+            .map(|row| {
+                let mut line = String::new();
+                for cell in &row.cells {
+                    let separator = if cell.is_header { "||" } else { "|" };
+                    line.push_str(separator);
+                    line.push_str(&encode_blocks(&cell.content).replace('\n', " "));
+                }
+                let last_separator = match row.cells.last() {
+                    Some(cell) if cell.is_header => "||",
+                    _ => "|",
+                };
+                line.push_str(last_separator);
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Rule => "----".to_string(),
+    }
+}
+
+/// Lists are flat in wiki markup: nesting is expressed by repeating the markers (`*`, `**`, `#*`).
+fn encode_list(items: &[Vec<Block>], marker: &str) -> String {
+    let mut lines = Vec::new();
+    for item in items {
+        let mut first = true;
+        for block in item {
+            match block {
+                Block::BulletList(nested) => {
+                    lines.push(encode_list(nested, &format!("{}*", marker)))
+                }
+                Block::OrderedList { items: nested, .. } => {
+                    lines.push(encode_list(nested, &format!("{}#", marker)))
+                }
+                other if first => {
+                    lines.push(format!("{} {}", marker, encode_block(other)));
+                    first = false;
+                }
+                other => lines.push(encode_block(other)),
+            }
+        }
+        if first {
+            lines.push(format!("{} ", marker));
+        }
+    }
+    lines.join("\n")
+}
+
+fn encode_inlines(inlines: &[Inline]) -> String {
+    let mut out = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text { text, marks } => {
+                let mut encoded = if marks.contains(&Mark::Code) {
+                    text.clone()
+                } else {
+                    escape(text)
+                };
+                for mark in marks {
+                    encoded = match mark {
+                        Mark::Strong => format!("*{}*", encoded),
+                        Mark::Em => format!("_{}_", encoded),
+                        Mark::Code => format!("{{{{{}}}}}", encoded),
+                        Mark::Strike => format!("-{}-", encoded),
+                        Mark::Underline => format!("+{}+", encoded),
+                        Mark::Link(href) if href == text => format!("[{}]", href),
+                        Mark::Link(href) => format!("[{}|{}]", encoded, href),
+                    };
+                }
+                out.push_str(&encoded);
+            }
+            Inline::Mention { account_id, .. } => out.push_str(&format!("[~{}]", account_id)),
+            Inline::Emoji(shortcode) => out.push_str(shortcode),
+            Inline::HardBreak => out.push('\n'),
+        }
+    }
+    out
+}
+
+/// Escapes characters that would otherwise be read as wiki markup.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut previous: Option<char> = None;
+    for c in text.chars() {
+        let at_word_start = previous.is_none_or(|p| !p.is_alphanumeric());
+        let special = matches!(c, '\\' | '*' | '_' | '{' | '}' | '[' | ']' | '|')
+            || (matches!(c, '-' | '+') && at_word_start);
+        if special {
+            out.push('\\');
+        }
+        out.push(c);
+        previous = Some(c);
+    }
+    out
+}
+
+// --- Decoding ---
+
+fn parse_blocks(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+
+        if trimmed.is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if let Some((name, param)) = open_macro(trimmed) {
+            let (body, consumed) = macro_body(&lines[i..], name);
+            i += consumed;
+            match name {
+                "code" | "noformat" => {
+                    let language = param.filter(|p| !p.contains('=')).map(str::to_string);
+                    blocks.push(Block::Code {
+                        language,
+                        text: body.join("\n").trim_matches('\n').to_string(),
+                    });
+                }
+                "quote" => blocks.push(Block::Blockquote(parse_blocks(&body))),
+                _ => blocks.push(Block::Panel {
+                    kind: panel_kind(name),
+                    content: parse_blocks(&body),
+                }),
+            }
+            continue;
+        }
+
+        if let Some((level, content)) = parse_heading(trimmed) {
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inlines(content),
+            });
+            i += 1;
+            continue;
+        }
+
+        if let Some(quoted) = trimmed.strip_prefix("bq. ") {
+            blocks.push(Block::Blockquote(vec![Block::Paragraph(parse_inlines(
+                quoted,
+            ))]));
+            i += 1;
+            continue;
+        }
+
+        if trimmed == "----" {
+            blocks.push(Block::Rule);
+            i += 1;
+            continue;
+        }
+
+        if trimmed.starts_with('|') {
+            let start = i;
+            while i < lines.len() && lines[i].trim_start().starts_with('|') {
+                i += 1;
+            }
+            blocks.push(parse_table(&lines[start..i]));
+            continue;
+        }
+
+        if list_marker(trimmed).is_some() {
+            let mut entries: Vec<(String, Vec<Inline>)> = Vec::new();
+            while i < lines.len() {
+                let line = lines[i].trim();
+                if line.is_empty() {
+                    break;
+                }
+                match list_marker(line) {
+                    Some((markers, content)) => {
+                        entries.push((markers.to_string(), parse_inlines(content)))
+                    }
+                    None if starts_block(line) => break,
+                    None => {
+                        // Continuation of the previous item
+                        if let Some((_, content)) = entries.last_mut() {
+                            content.push(Inline::HardBreak);
+                            content.extend(parse_inlines(line));
+                        }
+                    }
+                }
+                i += 1;
+            }
+            blocks.push(build_list(&entries, 0));
+            continue;
+        }
+
+        // Paragraph: runs until a blank line or the start of another block
+        let mut inlines = Vec::new();
+        while i < lines.len() {
+            let current = lines[i].trim();
+            if current.is_empty() || (!inlines.is_empty() && starts_block(current)) {
+                break;
+            }
+            if !inlines.is_empty() {
+                inlines.push(Inline::HardBreak);
+            }
+            inlines.extend(parse_inlines(current));
+            i += 1;
+        }
+        blocks.push(Block::Paragraph(inlines));
+    }
+
+    blocks
+}
+
+const BLOCK_MACROS: [&str; 8] = [
+    "code", "noformat", "quote", "panel", "info", "note", "warning", "tip",
+];
+
+fn starts_block(line: &str) -> bool {
+    open_macro(line).is_some()
+        || parse_heading(line).is_some()
+        || line.starts_with("bq. ")
+        || line == "----"
+        || line.starts_with('|')
+        || list_marker(line).is_some()
+}
+
+/// Recognises the opening tag of a block macro, e.g. `{code:rust}`. Returns its name and parameter.
+fn open_macro(line: &str) -> Option<(&'static str, Option<&str>)> {
+    let rest = line.strip_prefix('{')?;
+    let end = rest.find('}')?;
+    let tag = &rest[..end];
+    let (name, param) = match tag.split_once(':') {
+        Some((name, param)) => (name, Some(param)),
+        None => (tag, None),
+    };
+    BLOCK_MACROS
+        .iter()
+        .find(|m| **m == name)
+        .map(|m| (*m, param))
+}
+
+/// Collects the lines between a macro's opening and closing tags.
+///
+/// Content may share a line with either tag (`{code}x = 1{code}`). Returns the body
+/// and the number of lines consumed, including the tags.
+fn macro_body<'a>(lines: &[&'a str], name: &str) -> (Vec<&'a str>, usize) {
+    let closing = format!("{{{}}}", name);
+    let first = lines[0].trim();
+    let after_open = &first[first.find('}').map_or(first.len(), |p| p + 1)..];
+
+    if let Some(end) = after_open.find(&closing) {
+        return (vec![&after_open[..end]], 1);
+    }
+
+    let mut body = Vec::new();
+    if !after_open.is_empty() {
+        body.push(after_open);
+    }
+    for (i, line) in lines.iter().enumerate().skip(1) {
+        if let Some(end) = line.find(&closing) {
+            if !line[..end].trim().is_empty() {
+                body.push(&line[..end]);
+            }
+            return (body, i + 1);
+        }
+        body.push(line);
+    }
+    // Unterminated macro: the rest of the text is its body
+    (body, lines.len())
+}
+
+fn panel_kind(name: &str) -> PanelKind {
+    match name {
+        "info" => PanelKind::Info,
+        "warning" => PanelKind::Warning,
+        "tip" => PanelKind::Success,
+        _ => PanelKind::Note,
+    }
+}
+
+fn parse_heading(line: &str) -> Option<(u8, &str)> {
+    let rest = line.strip_prefix('h')?;
+    let level = rest.chars().next()?.to_digit(10)?;
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    rest[1..]
+        .strip_prefix(". ")
+        .map(|content| (level as u8, content.trim()))
+}
+
+/// Recognises list lines such as `* item`, `## item` or `#* item`. Returns the markers and content.
+fn list_marker(line: &str) -> Option<(&str, &str)> {
+    let depth = line
+        .chars()
+        .take_while(|c| matches!(c, '*' | '#' | '-'))
+        .count();
+    if depth == 0 || line == "----" {
+        return None;
+    }
+    let markers = &line[..depth];
+    // `-` only works as a single-level bullet
+    if markers.contains('-') && markers != "-" {
+        return None;
+    }
+    line[depth..]
+        .strip_prefix(' ')
+        .map(|content| (markers, content.trim()))
+}
+
+/// Builds a (possibly nested) list from flat `(markers, content)` entries, starting at `depth`.
+fn build_list(entries: &[(String, Vec<Inline>)], depth: usize) -> Block {
+    let ordered = entries
+        .first()
+        .and_then(|(markers, _)| markers.chars().nth(depth))
+        == Some('#');
+
+    let mut items: Vec<Vec<Block>> = Vec::new();
+    let mut i = 0;
+    while i < entries.len() {
+        let (markers, content) = &entries[i];
+        if markers.len() <= depth + 1 || items.is_empty() {
+            items.push(vec![Block::Paragraph(content.clone())]);
+            i += 1;
+            continue;
+        }
+
+        // Deeper entries belong to the current item as a nested list
+        let start = i;
+        while i < entries.len() && entries[i].0.len() > depth + 1 {
+            i += 1;
+        }
+        if let Some(item) = items.last_mut() {
+            item.push(build_list(&entries[start..i], depth + 1));
+        }
+    }
+
+    if ordered {
+        Block::OrderedList { start: 1, items }
+    } else {
+        Block::BulletList(items)
+    }
+}
+
+fn parse_table(lines: &[&str]) -> Block {
+    let rows = lines
+        .iter()
+        .map(|line| {
+            let mut cells = Vec::new();
+            let mut rest = line.trim();
+            while !rest.is_empty() {
+                let is_header = rest.starts_with("||");
+                rest = rest.trim_start_matches('|');
+                let end = find_cell_end(rest);
+                let cell = rest[..end].trim();
+                rest = &rest[end..];
+                if cell.is_empty() && rest.is_empty() {
+                    break;
+                }
+                cells.push(TableCell {
+                    is_header,
+                    content: if cell.is_empty() {
+                        Vec::new()
+                    } else {
+                        vec![Block::Paragraph(parse_inlines(cell))]
+                    },
+                });
+            }
+            TableRow { cells }
+        })
+        .collect();
+    Block::Table(rows)
+}
+
+/// Finds the `|` ending a table cell, ignoring the ones inside `[text|url]` links.
+fn find_cell_end(text: &str) -> usize {
+    let mut in_link = false;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => in_link = true,
+            ']' => in_link = false,
+            '|' if !in_link => return i,
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+fn parse_inlines(text: &str) -> Vec<Inline> {
+    let mut out: Vec<Inline> = Vec::new();
+    let mut buffer = String::new();
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    let flush = |buffer: &mut String, out: &mut Vec<Inline>| {
+        if !buffer.is_empty() {
+            push_inline(out, Inline::text(std::mem::take(buffer)));
+        }
+    };
+
+    while i < chars.len() {
+        // `\\` is a forced line break, any other backslash escapes the next character
+        if starts_at(&chars, i, "\\\\") {
+            flush(&mut buffer, &mut out);
+            out.push(Inline::HardBreak);
+            i += 2;
+            continue;
+        }
+        if chars[i] == '\\' && i + 1 < chars.len() {
+            buffer.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        if starts_at(&chars, i, "{{")
+            && let Some(end) = find(&chars, i + 2, "}}")
+        {
+            flush(&mut buffer, &mut out);
+            let code: String = chars[i + 2..end].iter().collect();
+            push_inline(
+                &mut out,
+                Inline::Text {
+                    text: code,
+                    marks: vec![Mark::Code],
+                },
+            );
+            i = end + 2;
+            continue;
+        }
+
+        // Colour tags only carry presentation: keep the text inside them
+        if starts_at(&chars, i, "{color")
+            && let Some(end) = find(&chars, i, "}")
+        {
+            i = end + 1;
+            continue;
+        }
+
+        if chars[i] == '['
+            && let Some(end) = find(&chars, i + 1, "]")
+        {
+            flush(&mut buffer, &mut out);
+            let inner: String = chars[i + 1..end].iter().collect();
+            if let Some(user) = inner.strip_prefix('~') {
+                let account_id = user.strip_prefix("accountid:").unwrap_or(user);
+                out.push(Inline::Mention {
+                    account_id: account_id.to_string(),
+                    text: format!("@{}", account_id),
+                });
+            } else {
+                let (label, href) = match inner.rsplit_once('|') {
+                    Some((label, href)) => (label.to_string(), href.trim().to_string()),
+                    None => (inner.clone(), inner.trim().to_string()),
+                };
+                for inline in parse_inlines(&label) {
+                    push_inline(&mut out, with_mark(inline, Mark::Link(href.clone())));
+                }
+            }
+            i = end + 1;
+            continue;
+        }
+
+        let delimiters = [
+            ('*', Mark::Strong),
+            ('_', Mark::Em),
+            ('-', Mark::Strike),
+            ('+', Mark::Underline),
+        ];
+        if let Some((delim, mark)) = delimiters.iter().find(|(d, _)| chars[i] == *d)
+            && opens_at(&chars, i)
+            && let Some(end) = find_delimiter_close(&chars, i + 1, *delim)
+        {
+            flush(&mut buffer, &mut out);
+            let inner: String = chars[i + 1..end].iter().collect();
+            for inline in parse_inlines(&inner) {
+                push_inline(&mut out, with_mark(inline, mark.clone()));
+            }
+            i = end + 1;
+            continue;
+        }
+
+        buffer.push(chars[i]);
+        i += 1;
+    }
+
+    flush(&mut buffer, &mut out);
+    out
+}
+
+/// A delimiter opens a span at a word start and when followed by a non-space character.
+fn opens_at(chars: &[char], i: usize) -> bool {
+    let after_boundary = i == 0 || !chars[i - 1].is_alphanumeric();
+    let followed_by_text = chars.get(i + 1).is_some_and(|c| !c.is_whitespace());
+    after_boundary && followed_by_text
+}
+
+/// Finds a closing delimiter preceded by text and followed by a word boundary.
+fn find_delimiter_close(chars: &[char], from: usize, delim: char) -> Option<usize> {
+    (from + 1..chars.len()).find(|&j| {
+        chars[j] == delim
+            && !chars[j - 1].is_whitespace()
+            && chars[j - 1] != '\\'
+            && chars.get(j + 1).is_none_or(|c| !c.is_alphanumeric())
+    })
+}
+
+/// Finds the index of the next `delim` in `chars` starting at `from`.
+fn find(chars: &[char], from: usize, delim: &str) -> Option<usize> {
+    (from..chars.len()).find(|&j| starts_at(chars, j, delim))
+}
+
+/// Whether `chars` holds `delim` at index `at`.
+fn starts_at(chars: &[char], at: usize, delim: &str) -> bool {
+    let mut rest = chars[at..].iter();
+    delim.chars().all(|c| rest.next() == Some(&c))
+}
+
+fn with_mark(inline: Inline, mark: Mark) -> Inline {
+    match inline {
+        Inline::Text { text, mut marks } => {
+            marks.push(mark);
+            Inline::Text { text, marks }
+        }
+        other => other,
+    }
+}
+
+/// Appends an inline, merging it with the previous one when both carry the same marks.
+fn push_inline(out: &mut Vec<Inline>, inline: Inline) {
+    if let (
+        Some(Inline::Text { text, marks }),
+        Inline::Text {
+            text: next_text,
+            marks: next_marks,
+        },
+    ) = (out.last_mut(), &inline)
+        && marks == next_marks
+    {
+        text.push_str(next_text);
+        return;
+    }
+    out.push(inline);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes Markdown to wiki markup, decodes it back and compares the Markdown.
+    fn assert_markdown_round_trip(markdown: &str) {
+        let doc = RichText::from_markdown(markdown);
+        let decoded = from_wiki(&to_wiki(&doc));
+        assert_eq!(decoded.to_markdown(), markdown);
+    }
+
+    #[test]
+    fn encodes_markdown_as_wiki_markup() {
+        assert_eq!(
+            markdown_to_wiki("# Title\n\nSome **bold** and `code`\n\n- one\n  - nested"),
+            "h1. Title\n\nSome *bold* and {{code}}\n\n* one\n** nested"
+        );
+        assert_eq!(
+            markdown_to_wiki("[docs](https://example.com)"),
+            "[docs|https://example.com]"
+        );
+    }
+
+    #[test]
+    fn decodes_server_descriptions() {
+        let doc = from_wiki(
+            "h2. Steps\n# open the _app_\n# click *save*\n\n{code:java}\nint x = 1;\n{code}",
+        );
+        assert_eq!(
+            doc.to_markdown(),
+            "## Steps\n\n1. open the *app*\n2. click **save**\n\n```java\nint x = 1;\n```"
+        );
+    }
+
+    #[test]
+    fn inline_markup_needs_word_boundaries() {
+        let doc = from_wiki("2 * 3 = 6, snake_case_name and a-b-c");
+        assert_eq!(
+            doc.blocks,
+            vec![Block::Paragraph(vec![Inline::text(
+                "2 * 3 = 6, snake_case_name and a-b-c"
+            )])]
+        );
+    }
+
+    #[test]
+    fn mentions_and_panels() {
+        let doc = from_wiki("{warning:title=Careful}\nping [~jdoe]\n{warning}");
+        assert_eq!(
+            doc.blocks,
+            vec![Block::Panel {
+                kind: PanelKind::Warning,
+                content: vec![Block::Paragraph(vec![
+                    Inline::text("ping "),
+                    Inline::Mention {
+                        account_id: "jdoe".to_string(),
+                        text: "@jdoe".to_string(),
+                    },
+                ])],
+            }]
+        );
+    }
+
+    #[test]
+    fn round_trips_common_markdown() {
+        assert_markdown_round_trip("First line\nsecond line\n\nAnother paragraph");
+        assert_markdown_round_trip("Some **bold**, *italic*, ~~gone~~ and `a_b`");
+        assert_markdown_round_trip("- one\n- two\n  - nested\n- three");
+        assert_markdown_round_trip("| Name | Value |\n|---|---|\n| a | [b](https://b.io) |");
+        assert_markdown_round_trip("> quoted\n\n---\n\nbelow");
+    }
+
+    #[test]
+    fn literal_markup_characters_are_escaped() {
+        let doc = RichText {
+            blocks: vec![Block::Paragraph(vec![Inline::text(
+                "*not bold* _nor em_ -nor strike- [nor link]",
+            )])],
+        };
+        assert_eq!(from_wiki(&to_wiki(&doc)), doc);
+    }
+}
@@ -1,2 +1,4 @@
 pub mod config;
+pub mod credentials;
 pub mod jira;
+pub mod saved_queries;
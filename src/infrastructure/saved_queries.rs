@@ -0,0 +1,173 @@
+use crate::domain::errors::{AppError, Result};
+use crate::domain::models::{SavedQuery, SavedQuerySource};
+use crate::domain::repositories::SavedQueryRepository;
+use crate::infrastructure::config;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// On-disk representation of a saved query.
+#[derive(Serialize, Deserialize)]
+struct SavedQueryEntry {
+    name: String,
+    jql: String,
+}
+
+/// Stores named JQL queries as a JSON array in the user's config directory.
+pub struct FileSavedQueryRepository {
+    /// `None` when there is nowhere to keep the file: no queries, and saving fails.
+    path: Option<PathBuf>,
+}
+
+impl FileSavedQueryRepository {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path: Some(path) }
+    }
+
+    /// A repository without a file, used when the config directory cannot be found.
+    pub fn disabled() -> Self {
+        Self { path: None }
+    }
+
+    /// `~/.config/jira-tui/saved_queries.json`, next to `config.toml`.
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(config::config_dir()?.join("saved_queries.json"))
+    }
+
+    async fn read_entries(&self) -> Result<Vec<SavedQueryEntry>> {
+        let Some(path) = &self.path else {
+            return Ok(Vec::new());
+        };
+        match tokio::fs::read_to_string(path).await {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| AppError::ConfigError(format!("Invalid {}: {}", path.display(), e))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn write_entries(&self, entries: &[SavedQueryEntry]) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Err(AppError::ConfigError(
+                "No config directory to save queries in".to_string(),
+            ));
+        };
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let content =
+            serde_json::to_string_pretty(entries).map_err(|e| AppError::Unknown(e.to_string()))?;
+        tokio::fs::write(path, content).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SavedQueryRepository for FileSavedQueryRepository {
+    async fn load(&self) -> Result<Vec<SavedQuery>> {
+        Ok(self
+            .read_entries()
+            .await?
+            .into_iter()
+            .map(|e| SavedQuery {
+                name: e.name,
+                jql: e.jql,
+                source: SavedQuerySource::Local,
+            })
+            .collect())
+    }
+
+    async fn save(&self, query: SavedQuery) -> Result<()> {
+        let mut entries = self.read_entries().await?;
+        entries.retain(|e| e.name != query.name);
+        entries.push(SavedQueryEntry {
+            name: query.name,
+            jql: query.jql,
+        });
+        self.write_entries(&entries).await
+    }
+
+    async fn delete(&self, name: &str) -> Result<()> {
+        let mut entries = self.read_entries().await?;
+        entries.retain(|e| e.name != name);
+        self.write_entries(&entries).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("jira-tui-{}-{}", std::process::id(), name))
+    }
+
+    fn query(name: &str, jql: &str) -> SavedQuery {
+        SavedQuery {
+            name: name.to_string(),
+            jql: jql.to_string(),
+            source: SavedQuerySource::Local,
+        }
+    }
+
+    #[tokio::test]
+    async fn queries_round_trip_and_are_replaced_by_name() {
+        let dir = temp_path("saved-queries");
+        let path = dir.join("saved_queries.json");
+        let repo = FileSavedQueryRepository::new(path.clone());
+        assert!(repo.load().await.unwrap().is_empty());
+
+        repo.save(query("Mine", "assignee = currentUser()"))
+            .await
+            .unwrap();
+        repo.save(query("Bugs", "type = Bug")).await.unwrap();
+        repo.save(query(
+            "Mine",
+            "assignee = currentUser() ORDER BY updated DESC",
+        ))
+        .await
+        .unwrap();
+
+        let loaded = FileSavedQueryRepository::new(path).load().await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            loaded,
+            vec![
+                query("Bugs", "type = Bug"),
+                query("Mine", "assignee = currentUser() ORDER BY updated DESC"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn deleted_queries_are_gone() {
+        let path = temp_path("saved_queries.json");
+        let repo = FileSavedQueryRepository::new(path.clone());
+        repo.save(query("Mine", "assignee = currentUser()"))
+            .await
+            .unwrap();
+        repo.save(query("Bugs", "type = Bug")).await.unwrap();
+
+        repo.delete("Mine").await.unwrap();
+        repo.delete("Unknown").await.unwrap();
+        let loaded = repo.load().await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, vec![query("Bugs", "type = Bug")]);
+    }
+
+    #[tokio::test]
+    async fn invalid_files_are_reported_and_disabled_repositories_are_empty() {
+        let path = temp_path("invalid_saved_queries.json");
+        std::fs::write(&path, "not json").unwrap();
+        let err = FileSavedQueryRepository::new(path.clone())
+            .load()
+            .await
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(err, AppError::ConfigError(_)));
+
+        let disabled = FileSavedQueryRepository::disabled();
+        assert!(disabled.load().await.unwrap().is_empty());
+        assert!(disabled.save(query("Bugs", "type = Bug")).await.is_err());
+    }
+}
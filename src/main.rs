@@ -3,6 +3,7 @@
 #![allow(unused)]
 #![allow(clippy::wrong_self_convention)]
 #![allow(clippy::module_inception)]
+#![allow(clippy::collapsible_match)]
 
 mod application;
 mod domain;
@@ -10,46 +11,349 @@ mod infrastructure;
 mod ui;
 
 use dotenv::dotenv;
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::application::use_cases::{
-    AddWorklogUseCase, DeleteWorklogUseCase, GetBacklogUseCase, GetBoardsUseCase,
-    GetWorklogsUseCase, UpdateWorklogUseCase,
+    AddCommentUseCase, AddWorklogUseCase, AssignIssueUseCase, CreateIssueUseCase,
+    DeleteCommentUseCase, DeleteIssueLinkUseCase, DeleteSavedQueryUseCase, DeleteWorklogUseCase,
+    DownloadAttachmentUseCase, GetBacklogUseCase, GetBoardConfigurationUseCase, GetBoardsUseCase,
+    GetChangelogUseCase, GetCommentsUseCase, GetCreateFieldsUseCase, GetCreateIssueTypesUseCase,
+    GetCurrentUserUseCase, GetCustomFieldsUseCase, GetEditFieldsUseCase, GetIssueLinkTypesUseCase,
+    GetIssueUseCase, GetSavedQueriesUseCase, GetSprintsUseCase, GetTransitionsUseCase,
+    GetWatchersUseCase, GetWorklogsUseCase, LinkIssuesUseCase, MoveIssueToSprintUseCase,
+    SaveQueryUseCase, SearchAssignableUsersUseCase, SearchIssuesUseCase, SearchUsersUseCase,
+    TransitionIssueUseCase, UpdateCommentUseCase, UpdateIssueUseCase, UpdateWorklogUseCase,
+    UploadAttachmentUseCase, VoteIssueUseCase, WatchIssueUseCase,
 };
-use crate::infrastructure::config::JiraConfig;
+use crate::domain::errors::{AppError, Result as AppResult};
+use crate::infrastructure::config::{AppConfig, AuthMethod, CredentialBackend, CustomFieldConfig};
+use crate::infrastructure::credentials::{self, EncryptedFileCredentials};
 use crate::infrastructure::jira::client::JiraClient;
-use crate::ui::app::{Action, App};
+use crate::infrastructure::saved_queries::FileSavedQueryRepository;
+use crate::ui::app::{Action, App, LoginStorage, RetryableTask};
 use crate::ui::events::{Event, EventHandler};
 use crate::ui::handlers;
 use crate::ui::keys;
 use crate::ui::tui;
 use crate::ui::ui::render;
 
+/// Reads the `--profile <name>` (or `--profile=<name>`, `-p <name>`) command line switch.
+fn parse_profile_arg(mut args: impl Iterator<Item = String>) -> Result<Option<String>, String> {
+    let mut profile = None;
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--profile=") {
+            profile = Some(value.to_string());
+        } else if arg == "--profile" || arg == "-p" {
+            profile = Some(args.next().ok_or("--profile requires a profile name")?);
+        } else {
+            return Err(format!("Unknown argument '{}'", arg));
+        }
+    }
+    Ok(profile)
+}
+
+/// Result of one step of the start-up screens.
+enum Setup {
+    /// A token is available: the session can start.
+    Ready(Box<AppConfig>, String),
+    /// Keep showing the current screen.
+    Pending,
+}
+
+/// Resolves the profile and its API token, going through the configuration error,
+/// login and unlock screens as needed. Returns `None` when the user quits instead.
+async fn start_session(
+    app: &mut App,
+    terminal: &mut tui::Tui,
+    events: &mut EventHandler,
+) -> Result<Option<(AppConfig, JiraClient)>, Box<dyn std::error::Error>> {
+    let path = AppConfig::default_path()?;
+    let loaded = parse_profile_arg(std::env::args().skip(1)).and_then(|profile| {
+        AppConfig::load(&path, profile.as_deref())
+            .map_err(|e| format!("{}\n\nConfig file: {}", e, path.display()))
+    });
+
+    let mut config = None;
+    let mut setup = Setup::Pending;
+    match loaded {
+        Ok(loaded) => {
+            match initial_setup(app, &loaded, &path) {
+                Ok(step) => setup = step,
+                Err(e) => app.update(Action::ShowConfigError(e.to_string())),
+            }
+            config = Some(loaded);
+        }
+        Err(message) => app.update(Action::ShowConfigError(message)),
+    }
+
+    loop {
+        if let Setup::Ready(ready, token) = std::mem::replace(&mut setup, Setup::Pending) {
+            match ready.jira_config(token).and_then(JiraClient::new) {
+                Ok(client) => {
+                    app.update(Action::SessionStarted);
+                    return Ok(Some((*ready, client)));
+                }
+                Err(e) => app.update(Action::ShowConfigError(e.to_string())),
+            }
+        }
+
+        terminal.draw(|frame| render(app, frame))?;
+        let action = match events.next().await {
+            Some(Event::Key(key)) => keys::from_event(key, app),
+            Some(_) => None,
+            None => return Ok(None),
+        };
+        let Some(action) = action else {
+            continue;
+        };
+
+        app.update(action.clone());
+        if app.should_quit {
+            return Ok(None);
+        }
+
+        let Some(config) = &config else {
+            continue;
+        };
+        let step = match action {
+            Action::SubmitLogin => {
+                // Show the loading popup while the token is stored
+                terminal.draw(|frame| render(app, frame))?;
+                complete_login(app, config, &path)
+            }
+            Action::SubmitUnlock => {
+                terminal.draw(|frame| render(app, frame))?;
+                unlock(app, config, &path)
+            }
+            _ => continue,
+        };
+        match step {
+            Ok(next) => setup = next,
+            Err(e) => app.update(Action::LoginFailed(e.to_string())),
+        }
+    }
+}
+
+/// Decides which screen (if any) is needed before connecting.
+fn initial_setup(app: &mut App, config: &AppConfig, path: &Path) -> AppResult<Setup> {
+    if config.missing_setting().is_none() {
+        if let Some(token) = &config.credentials.api_token {
+            return Ok(Setup::Ready(Box::new(config.clone()), token.clone()));
+        }
+        match config.credentials.backend {
+            CredentialBackend::Command => {
+                if let Some(token) = credentials::provider_for(config, path, None)?.load()? {
+                    return Ok(Setup::Ready(Box::new(config.clone()), token));
+                }
+            }
+            CredentialBackend::EncryptedFile => {
+                let file = EncryptedFileCredentials::default_path(config.profile_name())?;
+                if EncryptedFileCredentials::exists(&file) {
+                    app.update(Action::ShowUnlock);
+                    return Ok(Setup::Pending);
+                }
+            }
+            CredentialBackend::Env => {}
+        }
+    }
+
+    show_login(app, config);
+    Ok(Setup::Pending)
+}
+
+fn show_login(app: &mut App, config: &AppConfig) {
+    let mut storage_options = vec![LoginStorage::EncryptedFile, LoginStorage::Env];
+    if config.credentials.store_command.is_some() {
+        storage_options.insert(0, LoginStorage::Command);
+    }
+    // Offer the configured backend first
+    let configured = match config.credentials.backend {
+        CredentialBackend::Env if config.profile.is_some() => Some(LoginStorage::Env),
+        CredentialBackend::Command => Some(LoginStorage::Command),
+        _ => None,
+    };
+    if let Some(pos) = configured.and_then(|c| storage_options.iter().position(|s| *s == c)) {
+        let preferred = storage_options.remove(pos);
+        storage_options.insert(0, preferred);
+    }
+
+    app.update(Action::ShowLogin {
+        base_url: config.base_url.clone().unwrap_or_default(),
+        email: config.email.clone().unwrap_or_default(),
+        needs_email: config.auth == AuthMethod::Basic,
+        storage_options,
+    });
+}
+
+/// Saves the login form to the profile and the chosen credentials backend.
+fn complete_login(app: &App, config: &AppConfig, path: &Path) -> AppResult<Setup> {
+    let required = |value: &str, name: &str| {
+        let value = value.trim();
+        if value.is_empty() {
+            Err(AppError::ConfigError(format!("{} is required", name)))
+        } else {
+            Ok(value.to_string())
+        }
+    };
+
+    let mut config = config.clone();
+    config.base_url = Some(required(&app.login_base_url, "Jira URL")?);
+    if app.login_needs_email {
+        config.email = Some(required(&app.login_email, "Email")?);
+    }
+    let token = required(&app.login_token, "API token")?;
+    config.credentials.backend = match app.login_storage {
+        LoginStorage::EncryptedFile => CredentialBackend::EncryptedFile,
+        LoginStorage::Command => CredentialBackend::Command,
+        LoginStorage::Env => CredentialBackend::Env,
+    };
+    let passphrase = match app.login_storage {
+        LoginStorage::EncryptedFile => Some(required(&app.login_passphrase, "Passphrase")?),
+        _ => None,
+    };
+    config.profile = Some(config.profile_name().to_string());
+
+    credentials::provider_for(&config, path, passphrase)?.store(&token)?;
+    config.save(path)?;
+    Ok(Setup::Ready(Box::new(config), token))
+}
+
+/// Decrypts the credentials file with the passphrase typed in the unlock screen.
+fn unlock(app: &mut App, config: &AppConfig, path: &Path) -> AppResult<Setup> {
+    let provider = credentials::provider_for(config, path, Some(app.login_passphrase.clone()))?;
+    match provider.load()? {
+        Some(token) => Ok(Setup::Ready(Box::new(config.clone()), token)),
+        None => {
+            show_login(app, config);
+            Ok(Setup::Pending)
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
     env_logger::init();
 
+    let mut terminal = tui::init()?;
+    let mut events = EventHandler::new(250);
+    let mut app = App::new();
+
     // 1. Infrastructure
-    let config = JiraConfig::from_env().expect("Failed to load .env config");
-    let jira_client = JiraClient::new(config)?;
+    let (config, jira_client) = match start_session(&mut app, &mut terminal, &mut events).await {
+        Ok(Some(session)) => session,
+        Ok(None) => {
+            tui::restore()?;
+            return Ok(());
+        }
+        Err(e) => {
+            tui::restore()?;
+            return Err(e);
+        }
+    };
+    if let Some(profile) = &config.profile {
+        log::info!("Using profile '{}'", profile);
+    }
     let repo = Arc::new(jira_client);
+    let saved_queries_repo = Arc::new(match FileSavedQueryRepository::default_path() {
+        Ok(path) => FileSavedQueryRepository::new(path),
+        Err(e) => {
+            log::warn!("Saved queries are disabled: {}", e);
+            FileSavedQueryRepository::disabled()
+        }
+    });
 
     // 2. Use Cases
     let get_boards_uc = Arc::new(GetBoardsUseCase::new(repo.clone()));
     let get_backlog_uc = Arc::new(GetBacklogUseCase::new(repo.clone()));
+    let get_board_configuration_uc = Arc::new(GetBoardConfigurationUseCase::new(repo.clone()));
+    let get_sprints_uc = Arc::new(GetSprintsUseCase::new(repo.clone()));
+    let move_issue_to_sprint_uc = Arc::new(MoveIssueToSprintUseCase::new(repo.clone()));
     let add_worklog_uc = Arc::new(AddWorklogUseCase::new(repo.clone()));
     let get_worklogs_uc = Arc::new(GetWorklogsUseCase::new(repo.clone()));
     let update_worklog_uc = Arc::new(UpdateWorklogUseCase::new(repo.clone()));
     let delete_worklog_uc = Arc::new(DeleteWorklogUseCase::new(repo.clone()));
+    let get_transitions_uc = Arc::new(GetTransitionsUseCase::new(repo.clone()));
+    let transition_issue_uc = Arc::new(TransitionIssueUseCase::new(repo.clone()));
+    let get_edit_fields_uc = Arc::new(GetEditFieldsUseCase::new(repo.clone()));
+    let update_issue_uc = Arc::new(UpdateIssueUseCase::new(repo.clone()));
+    let get_comments_uc = Arc::new(GetCommentsUseCase::new(repo.clone()));
+    let add_comment_uc = Arc::new(AddCommentUseCase::new(repo.clone()));
+    let update_comment_uc = Arc::new(UpdateCommentUseCase::new(repo.clone()));
+    let delete_comment_uc = Arc::new(DeleteCommentUseCase::new(repo.clone()));
+    let get_changelog_uc = Arc::new(GetChangelogUseCase::new(repo.clone()));
+    let get_custom_fields_uc = Arc::new(GetCustomFieldsUseCase::new(repo.clone()));
+    let get_watchers_uc = Arc::new(GetWatchersUseCase::new(repo.clone()));
+    let watch_issue_uc = Arc::new(WatchIssueUseCase::new(repo.clone()));
+    let vote_issue_uc = Arc::new(VoteIssueUseCase::new(repo.clone()));
+    let search_users_uc = Arc::new(SearchUsersUseCase::new(repo.clone()));
+    let search_assignable_users_uc = Arc::new(SearchAssignableUsersUseCase::new(repo.clone()));
+    let get_current_user_uc = Arc::new(GetCurrentUserUseCase::new(repo.clone()));
+    let assign_issue_uc = Arc::new(AssignIssueUseCase::new(repo.clone()));
+    let get_issue_uc = Arc::new(GetIssueUseCase::new(repo.clone()));
+    let get_issue_link_types_uc = Arc::new(GetIssueLinkTypesUseCase::new(repo.clone()));
+    let link_issues_uc = Arc::new(LinkIssuesUseCase::new(repo.clone()));
+    let delete_issue_link_uc = Arc::new(DeleteIssueLinkUseCase::new(repo.clone()));
+    let download_attachment_uc = Arc::new(DownloadAttachmentUseCase::new(
+        repo.clone(),
+        config.download_dir.clone(),
+    ));
+    let upload_attachment_uc = Arc::new(UploadAttachmentUseCase::new(repo.clone()));
+    let get_create_issue_types_uc = Arc::new(GetCreateIssueTypesUseCase::new(repo.clone()));
+    let get_create_fields_uc = Arc::new(GetCreateFieldsUseCase::new(repo.clone()));
+    let create_issue_uc = Arc::new(CreateIssueUseCase::new(repo.clone()));
+    let search_issues_uc = Arc::new(SearchIssuesUseCase::new(repo.clone()));
+    let get_saved_queries_uc = Arc::new(GetSavedQueriesUseCase::new(
+        saved_queries_repo.clone(),
+        repo.clone(),
+    ));
+    let save_query_uc = Arc::new(SaveQueryUseCase::new(saved_queries_repo.clone()));
+    let delete_saved_query_uc = Arc::new(DeleteSavedQueryUseCase::new(saved_queries_repo.clone()));
 
     // 3. UI Init
-    let mut app = App::new();
-    let mut terminal = tui::init()?;
-    let mut events = EventHandler::new(250);
+    if let Some(assignee) = config.default_filter.assignee.clone() {
+        app.filter_assignee = assignee;
+    }
+    if let Some(watcher) = config.default_filter.watcher.clone() {
+        app.filter_watcher = watcher;
+    }
+    if let Some(status) = config.default_filter.status.clone() {
+        app.filter_status = status;
+    }
+    if let Some(order_by) = config.default_filter.order_by.clone() {
+        app.filter_order_by = order_by;
+    }
+    let declared = |wanted: fn(&CustomFieldConfig) -> bool| {
+        config
+            .custom_fields
+            .iter()
+            .filter(|field| wanted(field))
+            .map(|field| field.field.clone())
+            .collect()
+    };
+    app.field_columns = declared(|field| field.column);
+    app.field_filters = declared(|field| field.filter);
 
     let (action_tx, mut action_rx) = tokio::sync::mpsc::unbounded_channel();
 
+    if !config.custom_fields.is_empty() {
+        handlers::load_custom_fields(get_custom_fields_uc.clone(), action_tx.clone());
+    }
+
+    // Open the profile's default board straight away
+    if let Some(board_id) = config.default_board {
+        let action = Action::LoadIssues(board_id);
+        handlers::handle_side_effects(
+            &action,
+            &app,
+            get_boards_uc.clone(),
+            get_backlog_uc.clone(),
+            action_tx.clone(),
+        );
+        app.update(action);
+    }
+
     loop {
         terminal.draw(|frame| render(&app, frame))?;
 
@@ -62,11 +366,49 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             // Handle side effects (async network calls)
                             handlers::handle_side_effects(
                                 &action,
+                                &app,
                                 get_boards_uc.clone(),
                                 get_backlog_uc.clone(),
                                 action_tx.clone(),
                             );
 
+                            // Handle Kanban view: columns and card moves
+                            handlers::handle_kanban_configuration(
+                                &action,
+                                &app,
+                                get_board_configuration_uc.clone(),
+                                action_tx.clone(),
+                            );
+                            if let Action::MoveIssueToColumn(column) = &action {
+                                handlers::handle_move_issue_to_column(
+                                    *column,
+                                    &app,
+                                    get_transitions_uc.clone(),
+                                    transition_issue_uc.clone(),
+                                    action_tx.clone(),
+                                );
+                            }
+
+                            // Handle sprint picker
+                            match &action {
+                                Action::OpenSprintPicker | Action::OpenMoveToSprint => {
+                                    handlers::handle_load_sprints(
+                                        &app,
+                                        get_sprints_uc.clone(),
+                                        action_tx.clone(),
+                                    )
+                                }
+                                Action::MoveIssueToSprint(sprint_id) => {
+                                    handlers::handle_move_issue_to_sprint(
+                                        *sprint_id,
+                                        &app,
+                                        move_issue_to_sprint_uc.clone(),
+                                        action_tx.clone(),
+                                    )
+                                }
+                                _ => {}
+                            }
+
                             // Handle filter application
                             if matches!(action, Action::ApplyFilter) {
                                 handlers::handle_filter_application(
@@ -123,13 +465,375 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 );
                             }
 
+                            // Handle open transition picker
+                            if matches!(action, Action::OpenTransitionModal) {
+                                handlers::handle_load_transitions(
+                                    &app,
+                                    get_transitions_uc.clone(),
+                                    action_tx.clone(),
+                                );
+                            }
+
+                            // Handle apply transition
+                            if matches!(action, Action::ApplyTransition) {
+                                handlers::handle_apply_transition(
+                                    &app,
+                                    transition_issue_uc.clone(),
+                                    action_tx.clone(),
+                                );
+                            }
+
+                            // Handle issue edit mode
+                            match &action {
+                                Action::StartIssueEdit => handlers::handle_start_issue_edit(
+                                    &app,
+                                    get_edit_fields_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::SubmitIssueEdit => handlers::handle_submit_issue_edit(
+                                    &app,
+                                    update_issue_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                _ => {}
+                            }
+
+                            // Handle history tab
+                            if let Action::ShowDetailTab(_) | Action::LoadMoreHistory = &action {
+                                handlers::handle_load_history(
+                                    &action,
+                                    &app,
+                                    get_changelog_uc.clone(),
+                                    action_tx.clone(),
+                                );
+                            }
+
+                            // Handle comments tab and composer
+                            match &action {
+                                Action::ShowDetailTab(_) | Action::LoadMoreComments => {
+                                    handlers::handle_load_comments(
+                                        &action,
+                                        &app,
+                                        get_comments_uc.clone(),
+                                        action_tx.clone(),
+                                    )
+                                }
+                                Action::SubmitComment => handlers::handle_submit_comment(
+                                    &app,
+                                    add_comment_uc.clone(),
+                                    update_comment_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::DeleteSelectedComment => handlers::handle_delete_comment(
+                                    &app,
+                                    delete_comment_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                _ => {}
+                            }
+
+                            // Handle issue assignment
+                            match &action {
+                                Action::AssignIssue(user) => handlers::handle_assign_issue(
+                                    user.clone(),
+                                    &app,
+                                    assign_issue_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::AssignToMe => handlers::handle_assign_to_me(
+                                    &app,
+                                    get_current_user_uc.clone(),
+                                    assign_issue_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                _ => {}
+                            }
+
+                            // Handle watchers and votes
+                            match &action {
+                                Action::ViewIssueDetail => handlers::handle_load_watchers(
+                                    &app,
+                                    get_watchers_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::ToggleWatch => handlers::handle_toggle_watch(
+                                    &app,
+                                    get_current_user_uc.clone(),
+                                    watch_issue_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::ToggleVote => handlers::handle_toggle_vote(
+                                    &app,
+                                    vote_issue_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                _ => {}
+                            }
+
+                            // Handle issue links
+                            match &action {
+                                Action::OpenSelectedLink => handlers::handle_open_linked_issue(
+                                    &app,
+                                    get_issue_uc.clone(),
+                                    get_watchers_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::OpenLinkPicker => handlers::handle_open_link_picker(
+                                    &app,
+                                    get_issue_link_types_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::SubmitIssueLink => handlers::handle_link_issues(
+                                    &app,
+                                    link_issues_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::DeleteSelectedLink => handlers::handle_delete_issue_link(
+                                    &app,
+                                    delete_issue_link_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                _ => {}
+                            }
+
+                            // Handle attachments
+                            match &action {
+                                Action::DownloadSelectedAttachment => {
+                                    handlers::handle_download_attachment(
+                                        &app,
+                                        download_attachment_uc.clone(),
+                                        action_tx.clone(),
+                                    )
+                                }
+                                Action::SubmitAttachmentUpload => {
+                                    handlers::handle_upload_attachment(
+                                        &app,
+                                        upload_attachment_uc.clone(),
+                                        action_tx.clone(),
+                                    )
+                                }
+                                _ => {}
+                            }
+
+                            // Handle create issue form
+                            match &action {
+                                Action::OpenCreateIssue => handlers::handle_open_create_issue(
+                                    &app,
+                                    get_create_issue_types_uc.clone(),
+                                    get_create_fields_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::SelectCreateIssueType(issue_type_id) => {
+                                    handlers::handle_load_create_fields(
+                                        issue_type_id,
+                                        &app,
+                                        get_create_fields_uc.clone(),
+                                        action_tx.clone(),
+                                    )
+                                }
+                                Action::SubmitCreateIssue => handlers::handle_create_issue(
+                                    &app,
+                                    create_issue_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                _ => {}
+                            }
+
+                            // Handle retry of a failed background task
+                            if let Action::RetryTask(task) = &action {
+                                match task {
+                                    RetryableTask::LoadBoards => {
+                                        handlers::handle_side_effects(
+                                            &Action::LoadBoards,
+                                            &app,
+                                            get_boards_uc.clone(),
+                                            get_backlog_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadIssues(board_id) => {
+                                        handlers::handle_side_effects(
+                                            &Action::LoadIssues(*board_id),
+                                            &app,
+                                            get_boards_uc.clone(),
+                                            get_backlog_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadBoardConfiguration(board_id) => {
+                                        handlers::handle_load_board_configuration(
+                                            *board_id,
+                                            get_board_configuration_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadSprints(_) => {
+                                        handlers::handle_load_sprints(
+                                            &app,
+                                            get_sprints_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadMoreIssues => {
+                                        handlers::handle_load_more_issues(
+                                            &app,
+                                            get_backlog_uc.clone(),
+                                            search_issues_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::Search(jql) => handlers::handle_jql_search(
+                                        jql,
+                                        search_issues_uc.clone(),
+                                        action_tx.clone(),
+                                    ),
+                                    RetryableTask::LoadWorklogs(issue_key) => {
+                                        handlers::handle_load_worklogs(
+                                            issue_key,
+                                            get_worklogs_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadTransitions => {
+                                        handlers::handle_load_transitions(
+                                            &app,
+                                            get_transitions_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadCreateIssue => {
+                                        handlers::handle_open_create_issue(
+                                            &app,
+                                            get_create_issue_types_uc.clone(),
+                                            get_create_fields_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadEditFields => {
+                                        handlers::handle_start_issue_edit(
+                                            &app,
+                                            get_edit_fields_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadComments(issue_key) => {
+                                        handlers::load_comments(
+                                            issue_key.clone(),
+                                            0,
+                                            get_comments_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadLinkedIssue(issue_key) => {
+                                        handlers::load_linked_issue(
+                                            issue_key.clone(),
+                                            get_issue_uc.clone(),
+                                            get_watchers_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadCustomFields => {
+                                        handlers::load_custom_fields(
+                                            get_custom_fields_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadWatchers(issue_key) => {
+                                        handlers::load_watchers(
+                                            issue_key.clone(),
+                                            get_watchers_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadLinkTypes => handlers::load_link_types(
+                                        get_issue_link_types_uc.clone(),
+                                        action_tx.clone(),
+                                    ),
+                                    RetryableTask::LoadHistory(issue_key) => {
+                                        handlers::load_history(
+                                            issue_key.clone(),
+                                            0,
+                                            get_changelog_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                    RetryableTask::LoadSavedQueries => {
+                                        handlers::handle_load_saved_queries(
+                                            get_saved_queries_uc.clone(),
+                                            action_tx.clone(),
+                                        )
+                                    }
+                                }
+                            }
+
+                            // Handle JQL search screen
+                            match &action {
+                                Action::OpenJqlSearch => handlers::handle_load_saved_queries(
+                                    get_saved_queries_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::RunJqlSearch => handlers::handle_jql_search(
+                                    &app.jql_input,
+                                    search_issues_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::RunSavedQuery => {
+                                    if let Some(query) = app.get_selected_saved_query() {
+                                        handlers::handle_jql_search(
+                                            &query.jql,
+                                            search_issues_uc.clone(),
+                                            action_tx.clone(),
+                                        );
+                                    }
+                                }
+                                Action::ConfirmSaveQuery => handlers::handle_save_query(
+                                    &app,
+                                    save_query_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                Action::DeleteSavedQuery => handlers::handle_delete_saved_query(
+                                    &app,
+                                    delete_saved_query_uc.clone(),
+                                    action_tx.clone(),
+                                ),
+                                _ => {}
+                            }
+
                             // Update UI state
                             app.update(action.clone());
 
+                            // Look up the mention being typed in the comment composer
+                            if matches!(
+                                action,
+                                Action::InputCommentChar(_) | Action::DeleteCommentChar
+                            ) {
+                                handlers::handle_mention_search(
+                                    &app,
+                                    search_users_uc.clone(),
+                                    action_tx.clone(),
+                                );
+                            }
+
+                            // Search the users of the user picker as the query changes
+                            if matches!(
+                                action,
+                                Action::OpenUserPicker(_)
+                                    | Action::InputUserQueryChar(_)
+                                    | Action::DeleteUserQueryChar
+                            ) {
+                                handlers::handle_user_search(
+                                    &app,
+                                    search_assignable_users_uc.clone(),
+                                    search_users_uc.clone(),
+                                    action_tx.clone(),
+                                );
+                            }
+
                             // Check if infinite scroll should trigger
                             handlers::check_infinite_scroll(
-                                &app,
+                                &mut app,
                                 get_backlog_uc.clone(),
+                                search_issues_uc.clone(),
                                 action_tx.clone(),
                             );
                         }
@@ -141,7 +845,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             // B. ASYNC BACKGROUND TASKS
             Some(action) = action_rx.recv() => {
+                let issues_loaded = matches!(action, Action::IssuesLoaded(..));
                 app.update(action);
+
+                // The board keeps loading pages until its columns hold every issue
+                if issues_loaded {
+                    handlers::check_infinite_scroll(
+                        &mut app,
+                        get_backlog_uc.clone(),
+                        search_issues_uc.clone(),
+                        action_tx.clone(),
+                    );
+                }
             }
         }
 
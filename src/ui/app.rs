@@ -1,17 +1,51 @@
+use crate::domain::errors::AppError;
 use crate::domain::models::{
-    AssigneeFilter, Board, Issue, OrderByFilter, Paginated, StatusFilter, WorklogEntry,
+    AssigneeFilter, Attachment, Board, BoardConfiguration, BoardFilter, BoardTypeFilter,
+    ChangelogEntry, Comment, FieldKind, FieldMeta, FieldValue, Issue, IssueFilter, IssueLevel,
+    IssueLink, IssueLinkType, IssueStatus, IssueType, LinkDirection, NewIssue, OrderByFilter,
+    PageCursor, Paginated, SavedQuery, SavedQuerySource, Sprint, SprintId, SprintState,
+    StatusFilter, Transition, User, Votes, WatcherFilter, Watchers, WorklogEntry,
 };
-use chrono::{Datelike, Local, Timelike};
+use crate::domain::rich_text::{MENTION_SCHEME, RichText};
+use chrono::{Datelike, Local, NaiveDate, Timelike};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CurrentScreen {
     Dashboard,
     BoardsList,
     Backlog,
+    /// The board issues laid out in the columns of the board configuration
+    Kanban,
     IssueDetail,
+    /// Picks the sprint shown in the backlog, or the sprint an issue is moved to
+    SprintPicker,
     FilterModal,
+    /// Server-side search over the boards list
+    BoardFilterModal,
     WorklogModal,
     WorklogListModal,
+    TransitionModal,
+    /// The issue detail with its editable fields turned into inputs
+    EditIssue,
+    /// Popup over the issue detail for writing or editing a comment
+    CommentComposer,
+    /// Searchable list of users, to assign an issue or filter by assignee
+    UserPicker,
+    /// Popup over the issue detail for linking the issue to another one
+    LinkPicker,
+    /// Popup over the issue detail asking for the path of a file to attach
+    AttachmentUpload,
+    /// Form for a new issue in the project of the current board
+    CreateIssue,
+    JqlSearch,
+    /// Shown instead of the app when the configuration cannot be loaded
+    ConfigError,
+    /// First-run form asking for the Jira site and credentials
+    Login,
+    /// Passphrase prompt for the encrypted credentials file
+    Unlock,
     Exiting,
 }
 
@@ -19,10 +53,416 @@ pub enum CurrentScreen {
 #[derive(Debug, Clone, PartialEq)]
 pub enum FilterField {
     Assignee,
+    Watcher,
     Status,
+    /// A custom field offered as a filter, by position in `filter_fields()`
+    Field(usize),
     OrderBy,
 }
 
+/// Represents which field is currently focused in the board filter modal
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardFilterField {
+    Name,
+    Project,
+    Type,
+}
+
+/// What choosing an entry in the sprint picker does
+#[derive(Debug, Clone, PartialEq)]
+pub enum SprintPickerMode {
+    /// Show the issues of the chosen sprint (or the whole board)
+    Browse,
+    /// Move the selected issue to the chosen sprint (or the backlog)
+    MoveIssue,
+}
+
+/// Pane shown below the issue info in the detail view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DetailTab {
+    Description,
+    Comments,
+    Links,
+    Attachments,
+    History,
+}
+
+impl DetailTab {
+    pub fn next(self) -> Self {
+        match self {
+            DetailTab::Description => DetailTab::Comments,
+            DetailTab::Comments => DetailTab::Links,
+            DetailTab::Links => DetailTab::Attachments,
+            DetailTab::Attachments => DetailTab::History,
+            DetailTab::History => DetailTab::Description,
+        }
+    }
+}
+
+/// The name being typed after an `@` at the end of a comment, if the user is writing a mention.
+pub fn mention_query(input: &str) -> Option<&str> {
+    let at = input.rfind('@')?;
+    let starts_word = input[..at]
+        .chars()
+        .next_back()
+        .is_none_or(char::is_whitespace);
+    let query = &input[at + 1..];
+    let in_progress = !query.contains(|c: char| c.is_whitespace() || "[]()".contains(c));
+    (starts_word && in_progress).then_some(query)
+}
+
+/// What choosing a user in the user picker does
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserPickerMode {
+    /// Assign the selected issue to the chosen user (or to nobody)
+    AssignIssue,
+    /// Show the issues assigned to the chosen user
+    FilterAssignee,
+}
+
+/// Scores how well `text` matches `query` typed as a fuzzy pattern: the characters of the
+/// query must appear in order, ignoring case. Runs of consecutive characters and matches at
+/// the start of a word score higher. `None` when the text does not match.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let mut pattern = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut score = 0;
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+    for ch in text.chars().flat_map(char::to_lowercase) {
+        let matched = pattern.peek() == Some(&ch);
+        if matched {
+            pattern.next();
+            score += 1;
+            if previous_matched {
+                score += 5;
+            }
+            if previous.is_none_or(|p| !p.is_alphanumeric()) {
+                score += 3;
+            }
+        }
+        previous_matched = matched;
+        previous = Some(ch);
+    }
+    pattern.peek().is_none().then_some(score)
+}
+
+/// A visible row of the backlog tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    /// Index into `App::issues`
+    pub issue_index: usize,
+    pub depth: usize,
+    /// Number of loaded child issues
+    pub children: usize,
+    /// Children in a done status, shown as progress for epics
+    pub done_children: usize,
+    pub collapsed: bool,
+}
+
+/// Fields of the create screen shown even when Jira does not require them, in display order
+const CREATE_FORM_FIELDS: &[&str] = &[
+    "summary",
+    "description",
+    "priority",
+    "assignee",
+    "labels",
+    "components",
+    "parent",
+];
+
+/// Fields of the issue detail that can be edited in place, in display order
+const EDIT_FORM_FIELDS: &[&str] = &["summary", "priority", "assignee", "labels", "description"];
+
+/// An input of a form built from Jira field metadata
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormField {
+    pub meta: FieldMeta,
+    /// Typed text, for the fields without allowed values
+    pub input: String,
+    /// Highlighted option of a multi-choice field
+    pub cursor: usize,
+    /// Indexes of the chosen allowed values (at most one for single choices)
+    pub chosen: Vec<usize>,
+    /// Value the field started with, to tell which fields were changed
+    initial: (String, Vec<usize>),
+}
+
+impl FormField {
+    pub fn new(meta: FieldMeta) -> Self {
+        Self::prefilled(meta, String::new(), Vec::new())
+    }
+
+    pub fn prefilled(meta: FieldMeta, input: String, chosen: Vec<usize>) -> Self {
+        Self {
+            meta,
+            initial: (input.clone(), chosen.clone()),
+            input,
+            cursor: 0,
+            chosen,
+        }
+    }
+
+    /// Whether the field differs from the value it started with.
+    pub fn is_dirty(&self) -> bool {
+        self.input != self.initial.0 || self.chosen != self.initial.1
+    }
+
+    /// Whether the field is filled in by typing.
+    pub fn takes_text(&self) -> bool {
+        !matches!(
+            self.meta.kind,
+            FieldKind::Choice | FieldKind::MultiChoice | FieldKind::Unsupported(_)
+        )
+    }
+
+    /// Steps through the options: the chosen one for single choices, the highlighted one otherwise.
+    pub fn cycle(&mut self, forward: bool) {
+        let count = self.meta.allowed_values.len();
+        if count == 0 {
+            return;
+        }
+        match self.meta.kind {
+            // Single choices go through "none" before wrapping around
+            FieldKind::Choice => {
+                let next = match (self.chosen.first(), forward) {
+                    (None, true) => Some(0),
+                    (None, false) => Some(count - 1),
+                    (Some(&i), true) => (i + 1 < count).then_some(i + 1),
+                    (Some(&i), false) => i.checked_sub(1),
+                };
+                self.chosen = next.into_iter().collect();
+            }
+            FieldKind::MultiChoice => {
+                self.cursor = if forward {
+                    (self.cursor + 1) % count
+                } else {
+                    (self.cursor + count - 1) % count
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds or removes the highlighted option of a multi-choice field.
+    pub fn toggle(&mut self) {
+        if self.meta.kind != FieldKind::MultiChoice || self.meta.allowed_values.is_empty() {
+            return;
+        }
+        match self.chosen.iter().position(|&i| i == self.cursor) {
+            Some(pos) => {
+                self.chosen.remove(pos);
+            }
+            None => self.chosen.push(self.cursor),
+        }
+    }
+
+    /// The value to send, `None` when nothing was entered.
+    pub fn value(&self) -> Result<Option<FieldValue>, String> {
+        let text = self.input.trim();
+        let option_ids = || -> Vec<String> {
+            self.chosen
+                .iter()
+                .filter_map(|&i| self.meta.allowed_values.get(i))
+                .map(|option| option.id.clone())
+                .collect()
+        };
+
+        let value = match &self.meta.kind {
+            FieldKind::Choice => option_ids().into_iter().next().map(FieldValue::Choice),
+            FieldKind::MultiChoice => Some(option_ids())
+                .filter(|ids| !ids.is_empty())
+                .map(FieldValue::Choices),
+            FieldKind::Unsupported(_) => None,
+            _ if text.is_empty() => None,
+            FieldKind::Text => Some(FieldValue::Text(text.to_string())),
+            FieldKind::RichText => Some(FieldValue::RichText(self.input.clone())),
+            FieldKind::Number => match text.parse() {
+                Ok(number) => Some(FieldValue::Number(number)),
+                Err(_) => return Err(format!("{} must be a number", self.meta.name)),
+            },
+            // Jira labels cannot contain spaces, so both separators are accepted
+            FieldKind::Labels => Some(FieldValue::Labels(
+                text.split([',', ' '])
+                    .filter(|label| !label.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            )),
+            FieldKind::User => Some(FieldValue::User(text.to_string())),
+            FieldKind::IssueKey => Some(FieldValue::IssueKey(text.to_uppercase())),
+            FieldKind::Date => match NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+                Ok(date) => Some(FieldValue::Date(date)),
+                Err(_) => return Err(format!("{} must be a date (YYYY-MM-DD)", self.meta.name)),
+            },
+        };
+
+        match (&value, &self.meta.kind) {
+            (None, FieldKind::Unsupported(kind)) if self.meta.needs_value() => Err(format!(
+                "{} ({}) cannot be set from here",
+                self.meta.name, kind
+            )),
+            (None, _) if self.meta.needs_value() => Err(format!("{} is required", self.meta.name)),
+            _ => Ok(value),
+        }
+    }
+}
+
+/// Picks the fields of the create form: what Jira requires plus the usual ones, summary first.
+fn create_form_fields(fields: Vec<FieldMeta>) -> Vec<FormField> {
+    let rank = |meta: &FieldMeta| {
+        CREATE_FORM_FIELDS
+            .iter()
+            .position(|id| *id == meta.id)
+            .unwrap_or(CREATE_FORM_FIELDS.len())
+    };
+    let mut shown: Vec<FieldMeta> = fields
+        .into_iter()
+        // Both are chosen at the top of the form
+        .filter(|meta| meta.id != "project" && meta.id != "issuetype")
+        .filter(|meta| {
+            meta.needs_value()
+                || CREATE_FORM_FIELDS.contains(&meta.id.as_str())
+                // Epic Link on Jira Server
+                || meta.kind == FieldKind::IssueKey
+        })
+        .collect();
+    shown.sort_by_key(rank);
+    shown.into_iter().map(FormField::new).collect()
+}
+
+/// Picks the editable fields of the issue detail and fills them with the current values.
+fn edit_form_fields(fields: Vec<FieldMeta>, issue: &Issue) -> Vec<FormField> {
+    EDIT_FORM_FIELDS
+        .iter()
+        .filter_map(|id| fields.iter().find(|meta| meta.id == *id))
+        .map(|meta| {
+            let meta = meta.clone();
+            match meta.id.as_str() {
+                "summary" => FormField::prefilled(meta, issue.summary.clone(), Vec::new()),
+                "description" => {
+                    let markdown = issue
+                        .description
+                        .as_ref()
+                        .map(|d| d.to_markdown())
+                        .unwrap_or_default();
+                    FormField::prefilled(meta, markdown, Vec::new())
+                }
+                "priority" => {
+                    let chosen = meta
+                        .allowed_values
+                        .iter()
+                        .position(|option| Some(&option.name) == issue.priority.as_ref())
+                        .into_iter()
+                        .collect();
+                    FormField::prefilled(meta, String::new(), chosen)
+                }
+                "labels" => FormField::prefilled(meta, issue.labels.join(" "), Vec::new()),
+                // Only the assignee name is known: typing an account ID reassigns the issue
+                _ => FormField::new(meta),
+            }
+        })
+        .collect()
+}
+
+/// Applies a saved field change to the local copy of an issue.
+fn apply_issue_change(issue: &mut Issue, meta: &FieldMeta, value: &Option<FieldValue>) {
+    match (meta.id.as_str(), value) {
+        ("summary", Some(FieldValue::Text(summary))) => issue.summary = summary.clone(),
+        ("description", Some(FieldValue::RichText(markdown))) => {
+            issue.description = Some(RichText::from_markdown(markdown)).filter(|d| !d.is_empty());
+        }
+        ("description", None) => issue.description = None,
+        ("priority", Some(FieldValue::Choice(id))) => {
+            issue.priority = meta
+                .allowed_values
+                .iter()
+                .find(|option| option.id == *id)
+                .map(|option| option.name.clone());
+        }
+        ("priority", None) => issue.priority = None,
+        ("labels", Some(FieldValue::Labels(labels))) => issue.labels = labels.clone(),
+        ("labels", None) => issue.labels.clear(),
+        // Only the account ID was typed; the name shows once the issue is reloaded
+        ("assignee", Some(FieldValue::User(account_id))) => {
+            issue.assignee = Some(User {
+                account_id: account_id.clone(),
+                display_name: account_id.clone(),
+            });
+        }
+        ("assignee", None) => issue.assignee = None,
+        _ => {}
+    }
+    issue.updated_at = chrono::Utc::now();
+}
+
+/// Copies back from `original` the field that `apply_issue_change` set for `field_id`.
+fn restore_issue_field(issue: &mut Issue, original: &Issue, field_id: &str) {
+    match field_id {
+        "summary" => issue.summary = original.summary.clone(),
+        "description" => issue.description = original.description.clone(),
+        "priority" => issue.priority = original.priority.clone(),
+        "labels" => issue.labels = original.labels.clone(),
+        "assignee" => issue.assignee = original.assignee.clone(),
+        _ => {}
+    }
+}
+
+/// The fields of `fields` named, by name or ID, in `declared`.
+fn declared_fields<'a>(fields: &'a [FieldMeta], declared: &[String]) -> Vec<&'a FieldMeta> {
+    fields
+        .iter()
+        .filter(|field| {
+            declared
+                .iter()
+                .any(|name| *name == field.id || name.eq_ignore_ascii_case(&field.name))
+        })
+        .collect()
+}
+
+/// Issue type preselected in the create form: the first one that is not a subtask.
+pub fn default_issue_type_index(issue_types: &[IssueType]) -> usize {
+    issue_types
+        .iter()
+        .position(|t| t.level != IssueLevel::Subtask)
+        .unwrap_or(0)
+}
+
+/// Represents which field is currently focused in the JQL search screen
+#[derive(Debug, Clone, PartialEq)]
+pub enum JqlSearchField {
+    Query,
+    SavedQueries,
+    /// Naming the current query before saving it
+    SaveName,
+}
+
+/// Represents which field is currently focused in the login screen
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoginField {
+    BaseUrl,
+    Email,
+    Token,
+    Storage,
+    Passphrase,
+}
+
+/// Where the login screen stores the API token
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoginStorage {
+    EncryptedFile,
+    Command,
+    Env,
+}
+
+impl LoginStorage {
+    pub fn label(&self) -> &str {
+        match self {
+            LoginStorage::EncryptedFile => "Encrypted file (passphrase)",
+            LoginStorage::Command => "External command (store_command)",
+            LoginStorage::Env => "Plain text in config.toml",
+        }
+    }
+}
+
 /// Represents which field is currently focused in the worklog modal
 #[derive(Debug, Clone, PartialEq)]
 pub enum WorklogField {
@@ -36,6 +476,59 @@ pub enum WorklogField {
     Comment,
 }
 
+/// What a page of issues was fetched for: a board backlog (optionally one sprint) or a search.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueSource {
+    Board {
+        board_id: u64,
+        sprint_id: Option<SprintId>,
+    },
+    Search(String),
+}
+
+/// A background request that can be re-run after it failed.
+///
+/// Only reads are listed: repeating a write after an ambiguous failure could apply it twice.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RetryableTask {
+    LoadBoards,
+    /// First page of a board backlog, with the current filter
+    LoadIssues(u64),
+    LoadBoardConfiguration(u64),
+    LoadSprints(u64),
+    /// Next page of the current backlog or search
+    LoadMoreIssues,
+    Search(String),
+    LoadWorklogs(String),
+    /// Transitions of the selected issue
+    LoadTransitions,
+    /// Issue types of the create form
+    LoadCreateIssue,
+    /// Editable fields of the selected issue
+    LoadEditFields,
+    /// First page of the comments of an issue
+    LoadComments(String),
+    /// First page of the history of an issue
+    LoadHistory(String),
+    /// Issue opened from a link of the issue detail
+    LoadLinkedIssue(String),
+    LoadLinkTypes,
+    /// Watchers of an issue opened in the detail view
+    LoadWatchers(String),
+    /// Metadata of the configured custom fields
+    LoadCustomFields,
+    LoadSavedQueries,
+}
+
+/// Failure reported by a background task over the action channel.
+#[derive(Debug, Clone)]
+pub struct TaskFailure {
+    pub title: String,
+    pub message: String,
+    /// Set when trying again may succeed (network errors, rate limits, server errors).
+    pub retry: Option<RetryableTask>,
+}
+
 #[derive(Debug, Clone)]
 pub enum Action {
     Tick,
@@ -50,15 +543,51 @@ pub enum Action {
     ViewIssueDetail,
 
     LoadBoards,
-    BoardsLoaded(Vec<Board>),
+    /// A page of boards, tagged with the filter it was loaded for
+    BoardsLoaded(BoardFilter, Paginated<Board>),
+    OpenBoardFilter,
+    CloseBoardFilter,
+    NextBoardFilterField,
+    InputBoardFilterChar(char),
+    DeleteBoardFilterChar,
+    CycleBoardTypeFilter,
+    ApplyBoardFilter,
     LoadIssues(u64),
-    IssuesLoaded(Paginated<Issue>),
+    IssuesLoaded(IssueSource, Paginated<Issue>),
     LoadMoreIssues,
 
+    OpenSprintPicker,
+    OpenMoveToSprint,
+    CloseSprintPicker,
+    SprintsLoaded(u64, Vec<Sprint>),
+    /// Shows the issues of a sprint, or of the whole board for `None`
+    SelectSprint(Option<SprintId>),
+    /// Moves the selected issue to a sprint, or to the backlog for `None`
+    MoveIssueToSprint(Option<SprintId>),
+    IssueSprintChanged(String, Option<SprintId>),
+
+    ToggleIssueTree,
+    ToggleIssueCollapsed,
+    /// Collapses the selected issue, or selects its parent when there is nothing to collapse
+    CollapseIssue,
+    ExpandIssue,
+
+    ToggleKanban,
+    BoardConfigurationLoaded(BoardConfiguration),
+    SelectNextColumn,
+    SelectPreviousColumn,
+    /// Moves the selected issue to the given board column through a transition
+    MoveIssueToColumn(usize),
+
     OpenFilterModal,
     CloseFilterModal,
     NextFilterField,
     CycleAssigneeFilter,
+    CycleWatcherFilter,
+    /// Edits the value of the custom field focused in the filter modal
+    InputFilterValueChar(char),
+    DeleteFilterValueChar,
+    CustomFieldsLoaded(Vec<FieldMeta>),
     CycleStatusFilter,
     CycleOrderByFilter,
     ApplyFilter,
@@ -82,8 +611,155 @@ pub enum Action {
     WorklogDeleted,
     WorklogUpdated,
 
+    OpenTransitionModal,
+    CloseTransitionModal,
+    TransitionsLoaded(Vec<Transition>),
+    ApplyTransition,
+    IssueTransitioned(String, Transition),
+
+    ShowDetailTab(DetailTab),
+    /// A page of the comments of an issue
+    CommentsLoaded(String, Paginated<Comment>),
+    LoadMoreComments,
+    OpenCommentComposer,
+    EditSelectedComment,
+    CloseCommentComposer,
+    InputCommentChar(char),
+    DeleteCommentChar,
+    /// Users matching a mention query
+    MentionSuggestionsLoaded(String, Vec<User>),
+    SelectNextMention,
+    SelectPreviousMention,
+    /// Replaces the mention being typed with the highlighted user
+    AcceptMention,
+    SubmitComment,
+    CommentAdded(Comment),
+    CommentUpdated(Comment),
+    DeleteSelectedComment,
+    CommentDeleted(String),
+
+    /// A page of the history of an issue
+    HistoryLoaded(String, Paginated<ChangelogEntry>),
+    LoadMoreHistory,
+
+    OpenUserPicker(UserPickerMode),
+    CloseUserPicker,
+    InputUserQueryChar(char),
+    DeleteUserQueryChar,
+    /// Users matching a query of the user picker
+    UsersLoaded(String, Vec<User>),
+    /// Assigns the selected issue to a user, or unassigns it for `None`
+    AssignIssue(Option<User>),
+    AssignToMe,
+    CurrentUserLoaded(User),
+    IssueAssigned(String, Option<User>),
+    FilterByAssignee(User),
+
+    /// Starts or stops watching the selected issue as the current user
+    ToggleWatch,
+    WatchersLoaded(String, Watchers),
+    /// Votes for the selected issue, or withdraws the vote
+    ToggleVote,
+    VotesLoaded(String, Votes),
+
+    /// Opens the issue at the other end of the selected link
+    OpenSelectedLink,
+    LinkedIssueLoaded(Issue),
+    /// Goes back to the issue the linked one was opened from
+    CloseLinkedIssue,
+    OpenLinkPicker,
+    CloseLinkPicker,
+    LinkTypesLoaded(Vec<IssueLinkType>),
+    InputLinkTargetChar(char),
+    DeleteLinkTargetChar,
+    /// Links the issue to the typed key with the selected relation
+    SubmitIssueLink,
+    /// The issue with its links, once one has been added
+    IssueLinked(Issue),
+    DeleteSelectedLink,
+    IssueLinkDeleted(String),
+
+    DownloadSelectedAttachment,
+    /// Bytes of an attachment received so far
+    AttachmentDownloadProgress(String, u64),
+    /// The download of an attachment is over, saved or not
+    AttachmentDownloadEnded(String),
+    OpenAttachmentUpload,
+    CloseAttachmentUpload,
+    InputAttachmentPathChar(char),
+    DeleteAttachmentPathChar,
+    SubmitAttachmentUpload,
+    AttachmentsAdded(String, Vec<Attachment>),
+
+    StartIssueEdit,
+    CancelIssueEdit,
+    /// Editable fields of an issue
+    EditFieldsLoaded(String, Vec<FieldMeta>),
+    NextEditField,
+    PreviousEditField,
+    InputEditChar(char),
+    DeleteEditChar,
+    NextEditOption,
+    PreviousEditOption,
+    /// Saves the changed fields, showing them before Jira confirms
+    SubmitIssueEdit,
+    /// Puts back the fields (by ID) of a rejected edit as they were in the given issue
+    IssueUpdateFailed(Issue, Vec<String>),
+
+    OpenCreateIssue,
+    CloseCreateIssue,
+    /// Issue types that can be created in a project
+    CreateIssueTypesLoaded(String, Vec<IssueType>),
+    SelectCreateIssueType(String),
+    /// Create screen fields of an issue type
+    CreateFieldsLoaded(String, Vec<FieldMeta>),
+    NextCreateField,
+    PreviousCreateField,
+    InputCreateChar(char),
+    DeleteCreateChar,
+    NextCreateOption,
+    PreviousCreateOption,
+    ToggleCreateOption,
+    SubmitCreateIssue,
+    IssueCreated(Issue),
+
+    OpenJqlSearch,
+    CloseJqlSearch,
+    NextJqlSearchField,
+    InputJqlChar(char),
+    DeleteJqlChar,
+    RunJqlSearch,
+    SearchFailed,
+    SavedQueriesLoaded(Vec<SavedQuery>),
+    RunSavedQuery,
+    StartSaveQuery,
+    ConfirmSaveQuery,
+    QuerySaved(SavedQuery),
+    DeleteSavedQuery,
+    SavedQueryDeleted(String),
+
     ShowNotification(String, String, bool),
     HideNotification,
+    TaskFailed(TaskFailure),
+    RetryTask(RetryableTask),
+
+    ShowConfigError(String),
+    ShowLogin {
+        base_url: String,
+        email: String,
+        needs_email: bool,
+        storage_options: Vec<LoginStorage>,
+    },
+    ShowUnlock,
+    NextLoginField,
+    PreviousLoginField,
+    InputLoginChar(char),
+    DeleteLoginChar,
+    CycleLoginStorage,
+    SubmitLogin,
+    SubmitUnlock,
+    LoginFailed(String),
+    SessionStarted,
 }
 
 pub struct App {
@@ -93,6 +769,12 @@ pub struct App {
 
     pub boards: Vec<Board>,
     pub selected_board_index: usize,
+    /// Filter the boards list was loaded with
+    pub board_filter: BoardFilter,
+    pub board_filter_name: String,
+    pub board_filter_project: String,
+    pub board_filter_type: BoardTypeFilter,
+    pub board_filter_focused_field: BoardFilterField,
 
     pub issues: Vec<Issue>,
     pub selected_issue_index: usize,
@@ -100,10 +782,34 @@ pub struct App {
     pub is_loading: bool,
 
     pub vertical_scroll: u16,
-    pub total_issues: u64,
+    /// Total number of matching issues, when the endpoint reports one.
+    pub total_issues: Option<u64>,
+    /// Cursor for the next page of issues; `None` once everything has been loaded.
+    pub next_issues_page: Option<PageCursor>,
+    /// A page after the first is on its way.
+    pub loading_more_issues: bool,
     pub current_board_id: Option<u64>,
+    /// When set, `issues` holds the results of this JQL search instead of a board backlog.
+    pub search_jql: Option<String>,
+    /// Sprint whose issues are listed; `None` lists the whole board.
+    pub current_sprint: Option<Sprint>,
+    /// Sprints of the current board, as listed in the sprint picker.
+    pub sprints: Vec<Sprint>,
+    pub selected_sprint_index: usize,
+    pub sprint_picker_mode: SprintPickerMode,
+    /// The backlog groups stories under epics and subtasks under their parents.
+    pub issue_tree: bool,
+    /// Keys of the tree rows whose children are hidden.
+    pub collapsed_issues: HashSet<String>,
+    /// Board issues are shown as Kanban columns instead of a list.
+    pub kanban_view: bool,
+    /// Column layout of the current board, loaded for the Kanban view.
+    pub board_configuration: Option<BoardConfiguration>,
 
     pub filter_assignee: AssigneeFilter,
+    pub filter_watcher: WatcherFilter,
+    /// Values typed for the custom field filters, by field ID
+    pub filter_field_values: HashMap<String, String>,
     pub filter_status: StatusFilter,
     pub filter_order_by: OrderByFilter,
     pub filter_focused_field: FilterField,
@@ -123,9 +829,95 @@ pub struct App {
     pub total_worklogs: u64,
     pub worklog_being_edited: Option<WorklogEntry>,
 
+    pub transitions: Vec<Transition>,
+    pub selected_transition_index: usize,
+
+    pub detail_tab: DetailTab,
+    /// Comments of `comments_issue_key`, oldest first
+    pub comments: Vec<Comment>,
+    pub comments_issue_key: Option<String>,
+    pub selected_comment_index: usize,
+    pub total_comments: u64,
+    /// Markdown typed in the comment composer
+    pub comment_input: String,
+    pub comment_being_edited: Option<Comment>,
+    /// Users matching the mention being typed
+    pub mention_suggestions: Vec<User>,
+    pub selected_mention_index: usize,
+
+    /// Changelog of `history_issue_key`, oldest first
+    pub history: Vec<ChangelogEntry>,
+    pub history_issue_key: Option<String>,
+    pub selected_history_index: usize,
+    pub total_history: u64,
+
+    pub user_picker_mode: UserPickerMode,
+    pub user_query: String,
+    /// Users returned by Jira for `users_query`, ranked against `user_query` as it is typed
+    pub users: Vec<User>,
+    pub users_query: String,
+    pub selected_user_index: usize,
+    /// The authenticated user, fetched the first time an issue is assigned to "me"
+    pub current_user: Option<User>,
+
+    /// Custom fields shown in the issue detail, as configured
+    pub custom_fields: Vec<FieldMeta>,
+    /// Names or IDs of the custom fields configured as backlog columns and as filters
+    pub field_columns: Vec<String>,
+    pub field_filters: Vec<String>,
+
+    /// Issues opened through links from the detail view, each with the link selected in the
+    /// issue below it to return to
+    pub issue_stack: Vec<(Issue, usize)>,
+    pub selected_link_index: usize,
+    /// Link types of the site, loaded the first time the link picker opens
+    pub link_types: Vec<IssueLinkType>,
+    pub selected_link_type_index: usize,
+    /// Key of the issue to link to, as typed in the link picker
+    pub link_target_input: String,
+
+    pub selected_attachment_index: usize,
+    /// Attachment being downloaded, with the bytes received so far
+    pub attachment_download: Option<(String, u64)>,
+    /// Local path typed in the upload popup
+    pub attachment_path_input: String,
+
+    /// Inputs of the issue detail in edit mode
+    pub edit_fields: Vec<FormField>,
+    pub edit_focused_field: usize,
+
+    /// Project the create form creates issues in
+    pub create_project_key: Option<String>,
+    pub create_issue_types: Vec<IssueType>,
+    pub create_issue_type_index: usize,
+    pub create_fields: Vec<FormField>,
+    /// 0 is the issue type, then the entries of `create_fields`
+    pub create_focused_field: usize,
+
+    pub jql_input: String,
+    pub jql_name_input: String,
+    pub jql_focused_field: JqlSearchField,
+    pub saved_queries: Vec<SavedQuery>,
+    pub selected_saved_query_index: usize,
+
     pub notification_title: Option<String>,
     pub notification_message: Option<String>,
     pub notification_is_success: bool,
+    /// Task offered for retry by the notification currently shown.
+    pub failed_task: Option<RetryableTask>,
+
+    pub config_error: Option<String>,
+
+    // Login / unlock
+    pub login_base_url: String,
+    pub login_email: String,
+    pub login_token: String,
+    pub login_passphrase: String,
+    pub login_needs_email: bool,
+    pub login_storage: LoginStorage,
+    pub login_storage_options: Vec<LoginStorage>,
+    pub login_focused_field: LoginField,
+    pub login_error: Option<String>,
 }
 
 impl App {
@@ -137,13 +929,31 @@ impl App {
             previous_screen: None,
             boards: Vec::new(),
             selected_board_index: 0,
+            board_filter: BoardFilter::default(),
+            board_filter_name: String::new(),
+            board_filter_project: String::new(),
+            board_filter_type: BoardTypeFilter::All,
+            board_filter_focused_field: BoardFilterField::Name,
             issues: Vec::new(),
             selected_issue_index: 0,
             is_loading: false,
             vertical_scroll: 0,
-            total_issues: 0,
+            total_issues: None,
+            next_issues_page: None,
+            loading_more_issues: false,
             current_board_id: None,
+            search_jql: None,
+            current_sprint: None,
+            sprints: Vec::new(),
+            selected_sprint_index: 0,
+            sprint_picker_mode: SprintPickerMode::Browse,
+            issue_tree: false,
+            collapsed_issues: HashSet::new(),
+            kanban_view: false,
+            board_configuration: None,
             filter_assignee: AssigneeFilter::CurrentUser,
+            filter_watcher: WatcherFilter::All,
+            filter_field_values: HashMap::new(),
             filter_status: StatusFilter::All,
             filter_order_by: OrderByFilter::UpdatedDesc,
             filter_focused_field: FilterField::Assignee,
@@ -160,9 +970,64 @@ impl App {
             selected_worklog_index: 0,
             total_worklogs: 0,
             worklog_being_edited: None,
+            transitions: Vec::new(),
+            selected_transition_index: 0,
+            detail_tab: DetailTab::Description,
+            comments: Vec::new(),
+            comments_issue_key: None,
+            selected_comment_index: 0,
+            total_comments: 0,
+            comment_input: String::new(),
+            comment_being_edited: None,
+            mention_suggestions: Vec::new(),
+            selected_mention_index: 0,
+            history: Vec::new(),
+            history_issue_key: None,
+            selected_history_index: 0,
+            total_history: 0,
+            user_picker_mode: UserPickerMode::AssignIssue,
+            user_query: String::new(),
+            users: Vec::new(),
+            users_query: String::new(),
+            selected_user_index: 0,
+            current_user: None,
+            custom_fields: Vec::new(),
+            field_columns: Vec::new(),
+            field_filters: Vec::new(),
+            issue_stack: Vec::new(),
+            selected_link_index: 0,
+            link_types: Vec::new(),
+            selected_link_type_index: 0,
+            link_target_input: String::new(),
+            selected_attachment_index: 0,
+            attachment_download: None,
+            attachment_path_input: String::new(),
+            edit_fields: Vec::new(),
+            edit_focused_field: 0,
+            create_project_key: None,
+            create_issue_types: Vec::new(),
+            create_issue_type_index: 0,
+            create_fields: Vec::new(),
+            create_focused_field: 0,
+            jql_input: String::new(),
+            jql_name_input: String::new(),
+            jql_focused_field: JqlSearchField::Query,
+            saved_queries: Vec::new(),
+            selected_saved_query_index: 0,
             notification_title: None,
             notification_message: None,
             notification_is_success: false,
+            failed_task: None,
+            config_error: None,
+            login_base_url: String::new(),
+            login_email: String::new(),
+            login_token: String::new(),
+            login_passphrase: String::new(),
+            login_needs_email: true,
+            login_storage: LoginStorage::EncryptedFile,
+            login_storage_options: Vec::new(),
+            login_focused_field: LoginField::BaseUrl,
+            login_error: None,
         }
     }
 
@@ -178,46 +1043,274 @@ impl App {
             }
 
             Action::GoToBacklog => {
-                self.current_screen = CurrentScreen::Backlog;
+                self.current_screen = self.issues_screen();
                 self.vertical_scroll = 0;
+                self.issue_stack.clear();
             }
 
-            Action::BoardsLoaded(boards) => {
-                self.boards = boards;
-                self.selected_board_index = 0;
-                self.is_loading = false;
+            Action::LoadBoards => {
+                self.is_loading = true;
+            }
+
+            Action::BoardsLoaded(filter, page) => {
+                // Pages of a previous search may still be arriving
+                if filter == self.board_filter {
+                    self.is_loading = false;
+                    if page.is_first_page {
+                        self.boards = page.items;
+                        self.selected_board_index = 0;
+                        self.current_screen = CurrentScreen::BoardsList;
+                        self.vertical_scroll = 0;
+                    } else {
+                        for board in page.items {
+                            if !self.boards.iter().any(|b| b.id == board.id) {
+                                self.boards.push(board);
+                            }
+                        }
+                    }
+                }
+            }
+
+            Action::OpenBoardFilter => {
+                self.previous_screen = Some(self.current_screen.clone());
+                self.current_screen = CurrentScreen::BoardFilterModal;
+                self.board_filter_focused_field = BoardFilterField::Name;
+            }
+
+            Action::CloseBoardFilter => {
+                if let Some(prev) = self.previous_screen.take() {
+                    self.current_screen = prev;
+                } else {
+                    self.current_screen = CurrentScreen::BoardsList;
+                }
+            }
+
+            Action::NextBoardFilterField => {
+                self.board_filter_focused_field = match self.board_filter_focused_field {
+                    BoardFilterField::Name => BoardFilterField::Project,
+                    BoardFilterField::Project => BoardFilterField::Type,
+                    BoardFilterField::Type => BoardFilterField::Name,
+                };
+            }
+
+            Action::InputBoardFilterChar(ch) => match self.board_filter_focused_field {
+                BoardFilterField::Name => self.board_filter_name.push(ch),
+                BoardFilterField::Project => self.board_filter_project.push(ch),
+                BoardFilterField::Type => {}
+            },
+
+            Action::DeleteBoardFilterChar => match self.board_filter_focused_field {
+                BoardFilterField::Name => {
+                    self.board_filter_name.pop();
+                }
+                BoardFilterField::Project => {
+                    self.board_filter_project.pop();
+                }
+                BoardFilterField::Type => {}
+            },
+
+            Action::CycleBoardTypeFilter => {
+                self.board_filter_type = match self.board_filter_type {
+                    BoardTypeFilter::All => BoardTypeFilter::Scrum,
+                    BoardTypeFilter::Scrum => BoardTypeFilter::Kanban,
+                    BoardTypeFilter::Kanban => BoardTypeFilter::All,
+                };
+            }
+
+            Action::ApplyBoardFilter => {
+                self.board_filter = self.board_filter_input();
+                self.previous_screen = None;
                 self.current_screen = CurrentScreen::BoardsList;
-                self.vertical_scroll = 0;
+                self.is_loading = true;
             }
 
             Action::ViewIssueDetail => {
                 if !self.issues.is_empty() {
                     self.current_screen = CurrentScreen::IssueDetail;
                     self.vertical_scroll = 0;
+                    self.detail_tab = DetailTab::Description;
+                    self.selected_link_index = 0;
+                    self.selected_attachment_index = 0;
+                    self.issue_stack.clear();
                 }
             }
 
             Action::LoadIssues(board_id) => {
                 self.is_loading = true;
-                self.current_screen = CurrentScreen::Backlog;
                 self.issues.clear(); // Clear for new search
                 self.vertical_scroll = 0;
+                if self.current_board_id != Some(board_id) {
+                    self.board_configuration = None;
+                    self.current_sprint = None;
+                }
                 self.current_board_id = Some(board_id);
-                self.total_issues = 0;
+                self.search_jql = None;
+                self.current_screen = self.issues_screen();
+                self.total_issues = None;
+                self.next_issues_page = None;
+                self.loading_more_issues = false;
             }
 
-            Action::IssuesLoaded(paginated) => {
-                self.is_loading = false;
-                self.current_screen = CurrentScreen::Backlog;
+            Action::LoadMoreIssues => {
+                if self.next_issues_page.is_some() {
+                    self.loading_more_issues = true;
+                }
+            }
 
-                if paginated.start_at == 0 {
+            // Pages of a board or search the user has left behind are dropped, as are later
+            // pages asked for before the current result set started
+            Action::IssuesLoaded(source, _)
+                if self.issue_source().is_some_and(|current| current != source) => {}
+            Action::IssuesLoaded(_, paginated)
+                if !paginated.is_first_page && !self.loading_more_issues => {}
+
+            Action::IssuesLoaded(_, paginated) => {
+                // Later pages land wherever the user is, e.g. an issue opened from the board
+                if paginated.is_first_page {
+                    self.is_loading = false;
+                    self.current_screen = self.issues_screen();
+                    self.issue_stack.clear();
                     self.issues = paginated.items;
                     self.selected_issue_index = 0;
                 } else {
+                    self.loading_more_issues = false;
                     self.issues.extend(paginated.items);
                 }
 
                 self.total_issues = paginated.total;
+                self.next_issues_page = paginated.next;
+            }
+
+            Action::OpenSprintPicker | Action::OpenMoveToSprint => {
+                self.sprint_picker_mode = if matches!(action, Action::OpenSprintPicker) {
+                    SprintPickerMode::Browse
+                } else {
+                    SprintPickerMode::MoveIssue
+                };
+                self.previous_screen = Some(self.current_screen.clone());
+                self.current_screen = CurrentScreen::SprintPicker;
+                self.sprints.clear();
+                self.selected_sprint_index = 0;
+                self.is_loading = true;
+            }
+
+            Action::CloseSprintPicker => {
+                self.is_loading = false;
+                self.current_screen = self
+                    .previous_screen
+                    .take()
+                    .unwrap_or_else(|| self.issues_screen());
+            }
+
+            Action::SprintsLoaded(board_id, sprints) => {
+                if self.current_board_id == Some(board_id) {
+                    self.is_loading = false;
+                    self.sprints = sprints;
+                    // Start on the sprint being shown
+                    let current = self.current_sprint.as_ref().map(|s| s.id);
+                    self.selected_sprint_index = match self.sprint_picker_mode {
+                        SprintPickerMode::Browse => self
+                            .sprint_picker_entries()
+                            .iter()
+                            .position(|entry| entry.map(|s| s.id) == current)
+                            .unwrap_or(0),
+                        SprintPickerMode::MoveIssue => 0,
+                    };
+                }
+            }
+
+            Action::SelectSprint(sprint_id) => {
+                if let Some(board_id) = self.current_board_id {
+                    self.current_sprint = sprint_id
+                        .and_then(|id| self.sprints.iter().find(|s| s.id == id))
+                        .cloned();
+                    self.previous_screen = None;
+                    self.update(Action::LoadIssues(board_id));
+                }
+            }
+
+            Action::MoveIssueToSprint(_) => {
+                self.update(Action::CloseSprintPicker);
+                self.is_loading = true;
+            }
+
+            Action::IssueSprintChanged(issue_key, sprint_id) => {
+                self.is_loading = false;
+                // The issue left the sprint being shown
+                let shown = self.current_sprint.as_ref().map(|s| s.id);
+                if shown.is_some()
+                    && shown != sprint_id
+                    && let Some(pos) = self.issues.iter().position(|i| i.key == issue_key)
+                {
+                    self.issues.remove(pos);
+                    self.total_issues = self.total_issues.map(|t| t.saturating_sub(1));
+                    if self.selected_issue_index >= self.issues.len() {
+                        self.selected_issue_index = self.issues.len().saturating_sub(1);
+                    }
+                }
+            }
+
+            Action::ToggleIssueTree => {
+                self.issue_tree = !self.issue_tree;
+            }
+
+            Action::ToggleIssueCollapsed => {
+                if let Some(key) = self.get_selected_issue().map(|i| i.key.clone())
+                    && !self.collapsed_issues.remove(&key)
+                {
+                    self.update(Action::CollapseIssue);
+                }
+            }
+
+            Action::CollapseIssue => {
+                let rows = self.tree_rows();
+                if let Some(row) = rows
+                    .iter()
+                    .find(|r| r.issue_index == self.selected_issue_index)
+                {
+                    let key = self.issues[row.issue_index].key.clone();
+                    if row.children > 0 && !row.collapsed {
+                        self.collapsed_issues.insert(key);
+                    } else if let Some(parent) = self.tree_parent(row.issue_index) {
+                        self.selected_issue_index = parent;
+                    }
+                }
+            }
+
+            Action::ExpandIssue => {
+                if let Some(issue) = self.get_selected_issue() {
+                    let key = issue.key.clone();
+                    self.collapsed_issues.remove(&key);
+                }
+            }
+
+            Action::ToggleKanban => match self.current_screen {
+                CurrentScreen::Backlog if self.can_show_kanban() => {
+                    self.kanban_view = true;
+                    self.current_screen = CurrentScreen::Kanban;
+                }
+                CurrentScreen::Kanban => {
+                    self.kanban_view = false;
+                    self.current_screen = CurrentScreen::Backlog;
+                }
+                _ => {}
+            },
+
+            Action::BoardConfigurationLoaded(configuration) => {
+                if self.current_board_id == Some(configuration.board_id) {
+                    self.board_configuration = Some(configuration);
+                }
+            }
+
+            Action::SelectNextColumn | Action::SelectPreviousColumn => {
+                let forward = matches!(action, Action::SelectNextColumn);
+                self.select_adjacent_column(forward);
+            }
+
+            Action::MoveIssueToColumn(_) => {
+                if self.get_selected_issue().is_some() {
+                    self.is_loading = true;
+                }
             }
 
             Action::SelectNext => match self.current_screen {
@@ -229,6 +1322,7 @@ impl App {
                         }
                     }
                 }
+                CurrentScreen::Backlog if self.issue_tree => self.select_tree_row(true),
                 CurrentScreen::Backlog => {
                     if !self.issues.is_empty() {
                         let next = self.selected_issue_index.saturating_add(1);
@@ -237,6 +1331,43 @@ impl App {
                         }
                     }
                 }
+                CurrentScreen::Kanban => self.select_in_column(true),
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::Comments => {
+                    let next = self.selected_comment_index.saturating_add(1);
+                    if next < self.comments.len() {
+                        self.selected_comment_index = next;
+                    }
+                }
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::Links => {
+                    let links = self
+                        .get_selected_issue()
+                        .map_or(0, |issue| issue.links.len());
+                    let next = self.selected_link_index.saturating_add(1);
+                    if next < links {
+                        self.selected_link_index = next;
+                    }
+                }
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::History => {
+                    let next = self.selected_history_index.saturating_add(1);
+                    if next < self.history.len() {
+                        self.selected_history_index = next;
+                    }
+                }
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::Attachments => {
+                    let attachments = self
+                        .get_selected_issue()
+                        .map_or(0, |issue| issue.attachments.len());
+                    let next = self.selected_attachment_index.saturating_add(1);
+                    if next < attachments {
+                        self.selected_attachment_index = next;
+                    }
+                }
+                CurrentScreen::LinkPicker => {
+                    let next = self.selected_link_type_index.saturating_add(1);
+                    if next < self.link_relations().len() {
+                        self.selected_link_type_index = next;
+                    }
+                }
                 CurrentScreen::IssueDetail => {
                     self.vertical_scroll = self.vertical_scroll.saturating_add(1);
                 }
@@ -248,6 +1379,30 @@ impl App {
                         }
                     }
                 }
+                CurrentScreen::TransitionModal => {
+                    let next = self.selected_transition_index.saturating_add(1);
+                    if next < self.transitions.len() {
+                        self.selected_transition_index = next;
+                    }
+                }
+                CurrentScreen::JqlSearch => {
+                    let next = self.selected_saved_query_index.saturating_add(1);
+                    if next < self.saved_queries.len() {
+                        self.selected_saved_query_index = next;
+                    }
+                }
+                CurrentScreen::SprintPicker => {
+                    let next = self.selected_sprint_index.saturating_add(1);
+                    if next < self.sprint_picker_entries().len() {
+                        self.selected_sprint_index = next;
+                    }
+                }
+                CurrentScreen::UserPicker => {
+                    let next = self.selected_user_index.saturating_add(1);
+                    if next < self.user_picker_entries().len() {
+                        self.selected_user_index = next;
+                    }
+                }
                 _ => {}
             },
 
@@ -257,11 +1412,38 @@ impl App {
                         self.selected_board_index -= 1;
                     }
                 }
+                CurrentScreen::Backlog if self.issue_tree => self.select_tree_row(false),
                 CurrentScreen::Backlog => {
                     if self.selected_issue_index > 0 {
                         self.selected_issue_index -= 1;
                     }
                 }
+                CurrentScreen::Kanban => self.select_in_column(false),
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::Comments => {
+                    if self.selected_comment_index > 0 {
+                        self.selected_comment_index -= 1;
+                    }
+                }
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::Links => {
+                    if self.selected_link_index > 0 {
+                        self.selected_link_index -= 1;
+                    }
+                }
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::History => {
+                    if self.selected_history_index > 0 {
+                        self.selected_history_index -= 1;
+                    }
+                }
+                CurrentScreen::IssueDetail if self.detail_tab == DetailTab::Attachments => {
+                    if self.selected_attachment_index > 0 {
+                        self.selected_attachment_index -= 1;
+                    }
+                }
+                CurrentScreen::LinkPicker => {
+                    if self.selected_link_type_index > 0 {
+                        self.selected_link_type_index -= 1;
+                    }
+                }
                 CurrentScreen::IssueDetail => {
                     if self.vertical_scroll > 0 {
                         self.vertical_scroll -= 1;
@@ -272,12 +1454,32 @@ impl App {
                         self.selected_worklog_index -= 1;
                     }
                 }
-                _ => {}
-            },
-
-            Action::OpenFilterModal => {
-                self.previous_screen = Some(self.current_screen.clone());
-                self.current_screen = CurrentScreen::FilterModal;
+                CurrentScreen::TransitionModal => {
+                    if self.selected_transition_index > 0 {
+                        self.selected_transition_index -= 1;
+                    }
+                }
+                CurrentScreen::JqlSearch => {
+                    if self.selected_saved_query_index > 0 {
+                        self.selected_saved_query_index -= 1;
+                    }
+                }
+                CurrentScreen::SprintPicker => {
+                    if self.selected_sprint_index > 0 {
+                        self.selected_sprint_index -= 1;
+                    }
+                }
+                CurrentScreen::UserPicker => {
+                    if self.selected_user_index > 0 {
+                        self.selected_user_index -= 1;
+                    }
+                }
+                _ => {}
+            },
+
+            Action::OpenFilterModal => {
+                self.previous_screen = Some(self.current_screen.clone());
+                self.current_screen = CurrentScreen::FilterModal;
                 self.filter_focused_field = FilterField::Assignee;
             }
 
@@ -291,8 +1493,15 @@ impl App {
 
             Action::NextFilterField => {
                 self.filter_focused_field = match self.filter_focused_field {
-                    FilterField::Assignee => FilterField::Status,
-                    FilterField::Status => FilterField::OrderBy,
+                    FilterField::Assignee => FilterField::Watcher,
+                    FilterField::Watcher => FilterField::Status,
+                    FilterField::Status if !self.filter_fields().is_empty() => {
+                        FilterField::Field(0)
+                    }
+                    FilterField::Field(i) if i + 1 < self.filter_fields().len() => {
+                        FilterField::Field(i + 1)
+                    }
+                    FilterField::Status | FilterField::Field(_) => FilterField::OrderBy,
                     FilterField::OrderBy => FilterField::Assignee,
                 };
             }
@@ -301,7 +1510,30 @@ impl App {
                 self.filter_assignee = match self.filter_assignee {
                     AssigneeFilter::CurrentUser => AssigneeFilter::Unassigned,
                     AssigneeFilter::Unassigned => AssigneeFilter::All,
-                    AssigneeFilter::All => AssigneeFilter::CurrentUser,
+                    AssigneeFilter::All | AssigneeFilter::User(_) => AssigneeFilter::CurrentUser,
+                };
+            }
+
+            Action::InputFilterValueChar(ch) => {
+                if let Some(id) = self.focused_filter_field_id() {
+                    self.filter_field_values.entry(id).or_default().push(ch);
+                }
+            }
+
+            Action::DeleteFilterValueChar => {
+                if let Some(id) = self.focused_filter_field_id() {
+                    self.filter_field_values.entry(id).or_default().pop();
+                }
+            }
+
+            Action::CustomFieldsLoaded(fields) => {
+                self.custom_fields = fields;
+            }
+
+            Action::CycleWatcherFilter => {
+                self.filter_watcher = match self.filter_watcher {
+                    WatcherFilter::All => WatcherFilter::Watching,
+                    WatcherFilter::Watching => WatcherFilter::All,
                 };
             }
 
@@ -314,6 +1546,14 @@ impl App {
                 };
             }
 
+            Action::ApplyFilter => {
+                // Filters apply to the board backlog, leaving search mode
+                if self.current_board_id.is_some() {
+                    self.search_jql = None;
+                }
+                self.loading_more_issues = false;
+            }
+
             Action::CycleOrderByFilter => {
                 self.filter_order_by = match self.filter_order_by {
                     OrderByFilter::UpdatedDesc => OrderByFilter::CreatedDesc,
@@ -465,6 +1705,126 @@ impl App {
             Action::HideNotification => {
                 self.notification_title = None;
                 self.notification_message = None;
+                self.failed_task = None;
+            }
+
+            Action::TaskFailed(failure) => {
+                self.is_loading = false;
+                self.loading_more_issues = false;
+                self.notification_title = Some(failure.title);
+                self.notification_message = Some(failure.message);
+                self.notification_is_success = false;
+                self.failed_task = failure.retry;
+            }
+
+            Action::RetryTask(task) => {
+                self.update(Action::HideNotification);
+                // Put the screen back in the state the task started from
+                match task {
+                    RetryableTask::LoadIssues(board_id) => {
+                        self.update(Action::LoadIssues(board_id))
+                    }
+                    RetryableTask::Search(jql) => self.start_search(jql),
+                    RetryableTask::LoadMoreIssues => self.update(Action::LoadMoreIssues),
+                    RetryableTask::LoadTransitions => self.update(Action::OpenTransitionModal),
+                    RetryableTask::LoadCreateIssue => self.update(Action::OpenCreateIssue),
+                    RetryableTask::LoadEditFields => self.update(Action::StartIssueEdit),
+                    RetryableTask::LoadComments(_)
+                    | RetryableTask::LoadHistory(_)
+                    | RetryableTask::LoadLinkedIssue(_)
+                    | RetryableTask::LoadLinkTypes => self.is_loading = true,
+                    RetryableTask::LoadSprints(_) => match self.sprint_picker_mode {
+                        SprintPickerMode::Browse => self.update(Action::OpenSprintPicker),
+                        SprintPickerMode::MoveIssue => self.update(Action::OpenMoveToSprint),
+                    },
+                    _ => {}
+                }
+            }
+
+            Action::ShowConfigError(message) => {
+                self.config_error = Some(message);
+                self.current_screen = CurrentScreen::ConfigError;
+            }
+
+            Action::ShowLogin {
+                base_url,
+                email,
+                needs_email,
+                storage_options,
+            } => {
+                self.login_focused_field = if base_url.is_empty() {
+                    LoginField::BaseUrl
+                } else if needs_email && email.is_empty() {
+                    LoginField::Email
+                } else {
+                    LoginField::Token
+                };
+                self.login_base_url = base_url;
+                self.login_email = email;
+                self.login_needs_email = needs_email;
+                if let Some(first) = storage_options.first() {
+                    self.login_storage = first.clone();
+                }
+                self.login_storage_options = storage_options;
+                self.login_passphrase.clear();
+                self.is_loading = false;
+                self.current_screen = CurrentScreen::Login;
+            }
+
+            Action::ShowUnlock => {
+                self.login_passphrase.clear();
+                self.login_focused_field = LoginField::Passphrase;
+                self.current_screen = CurrentScreen::Unlock;
+            }
+
+            Action::NextLoginField | Action::PreviousLoginField => {
+                let fields = self.login_fields();
+                if let Some(pos) = fields.iter().position(|f| *f == self.login_focused_field) {
+                    let next = if matches!(action, Action::NextLoginField) {
+                        (pos + 1) % fields.len()
+                    } else {
+                        (pos + fields.len() - 1) % fields.len()
+                    };
+                    self.login_focused_field = fields[next].clone();
+                }
+            }
+
+            Action::InputLoginChar(ch) => {
+                if let Some(field) = self.focused_login_input() {
+                    field.push(ch);
+                }
+            }
+
+            Action::DeleteLoginChar => {
+                if let Some(field) = self.focused_login_input() {
+                    field.pop();
+                }
+            }
+
+            Action::CycleLoginStorage => {
+                let options = &self.login_storage_options;
+                if let Some(pos) = options.iter().position(|s| *s == self.login_storage) {
+                    self.login_storage = options[(pos + 1) % options.len()].clone();
+                }
+            }
+
+            Action::SubmitLogin | Action::SubmitUnlock => {
+                self.login_error = None;
+                self.is_loading = true;
+            }
+
+            Action::LoginFailed(message) => {
+                self.login_error = Some(message);
+                self.is_loading = false;
+            }
+
+            Action::SessionStarted => {
+                // Secrets are not needed once the client has been built
+                self.login_token.clear();
+                self.login_passphrase.clear();
+                self.login_error = None;
+                self.is_loading = false;
+                self.current_screen = CurrentScreen::Dashboard;
             }
 
             Action::OpenWorklogListModal => {
@@ -486,13 +1846,13 @@ impl App {
 
             Action::WorklogsLoaded(paginated) => {
                 self.is_loading = false;
-                if paginated.start_at == 0 {
+                if paginated.is_first_page {
                     self.worklogs = paginated.items;
                     self.selected_worklog_index = 0;
                 } else {
                     self.worklogs.extend(paginated.items);
                 }
-                self.total_worklogs = paginated.total;
+                self.total_worklogs = paginated.total.unwrap_or(self.worklogs.len() as u64);
             }
 
             Action::SelectWorklogForEdit => {
@@ -529,19 +1889,2379 @@ impl App {
                 }
             }
 
-            _ => {}
-        }
-    }
+            Action::OpenTransitionModal => {
+                if self.get_selected_issue().is_some() {
+                    self.previous_screen = Some(self.current_screen.clone());
+                    self.current_screen = CurrentScreen::TransitionModal;
+                    self.transitions.clear();
+                    self.selected_transition_index = 0;
+                    self.is_loading = true;
+                }
+            }
 
-    pub fn get_selected_board(&self) -> Option<&Board> {
-        self.boards.get(self.selected_board_index)
-    }
+            Action::CloseTransitionModal => {
+                self.is_loading = false;
+                if let Some(prev) = self.previous_screen.take() {
+                    self.current_screen = prev;
+                } else {
+                    self.current_screen = CurrentScreen::IssueDetail;
+                }
+            }
 
-    pub fn get_selected_issue(&self) -> Option<&Issue> {
-        self.issues.get(self.selected_issue_index)
-    }
+            Action::TransitionsLoaded(transitions) => {
+                self.is_loading = false;
+                self.transitions = transitions;
+                self.selected_transition_index = 0;
+            }
 
-    pub fn get_selected_worklog(&self) -> Option<&WorklogEntry> {
-        self.worklogs.get(self.selected_worklog_index)
+            Action::ApplyTransition => {
+                if self.get_selected_transition().is_some() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::IssueTransitioned(issue_key, transition) => {
+                for issue in self.issues_with_key(&issue_key) {
+                    issue.status = transition.to_status.clone();
+                    issue.status_id = transition.to_status_id.clone();
+                    issue.updated_at = chrono::Utc::now();
+                }
+                // Kanban moves transition without opening the picker
+                if self.current_screen == CurrentScreen::TransitionModal {
+                    self.update(Action::CloseTransitionModal);
+                } else {
+                    self.is_loading = false;
+                }
+            }
+
+            Action::ShowDetailTab(tab) => {
+                self.detail_tab = tab;
+                if tab == DetailTab::Comments && self.comments_need_loading() {
+                    self.comments.clear();
+                    self.selected_comment_index = 0;
+                    self.total_comments = 0;
+                    self.is_loading = true;
+                }
+                if tab == DetailTab::History && self.history_needs_loading() {
+                    self.history.clear();
+                    self.selected_history_index = 0;
+                    self.total_history = 0;
+                    self.is_loading = true;
+                }
+            }
+
+            Action::CommentsLoaded(issue_key, page) => {
+                self.is_loading = false;
+                if page.is_first_page || self.comments_issue_key.as_ref() != Some(&issue_key) {
+                    self.comments = page.items;
+                    self.selected_comment_index = 0;
+                } else {
+                    for comment in page.items {
+                        if !self.comments.iter().any(|c| c.id == comment.id) {
+                            self.comments.push(comment);
+                        }
+                    }
+                }
+                self.total_comments = page.total.unwrap_or(self.comments.len() as u64);
+                self.comments_issue_key = Some(issue_key);
+            }
+
+            Action::LoadMoreComments => {
+                self.is_loading = true;
+                self.update(Action::SelectNext);
+            }
+
+            Action::HistoryLoaded(issue_key, page) => {
+                self.is_loading = false;
+                if page.is_first_page || self.history_issue_key.as_ref() != Some(&issue_key) {
+                    self.history = page.items;
+                    self.selected_history_index = 0;
+                } else {
+                    for entry in page.items {
+                        if !self.history.iter().any(|e| e.id == entry.id) {
+                            self.history.push(entry);
+                        }
+                    }
+                }
+                self.total_history = page.total.unwrap_or(self.history.len() as u64);
+                self.history_issue_key = Some(issue_key);
+            }
+
+            Action::LoadMoreHistory => {
+                self.is_loading = true;
+                self.update(Action::SelectNext);
+            }
+
+            Action::OpenCommentComposer | Action::EditSelectedComment => {
+                let editing = match action {
+                    Action::EditSelectedComment => match self.get_selected_comment() {
+                        Some(comment) => Some(comment.clone()),
+                        None => return,
+                    },
+                    _ => None,
+                };
+                self.comment_input = editing
+                    .as_ref()
+                    .map(|c| c.body.to_markdown())
+                    .unwrap_or_default();
+                self.comment_being_edited = editing;
+                self.mention_suggestions.clear();
+                self.previous_screen = Some(self.current_screen.clone());
+                self.current_screen = CurrentScreen::CommentComposer;
+            }
+
+            Action::CloseCommentComposer => {
+                self.is_loading = false;
+                self.comment_being_edited = None;
+                self.mention_suggestions.clear();
+                self.current_screen = self
+                    .previous_screen
+                    .take()
+                    .unwrap_or(CurrentScreen::IssueDetail);
+            }
+
+            Action::InputCommentChar(_) | Action::DeleteCommentChar => {
+                match action {
+                    Action::InputCommentChar(ch) => self.comment_input.push(ch),
+                    _ => {
+                        self.comment_input.pop();
+                    }
+                }
+                if mention_query(&self.comment_input).is_none_or(str::is_empty) {
+                    self.mention_suggestions.clear();
+                }
+            }
+
+            Action::MentionSuggestionsLoaded(query, users) => {
+                // Answers to queries the user has typed past are dropped
+                if self.current_screen == CurrentScreen::CommentComposer
+                    && mention_query(&self.comment_input) == Some(query.as_str())
+                {
+                    self.mention_suggestions = users;
+                    self.selected_mention_index = 0;
+                }
+            }
+
+            Action::SelectNextMention if !self.mention_suggestions.is_empty() => {
+                self.selected_mention_index =
+                    (self.selected_mention_index + 1) % self.mention_suggestions.len();
+            }
+
+            Action::SelectPreviousMention if !self.mention_suggestions.is_empty() => {
+                self.selected_mention_index = self
+                    .selected_mention_index
+                    .checked_sub(1)
+                    .unwrap_or(self.mention_suggestions.len() - 1);
+            }
+
+            Action::AcceptMention => {
+                if let Some(user) = self.mention_suggestions.get(self.selected_mention_index)
+                    && let Some(query) = mention_query(&self.comment_input)
+                {
+                    let start = self.comment_input.len() - query.len() - 1;
+                    let mention = format!(
+                        "[@{}]({}{}) ",
+                        user.display_name, MENTION_SCHEME, user.account_id
+                    );
+                    self.comment_input.replace_range(start.., &mention);
+                    self.mention_suggestions.clear();
+                }
+            }
+
+            Action::SubmitComment => {
+                if self.comment_body().is_ok() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::CommentAdded(comment) => {
+                let issue_key = self.get_selected_issue().map(|i| i.key.clone());
+                self.update(Action::CloseCommentComposer);
+                self.comment_input.clear();
+                if self.comments_issue_key.is_some() && self.comments_issue_key == issue_key {
+                    // Otherwise it arrives with the last page
+                    if self.comments.len() as u64 == self.total_comments {
+                        self.comments.push(comment);
+                        self.selected_comment_index = self.comments.len() - 1;
+                    }
+                    self.total_comments += 1;
+                }
+                self.detail_tab = DetailTab::Comments;
+            }
+
+            Action::CommentUpdated(comment) => {
+                self.update(Action::CloseCommentComposer);
+                self.comment_input.clear();
+                if let Some(existing) = self.comments.iter_mut().find(|c| c.id == comment.id) {
+                    *existing = comment;
+                }
+            }
+
+            Action::DeleteSelectedComment => {
+                if self.get_selected_comment().is_some() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::CommentDeleted(comment_id) => {
+                self.is_loading = false;
+                if let Some(pos) = self.comments.iter().position(|c| c.id == comment_id) {
+                    self.comments.remove(pos);
+                    self.total_comments = self.total_comments.saturating_sub(1);
+                    if self.selected_comment_index >= self.comments.len() {
+                        self.selected_comment_index = self.comments.len().saturating_sub(1);
+                    }
+                }
+            }
+
+            Action::OpenUserPicker(mode) => {
+                if mode == UserPickerMode::FilterAssignee || self.get_selected_issue().is_some() {
+                    // The filter modal keeps the screen it was opened from
+                    if mode == UserPickerMode::AssignIssue {
+                        self.previous_screen = Some(self.current_screen.clone());
+                    }
+                    // Assignable users are listed straight away, other searches need a name
+                    self.is_loading = mode == UserPickerMode::AssignIssue;
+                    self.user_picker_mode = mode;
+                    self.current_screen = CurrentScreen::UserPicker;
+                    self.user_query.clear();
+                    self.users.clear();
+                    self.users_query.clear();
+                    self.selected_user_index = 0;
+                }
+            }
+
+            Action::CloseUserPicker => {
+                self.is_loading = false;
+                self.current_screen = match self.user_picker_mode {
+                    UserPickerMode::FilterAssignee => CurrentScreen::FilterModal,
+                    UserPickerMode::AssignIssue => self
+                        .previous_screen
+                        .take()
+                        .unwrap_or_else(|| self.issues_screen()),
+                };
+            }
+
+            Action::InputUserQueryChar(ch) => {
+                self.user_query.push(ch);
+                self.selected_user_index = 0;
+            }
+
+            Action::DeleteUserQueryChar => {
+                self.user_query.pop();
+                self.selected_user_index = 0;
+            }
+
+            Action::UsersLoaded(query, users) => {
+                // Results for a shorter query are still ranked against what has been typed
+                // since, unless the ones shown are already closer to it
+                let shown_still_apply = self.user_query.starts_with(&self.users_query);
+                if self.current_screen == CurrentScreen::UserPicker
+                    && self.user_query.starts_with(&query)
+                    && (query.len() >= self.users_query.len() || !shown_still_apply)
+                {
+                    self.is_loading = false;
+                    self.users = users;
+                    self.users_query = query;
+                    self.selected_user_index = 0;
+                }
+            }
+
+            Action::AssignIssue(_) => {
+                if self.current_screen == CurrentScreen::UserPicker {
+                    self.update(Action::CloseUserPicker);
+                }
+                self.is_loading = true;
+            }
+
+            Action::AssignToMe => {
+                if self.get_selected_issue().is_some() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::CurrentUserLoaded(user) => {
+                self.current_user = Some(user);
+            }
+
+            Action::IssueAssigned(issue_key, user) => {
+                self.is_loading = false;
+                for issue in self.issues_with_key(&issue_key) {
+                    issue.assignee = user.clone();
+                    issue.updated_at = chrono::Utc::now();
+                }
+            }
+
+            Action::ToggleWatch | Action::ToggleVote => {
+                if self.get_selected_issue().is_some() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::WatchersLoaded(issue_key, watchers) => {
+                self.is_loading = false;
+                for issue in self.issues_with_key(&issue_key) {
+                    issue.watchers = watchers.clone();
+                }
+            }
+
+            Action::VotesLoaded(issue_key, votes) => {
+                self.is_loading = false;
+                for issue in self.issues_with_key(&issue_key) {
+                    issue.votes = votes.clone();
+                }
+            }
+
+            Action::OpenSelectedLink => {
+                if self.get_selected_link().is_some() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::LinkedIssueLoaded(issue) => {
+                self.is_loading = false;
+                // The detail may have been left while the issue was loading
+                if self.current_screen == CurrentScreen::IssueDetail {
+                    self.issue_stack.push((issue, self.selected_link_index));
+                    self.detail_tab = DetailTab::Description;
+                    self.vertical_scroll = 0;
+                    self.selected_link_index = 0;
+                    self.selected_attachment_index = 0;
+                }
+            }
+
+            Action::CloseLinkedIssue => {
+                if let Some((_, link_index)) = self.issue_stack.pop() {
+                    // The link may have been deleted from the other end
+                    let links = self
+                        .get_selected_issue()
+                        .map_or(0, |issue| issue.links.len());
+                    self.detail_tab = DetailTab::Links;
+                    self.vertical_scroll = 0;
+                    self.selected_link_index = link_index.min(links.saturating_sub(1));
+                    self.selected_attachment_index = 0;
+                }
+            }
+
+            Action::OpenLinkPicker => {
+                if self.get_selected_issue().is_some() {
+                    self.previous_screen = Some(self.current_screen.clone());
+                    self.current_screen = CurrentScreen::LinkPicker;
+                    self.selected_link_type_index = 0;
+                    self.link_target_input.clear();
+                    self.is_loading = self.link_types.is_empty();
+                }
+            }
+
+            Action::CloseLinkPicker => {
+                self.is_loading = false;
+                self.current_screen = self
+                    .previous_screen
+                    .take()
+                    .unwrap_or(CurrentScreen::IssueDetail);
+            }
+
+            Action::LinkTypesLoaded(link_types) => {
+                self.is_loading = false;
+                self.link_types = link_types;
+                self.selected_link_type_index = 0;
+            }
+
+            Action::InputLinkTargetChar(ch) => {
+                self.link_target_input.push(ch.to_ascii_uppercase());
+            }
+
+            Action::DeleteLinkTargetChar => {
+                self.link_target_input.pop();
+            }
+
+            Action::SubmitIssueLink => {
+                if self.get_selected_link_relation().is_some()
+                    && !self.link_target_input.trim().is_empty()
+                {
+                    self.update(Action::CloseLinkPicker);
+                    self.detail_tab = DetailTab::Links;
+                    self.is_loading = true;
+                }
+            }
+
+            Action::IssueLinked(linked) => {
+                self.is_loading = false;
+                for issue in self.issues_with_key(&linked.key.clone()) {
+                    issue.links = linked.links.clone();
+                    issue.updated_at = linked.updated_at;
+                }
+            }
+
+            Action::DeleteSelectedLink => {
+                if self.get_selected_link().is_some() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::DownloadSelectedAttachment => {
+                // One download at a time, so its progress can be shown
+                if self.attachment_download.is_none()
+                    && let Some(attachment) = self.get_selected_attachment()
+                {
+                    self.attachment_download = Some((attachment.id.clone(), 0));
+                }
+            }
+
+            Action::AttachmentDownloadProgress(attachment_id, received) => {
+                self.attachment_download = Some((attachment_id, received));
+            }
+
+            Action::AttachmentDownloadEnded(attachment_id) => {
+                if self
+                    .attachment_download
+                    .as_ref()
+                    .is_some_and(|(id, _)| *id == attachment_id)
+                {
+                    self.attachment_download = None;
+                }
+            }
+
+            Action::OpenAttachmentUpload => {
+                if self.get_selected_issue().is_some() {
+                    self.previous_screen = Some(self.current_screen.clone());
+                    self.current_screen = CurrentScreen::AttachmentUpload;
+                    self.attachment_path_input.clear();
+                }
+            }
+
+            Action::CloseAttachmentUpload => {
+                self.current_screen = self
+                    .previous_screen
+                    .take()
+                    .unwrap_or(CurrentScreen::IssueDetail);
+            }
+
+            Action::InputAttachmentPathChar(ch) => {
+                self.attachment_path_input.push(ch);
+            }
+
+            Action::DeleteAttachmentPathChar => {
+                self.attachment_path_input.pop();
+            }
+
+            Action::SubmitAttachmentUpload => {
+                if self.attachment_path().is_ok() {
+                    self.update(Action::CloseAttachmentUpload);
+                    self.detail_tab = DetailTab::Attachments;
+                    self.is_loading = true;
+                }
+            }
+
+            Action::AttachmentsAdded(issue_key, attachments) => {
+                self.is_loading = false;
+                for issue in self.issues_with_key(&issue_key) {
+                    issue.attachments.extend(attachments.iter().cloned());
+                }
+            }
+
+            Action::IssueLinkDeleted(link_id) => {
+                self.is_loading = false;
+                // Both ends of the link may be loaded
+                for issue in self
+                    .issues
+                    .iter_mut()
+                    .chain(self.issue_stack.iter_mut().map(|(issue, _)| issue))
+                {
+                    issue.links.retain(|link| link.id != link_id);
+                }
+                let links = self
+                    .get_selected_issue()
+                    .map_or(0, |issue| issue.links.len());
+                if self.selected_link_index >= links {
+                    self.selected_link_index = links.saturating_sub(1);
+                }
+            }
+
+            Action::FilterByAssignee(user) => {
+                self.filter_assignee = AssigneeFilter::User(user);
+                self.filter_focused_field = FilterField::Assignee;
+                self.current_screen = CurrentScreen::FilterModal;
+            }
+
+            Action::StartIssueEdit => {
+                if self.get_selected_issue().is_some() {
+                    self.current_screen = CurrentScreen::EditIssue;
+                    self.edit_fields.clear();
+                    self.edit_focused_field = 0;
+                    self.is_loading = true;
+                }
+            }
+
+            Action::CancelIssueEdit => {
+                self.is_loading = false;
+                self.edit_fields.clear();
+                self.current_screen = CurrentScreen::IssueDetail;
+            }
+
+            Action::EditFieldsLoaded(issue_key, fields) => {
+                if self.current_screen == CurrentScreen::EditIssue
+                    && let Some(issue) = self.get_selected_issue()
+                    && issue.key == issue_key
+                {
+                    self.edit_fields = edit_form_fields(fields, issue);
+                    self.is_loading = false;
+                    if self.edit_fields.is_empty() {
+                        self.update(Action::CancelIssueEdit);
+                        self.update(Action::ShowNotification(
+                            "🔒 Read Only".to_string(),
+                            format!("None of the fields of {} can be edited", issue_key),
+                            false,
+                        ));
+                    }
+                }
+            }
+
+            Action::NextEditField if !self.edit_fields.is_empty() => {
+                self.edit_focused_field = (self.edit_focused_field + 1) % self.edit_fields.len();
+            }
+
+            Action::PreviousEditField if !self.edit_fields.is_empty() => {
+                self.edit_focused_field = self
+                    .edit_focused_field
+                    .checked_sub(1)
+                    .unwrap_or(self.edit_fields.len() - 1);
+            }
+
+            Action::InputEditChar(ch) => {
+                if let Some(field) = self.edit_fields.get_mut(self.edit_focused_field)
+                    && field.takes_text()
+                {
+                    field.input.push(ch);
+                }
+            }
+
+            Action::DeleteEditChar => {
+                if let Some(field) = self.edit_fields.get_mut(self.edit_focused_field) {
+                    field.input.pop();
+                }
+            }
+
+            Action::NextEditOption | Action::PreviousEditOption => {
+                let forward = matches!(action, Action::NextEditOption);
+                if let Some(field) = self.edit_fields.get_mut(self.edit_focused_field) {
+                    field.cycle(forward);
+                }
+            }
+
+            Action::SubmitIssueEdit => {
+                if let Ok(changes) = self.issue_changes() {
+                    let key = self.get_selected_issue().map(|i| i.key.clone());
+                    for issue in self.issues_with_key(key.as_deref().unwrap_or_default()) {
+                        for (meta, value) in &changes {
+                            apply_issue_change(issue, meta, value);
+                        }
+                    }
+                    self.edit_fields.clear();
+                    self.current_screen = CurrentScreen::IssueDetail;
+                }
+            }
+
+            Action::IssueUpdateFailed(original, field_ids) => {
+                // Changes that landed while the update was on its way are kept
+                for issue in self.issues_with_key(&original.key.clone()) {
+                    for field_id in &field_ids {
+                        restore_issue_field(issue, &original, field_id);
+                    }
+                }
+            }
+
+            Action::OpenCreateIssue => {
+                if let Some(project_key) = self.create_issue_project() {
+                    self.previous_screen = Some(self.current_screen.clone());
+                    self.current_screen = CurrentScreen::CreateIssue;
+                    self.create_project_key = Some(project_key);
+                    self.create_issue_types.clear();
+                    self.create_issue_type_index = 0;
+                    self.create_fields.clear();
+                    self.create_focused_field = 0;
+                    self.is_loading = true;
+                }
+            }
+
+            Action::CloseCreateIssue => {
+                self.is_loading = false;
+                self.current_screen = self
+                    .previous_screen
+                    .take()
+                    .unwrap_or_else(|| self.issues_screen());
+            }
+
+            Action::CreateIssueTypesLoaded(project_key, issue_types) => {
+                if self.current_screen == CurrentScreen::CreateIssue
+                    && self.create_project_key.as_ref() == Some(&project_key)
+                {
+                    // The fields of the preselected type are loaded right after
+                    self.create_issue_type_index = default_issue_type_index(&issue_types);
+                    self.create_issue_types = issue_types;
+                }
+            }
+
+            Action::SelectCreateIssueType(issue_type_id) => {
+                if let Some(index) = self
+                    .create_issue_types
+                    .iter()
+                    .position(|t| t.id == issue_type_id)
+                {
+                    self.create_issue_type_index = index;
+                    self.is_loading = true;
+                }
+            }
+
+            Action::CreateFieldsLoaded(issue_type_id, fields) => {
+                if self.current_screen == CurrentScreen::CreateIssue
+                    && self
+                        .get_selected_create_issue_type()
+                        .is_some_and(|t| t.id == issue_type_id)
+                {
+                    self.is_loading = false;
+                    let previous = std::mem::take(&mut self.create_fields);
+                    self.create_fields = create_form_fields(fields)
+                        .into_iter()
+                        .map(|field| {
+                            // Keep what was entered in fields shared with the previous type
+                            previous
+                                .iter()
+                                .find(|old| old.meta == field.meta)
+                                .cloned()
+                                .unwrap_or(field)
+                        })
+                        .collect();
+                    self.create_focused_field =
+                        self.create_focused_field.min(self.create_fields.len());
+                }
+            }
+
+            Action::NextCreateField => {
+                self.create_focused_field =
+                    (self.create_focused_field + 1) % (self.create_fields.len() + 1);
+            }
+
+            Action::PreviousCreateField => {
+                self.create_focused_field = self
+                    .create_focused_field
+                    .checked_sub(1)
+                    .unwrap_or(self.create_fields.len());
+            }
+
+            Action::InputCreateChar(ch) => {
+                if let Some(field) = self.focused_create_field_mut()
+                    && field.takes_text()
+                {
+                    field.input.push(ch);
+                }
+            }
+
+            Action::DeleteCreateChar => {
+                if let Some(field) = self.focused_create_field_mut() {
+                    field.input.pop();
+                }
+            }
+
+            Action::NextCreateOption | Action::PreviousCreateOption => {
+                let forward = matches!(action, Action::NextCreateOption);
+                if let Some(field) = self.focused_create_field_mut() {
+                    field.cycle(forward);
+                }
+            }
+
+            Action::ToggleCreateOption => {
+                if let Some(field) = self.focused_create_field_mut() {
+                    field.toggle();
+                }
+            }
+
+            Action::SubmitCreateIssue => {
+                if self.new_issue().is_ok() {
+                    self.is_loading = true;
+                }
+            }
+
+            Action::IssueCreated(issue) => {
+                self.update(Action::CloseCreateIssue);
+                self.issues.retain(|i| i.key != issue.key);
+                self.issues.insert(0, issue);
+                self.selected_issue_index = 0;
+                self.total_issues = self.total_issues.map(|t| t + 1);
+                self.vertical_scroll = 0;
+            }
+
+            Action::OpenJqlSearch => {
+                self.previous_screen = Some(self.current_screen.clone());
+                self.current_screen = CurrentScreen::JqlSearch;
+                self.jql_focused_field = JqlSearchField::Query;
+                if let Some(jql) = &self.search_jql {
+                    self.jql_input = jql.clone();
+                }
+            }
+
+            Action::CloseJqlSearch => {
+                if let Some(prev) = self.previous_screen.take() {
+                    self.current_screen = prev;
+                } else {
+                    self.current_screen = CurrentScreen::BoardsList;
+                }
+            }
+
+            Action::NextJqlSearchField => {
+                self.jql_focused_field = match self.jql_focused_field {
+                    JqlSearchField::Query => JqlSearchField::SavedQueries,
+                    JqlSearchField::SavedQueries => JqlSearchField::Query,
+                    // Leaving the name input cancels the save
+                    JqlSearchField::SaveName => JqlSearchField::Query,
+                };
+            }
+
+            Action::InputJqlChar(ch) => match self.jql_focused_field {
+                JqlSearchField::Query => self.jql_input.push(ch),
+                JqlSearchField::SaveName => self.jql_name_input.push(ch),
+                JqlSearchField::SavedQueries => {}
+            },
+
+            Action::DeleteJqlChar => match self.jql_focused_field {
+                JqlSearchField::Query => {
+                    self.jql_input.pop();
+                }
+                JqlSearchField::SaveName => {
+                    self.jql_name_input.pop();
+                }
+                JqlSearchField::SavedQueries => {}
+            },
+
+            Action::RunJqlSearch => {
+                let jql = self.jql_input.trim().to_string();
+                if !jql.is_empty() {
+                    self.start_search(jql);
+                }
+            }
+
+            Action::SearchFailed => {
+                // Go back to the editor so the query can be fixed
+                self.is_loading = false;
+                self.previous_screen = None;
+                self.current_screen = CurrentScreen::JqlSearch;
+                self.jql_focused_field = JqlSearchField::Query;
+            }
+
+            Action::RunSavedQuery => {
+                if let Some(query) = self.get_selected_saved_query() {
+                    let jql = query.jql.trim().to_string();
+                    self.jql_input = jql.clone();
+                    self.start_search(jql);
+                }
+            }
+
+            Action::SavedQueriesLoaded(queries) => {
+                self.saved_queries = queries;
+                self.selected_saved_query_index = 0;
+            }
+
+            Action::StartSaveQuery => {
+                if !self.jql_input.trim().is_empty() {
+                    self.jql_name_input.clear();
+                    self.jql_focused_field = JqlSearchField::SaveName;
+                }
+            }
+
+            Action::ConfirmSaveQuery => {
+                self.jql_focused_field = JqlSearchField::Query;
+            }
+
+            Action::QuerySaved(query) => {
+                // Local queries are listed first, replacing one with the same name
+                self.saved_queries
+                    .retain(|q| !(q.source == SavedQuerySource::Local && q.name == query.name));
+                let local_count = self
+                    .saved_queries
+                    .iter()
+                    .filter(|q| q.source == SavedQuerySource::Local)
+                    .count();
+                self.saved_queries.insert(local_count, query);
+            }
+
+            Action::SavedQueryDeleted(name) => {
+                self.saved_queries
+                    .retain(|q| !(q.source == SavedQuerySource::Local && q.name == name));
+                if self.selected_saved_query_index >= self.saved_queries.len() {
+                    self.selected_saved_query_index = self.saved_queries.len().saturating_sub(1);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Switches the issue list to the results of a JQL search.
+    fn start_search(&mut self, jql: String) {
+        self.search_jql = Some(jql);
+        self.is_loading = true;
+        self.issues.clear();
+        self.selected_issue_index = 0;
+        self.total_issues = None;
+        self.next_issues_page = None;
+        self.loading_more_issues = false;
+        self.vertical_scroll = 0;
+        self.current_screen = CurrentScreen::Backlog;
+        self.previous_screen = None;
+    }
+
+    /// Screen listing the loaded issues: the Kanban board when it is enabled for a board backlog.
+    fn issues_screen(&self) -> CurrentScreen {
+        if self.kanban_view && self.can_show_kanban() {
+            CurrentScreen::Kanban
+        } else {
+            CurrentScreen::Backlog
+        }
+    }
+
+    /// Index of the loaded parent of an issue, which is where it shows in the tree.
+    fn tree_parent(&self, index: usize) -> Option<usize> {
+        let parent_key = self.issues[index].parent_key.as_ref()?;
+        self.issues.iter().position(|i| &i.key == parent_key)
+    }
+
+    /// Rows of the backlog tree, in display order, skipping children of collapsed rows.
+    ///
+    /// Issues whose parent is not loaded are shown at the top level, in list order.
+    pub fn tree_rows(&self) -> Vec<TreeRow> {
+        let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut roots = Vec::new();
+        for index in 0..self.issues.len() {
+            match self.tree_parent(index) {
+                Some(parent) if parent != index => children.entry(parent).or_default().push(index),
+                _ => roots.push(index),
+            }
+        }
+
+        let mut rows = Vec::new();
+        // Depth-first, children right below their parent
+        let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|i| (i, 0)).collect();
+        while let Some((index, depth)) = stack.pop() {
+            let issue = &self.issues[index];
+            let kids = children.get(&index).map(Vec::as_slice).unwrap_or_default();
+            let collapsed = self.collapsed_issues.contains(&issue.key);
+            rows.push(TreeRow {
+                issue_index: index,
+                depth,
+                children: kids.len(),
+                done_children: kids
+                    .iter()
+                    .filter(|&&k| self.issues[k].status == IssueStatus::Done)
+                    .count(),
+                collapsed,
+            });
+            if !collapsed {
+                stack.extend(kids.iter().rev().map(|&k| (k, depth + 1)));
+            }
+        }
+        rows
+    }
+
+    fn select_tree_row(&mut self, forward: bool) {
+        let rows = self.tree_rows();
+        let Some(pos) = rows
+            .iter()
+            .position(|r| r.issue_index == self.selected_issue_index)
+        else {
+            // The selection is hidden under a collapsed row
+            if let Some(first) = rows.first() {
+                self.selected_issue_index = first.issue_index;
+            }
+            return;
+        };
+        let next = if forward {
+            (pos + 1).min(rows.len() - 1)
+        } else {
+            pos.saturating_sub(1)
+        };
+        self.selected_issue_index = rows[next].issue_index;
+    }
+
+    /// Entries of the sprint picker: `None` first (whole board, or the backlog when moving
+    /// an issue), then the sprints. Closed sprints cannot receive issues.
+    pub fn sprint_picker_entries(&self) -> Vec<Option<&Sprint>> {
+        let moving = self.sprint_picker_mode == SprintPickerMode::MoveIssue;
+        std::iter::once(None)
+            .chain(
+                self.sprints
+                    .iter()
+                    .filter(|s| !(moving && s.state == SprintState::Closed))
+                    .map(Some),
+            )
+            .collect()
+    }
+
+    /// Sprint highlighted in the sprint picker, as the `Option<SprintId>` of its action.
+    pub fn get_selected_sprint_entry(&self) -> Option<Option<SprintId>> {
+        self.sprint_picker_entries()
+            .get(self.selected_sprint_index)
+            .map(|entry| entry.map(|s| s.id))
+    }
+
+    pub fn current_sprint_id(&self) -> Option<SprintId> {
+        self.current_sprint.as_ref().map(|s| s.id)
+    }
+
+    /// The board backlog or JQL search whose issues are listed.
+    pub fn issue_source(&self) -> Option<IssueSource> {
+        match (&self.search_jql, self.current_board_id) {
+            (Some(jql), _) => Some(IssueSource::Search(jql.clone())),
+            (None, Some(board_id)) => Some(IssueSource::Board {
+                board_id,
+                sprint_id: self.current_sprint_id(),
+            }),
+            (None, None) => None,
+        }
+    }
+
+    /// Kanban boards have no sprints. Boards opened from the configuration are not in
+    /// the boards list, so they are assumed to have them.
+    pub fn current_board_has_sprints(&self) -> bool {
+        self.current_board_id.is_some_and(|id| {
+            self.boards
+                .iter()
+                .find(|b| b.id == id)
+                .is_none_or(|b| !b.board_type.eq_ignore_ascii_case("kanban"))
+        })
+    }
+
+    /// JQL results span several boards, so only board backlogs have columns.
+    fn can_show_kanban(&self) -> bool {
+        self.current_board_id.is_some() && self.search_jql.is_none()
+    }
+
+    /// Indices into `issues` for each board column, in list order.
+    ///
+    /// Issues whose status is not mapped to any column are left out, as in Jira.
+    pub fn kanban_columns(&self) -> Vec<Vec<usize>> {
+        let Some(configuration) = &self.board_configuration else {
+            return Vec::new();
+        };
+        let mut columns = vec![Vec::new(); configuration.columns.len()];
+        for (index, issue) in self.issues.iter().enumerate() {
+            if let Some(column) = configuration.column_of(issue) {
+                columns[column].push(index);
+            }
+        }
+        columns
+    }
+
+    /// Board column of the selected issue.
+    pub fn selected_kanban_column(&self) -> Option<usize> {
+        let configuration = self.board_configuration.as_ref()?;
+        configuration.column_of(self.get_selected_issue()?)
+    }
+
+    fn select_in_column(&mut self, forward: bool) {
+        let columns = self.kanban_columns();
+        let Some(column) = self.selected_kanban_column() else {
+            return self.select_adjacent_column(true);
+        };
+        let cards = &columns[column];
+        let Some(row) = cards.iter().position(|&i| i == self.selected_issue_index) else {
+            return;
+        };
+        let next = if forward {
+            (row + 1).min(cards.len() - 1)
+        } else {
+            row.saturating_sub(1)
+        };
+        self.selected_issue_index = cards[next];
+    }
+
+    /// Selects a card in the nearest non-empty column, keeping the row when possible.
+    fn select_adjacent_column(&mut self, forward: bool) {
+        let columns = self.kanban_columns();
+        let current = self.selected_kanban_column();
+        let row = current
+            .and_then(|c| {
+                columns[c]
+                    .iter()
+                    .position(|&i| i == self.selected_issue_index)
+            })
+            .unwrap_or(0);
+
+        let candidates: Vec<usize> = match (current, forward) {
+            (Some(c), true) => (c + 1..columns.len()).collect(),
+            (Some(c), false) => (0..c).rev().collect(),
+            // Nothing selected on the board yet: start from the first column
+            (None, _) => (0..columns.len()).collect(),
+        };
+        if let Some(target) = candidates.into_iter().find(|&c| !columns[c].is_empty()) {
+            let cards = &columns[target];
+            self.selected_issue_index = cards[row.min(cards.len() - 1)];
+        }
+    }
+
+    /// Fields shown in the login screen, in focus order.
+    /// Project new issues are created in: the project of the current board, or else the
+    /// project of the selected issue (search results).
+    pub fn create_issue_project(&self) -> Option<String> {
+        self.boards
+            .iter()
+            .find(|b| Some(b.id) == self.current_board_id && self.search_jql.is_none())
+            .map(|b| b.project_key.clone())
+            .filter(|key| !key.is_empty())
+            .or_else(|| {
+                let issue = self.get_selected_issue()?;
+                let (project, _) = issue.key.rsplit_once('-')?;
+                Some(project.to_string())
+            })
+    }
+
+    pub fn get_selected_create_issue_type(&self) -> Option<&IssueType> {
+        self.create_issue_types.get(self.create_issue_type_index)
+    }
+
+    /// The issue type after (or before) the selected one, wrapping around.
+    pub fn adjacent_create_issue_type(&self, forward: bool) -> Option<&IssueType> {
+        let count = self.create_issue_types.len();
+        if count < 2 {
+            return None;
+        }
+        let index = if forward {
+            (self.create_issue_type_index + 1) % count
+        } else {
+            (self.create_issue_type_index + count - 1) % count
+        };
+        self.create_issue_types.get(index)
+    }
+
+    /// The focused form field, `None` while the issue type is focused.
+    pub fn focused_create_field(&self) -> Option<&FormField> {
+        self.create_fields
+            .get(self.create_focused_field.checked_sub(1)?)
+    }
+
+    fn focused_create_field_mut(&mut self) -> Option<&mut FormField> {
+        self.create_fields
+            .get_mut(self.create_focused_field.checked_sub(1)?)
+    }
+
+    /// The focused input of the issue edit form.
+    pub fn focused_edit_field(&self) -> Option<&FormField> {
+        self.edit_fields.get(self.edit_focused_field)
+    }
+
+    /// The fields changed in the edit form, `None` standing for a cleared field.
+    pub fn issue_changes(&self) -> Result<Vec<(FieldMeta, Option<FieldValue>)>, AppError> {
+        let mut changes = Vec::new();
+        let mut messages = Vec::new();
+        for field in self.edit_fields.iter().filter(|f| f.is_dirty()) {
+            match field.value() {
+                Ok(value) => changes.push((field.meta.clone(), value)),
+                Err(message) => messages.push(message),
+            }
+        }
+        if messages.is_empty() {
+            Ok(changes)
+        } else {
+            Err(AppError::Validation {
+                messages,
+                field_errors: Default::default(),
+            })
+        }
+    }
+
+    /// Builds the issue described by the create form, or explains what is missing.
+    pub fn new_issue(&self) -> Result<NewIssue, AppError> {
+        let (Some(project_key), Some(issue_type)) = (
+            &self.create_project_key,
+            self.get_selected_create_issue_type(),
+        ) else {
+            return Err(AppError::Validation {
+                messages: vec!["The issue types have not been loaded".to_string()],
+                field_errors: Default::default(),
+            });
+        };
+
+        let mut fields = Vec::new();
+        let mut messages = Vec::new();
+        for field in &self.create_fields {
+            match field.value() {
+                Ok(Some(value)) => fields.push((field.meta.clone(), value)),
+                Ok(None) => {}
+                Err(message) => messages.push(message),
+            }
+        }
+        if !messages.is_empty() {
+            return Err(AppError::Validation {
+                messages,
+                field_errors: Default::default(),
+            });
+        }
+
+        Ok(NewIssue {
+            project_key: project_key.clone(),
+            issue_type_id: issue_type.id.clone(),
+            fields,
+        })
+    }
+
+    pub fn login_fields(&self) -> Vec<LoginField> {
+        let mut fields = vec![LoginField::BaseUrl];
+        if self.login_needs_email {
+            fields.push(LoginField::Email);
+        }
+        fields.push(LoginField::Token);
+        if self.login_storage_options.len() > 1 {
+            fields.push(LoginField::Storage);
+        }
+        if self.login_storage == LoginStorage::EncryptedFile {
+            fields.push(LoginField::Passphrase);
+        }
+        fields
+    }
+
+    fn focused_login_input(&mut self) -> Option<&mut String> {
+        match self.login_focused_field {
+            LoginField::BaseUrl => Some(&mut self.login_base_url),
+            LoginField::Email => Some(&mut self.login_email),
+            LoginField::Token => Some(&mut self.login_token),
+            LoginField::Passphrase => Some(&mut self.login_passphrase),
+            LoginField::Storage => None,
+        }
+    }
+
+    /// The issue filter currently selected in the filter modal.
+    pub fn issue_filter(&self) -> IssueFilter {
+        let mut filter = IssueFilter::from_options(
+            self.filter_assignee.clone(),
+            self.filter_watcher.clone(),
+            self.filter_status.clone(),
+            self.filter_order_by.clone(),
+        );
+        for field in self.filter_fields() {
+            let value = self.filter_field_value(field);
+            if !value.is_empty() {
+                filter = filter.with_field(field, value);
+            }
+        }
+        filter
+    }
+
+    /// Custom fields shown as backlog columns.
+    pub fn column_fields(&self) -> Vec<&FieldMeta> {
+        declared_fields(&self.custom_fields, &self.field_columns)
+    }
+
+    /// Custom fields offered in the filter modal.
+    pub fn filter_fields(&self) -> Vec<&FieldMeta> {
+        declared_fields(&self.custom_fields, &self.field_filters)
+    }
+
+    /// The value typed for a custom field filter, trimmed.
+    pub fn filter_field_value(&self, field: &FieldMeta) -> &str {
+        self.filter_field_values
+            .get(&field.id)
+            .map_or("", |value| value.trim())
+    }
+
+    fn focused_filter_field_id(&self) -> Option<String> {
+        match self.filter_focused_field {
+            FilterField::Field(i) => self.filter_fields().get(i).map(|field| field.id.clone()),
+            _ => None,
+        }
+    }
+
+    /// Builds the board filter from the values typed in the board filter modal.
+    pub fn board_filter_input(&self) -> BoardFilter {
+        let text = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        BoardFilter {
+            name: text(&self.board_filter_name),
+            project_key: text(&self.board_filter_project),
+            board_type: self.board_filter_type.clone(),
+        }
+    }
+
+    pub fn get_selected_board(&self) -> Option<&Board> {
+        self.boards.get(self.selected_board_index)
+    }
+
+    /// The issue the detail view acts on: the last one opened through a link, or the one
+    /// selected in the list.
+    pub fn get_selected_issue(&self) -> Option<&Issue> {
+        match self.issue_stack.last() {
+            Some((issue, _)) => Some(issue),
+            None => self.issues.get(self.selected_issue_index),
+        }
+    }
+
+    /// Keys of the issues followed to reach the one shown, starting from the list.
+    pub fn issue_trail(&self) -> Vec<&str> {
+        self.issues
+            .get(self.selected_issue_index)
+            .into_iter()
+            .chain(self.issue_stack.iter().map(|(issue, _)| issue))
+            .map(|issue| issue.key.as_str())
+            .collect()
+    }
+
+    /// Loaded copies of an issue: in the list and in the stack of linked issues.
+    fn issues_with_key<'a>(&'a mut self, key: &'a str) -> impl Iterator<Item = &'a mut Issue> {
+        self.issues
+            .iter_mut()
+            .chain(self.issue_stack.iter_mut().map(|(issue, _)| issue))
+            .filter(move |issue| issue.key == key)
+    }
+
+    pub fn get_selected_link(&self) -> Option<&IssueLink> {
+        self.get_selected_issue()?
+            .links
+            .get(self.selected_link_index)
+    }
+
+    pub fn get_selected_attachment(&self) -> Option<&Attachment> {
+        self.get_selected_issue()?
+            .attachments
+            .get(self.selected_attachment_index)
+    }
+
+    /// The file typed in the upload popup, with `~` standing for the home directory.
+    pub fn attachment_path(&self) -> Result<PathBuf, AppError> {
+        let input = self.attachment_path_input.trim();
+        let path = match input.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+            None => PathBuf::from(input),
+        };
+        let message = if input.is_empty() {
+            "Enter the path of the file to attach".to_string()
+        } else if !path.is_file() {
+            format!("{} is not a file", path.display())
+        } else {
+            return Ok(path);
+        };
+        Err(AppError::Validation {
+            messages: vec![message],
+            field_errors: Default::default(),
+        })
+    }
+
+    /// Relations offered by the link picker: each link type read both ways ("blocks",
+    /// "is blocked by"), once for symmetric types such as "relates to".
+    pub fn link_relations(&self) -> Vec<(&IssueLinkType, LinkDirection)> {
+        self.link_types
+            .iter()
+            .flat_map(|link_type| {
+                let inward = (link_type.inward != link_type.outward)
+                    .then_some((link_type, LinkDirection::Inward));
+                std::iter::once((link_type, LinkDirection::Outward)).chain(inward)
+            })
+            .collect()
+    }
+
+    pub fn get_selected_link_relation(&self) -> Option<(&IssueLinkType, LinkDirection)> {
+        self.link_relations()
+            .get(self.selected_link_type_index)
+            .copied()
+    }
+
+    pub fn get_selected_worklog(&self) -> Option<&WorklogEntry> {
+        self.worklogs.get(self.selected_worklog_index)
+    }
+
+    /// Entries of the user picker: "Unassigned" first when assigning (until a name is typed),
+    /// then the loaded users best matching the query.
+    ///
+    /// Users Jira returned for the exact query are kept even when their name does not match,
+    /// as Jira also searches emails and usernames.
+    pub fn user_picker_entries(&self) -> Vec<Option<&User>> {
+        let exact = self.users_query == self.user_query;
+        let mut ranked: Vec<(i64, &User)> = self
+            .users
+            .iter()
+            .filter_map(|user| {
+                fuzzy_score(&self.user_query, &user.display_name)
+                    .or(exact.then_some(-1))
+                    .map(|score| (score, user))
+            })
+            .collect();
+        ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        let unassigned =
+            self.user_picker_mode == UserPickerMode::AssignIssue && self.user_query.is_empty();
+        unassigned
+            .then_some(None)
+            .into_iter()
+            .chain(ranked.into_iter().map(|(_, user)| Some(user)))
+            .collect()
+    }
+
+    /// User highlighted in the user picker; `Some(None)` is the "Unassigned" entry.
+    pub fn get_selected_user_entry(&self) -> Option<Option<&User>> {
+        self.user_picker_entries()
+            .get(self.selected_user_index)
+            .copied()
+    }
+
+    pub fn get_selected_comment(&self) -> Option<&Comment> {
+        self.comments.get(self.selected_comment_index)
+    }
+
+    /// Whether the comments shown belong to another issue than the selected one.
+    pub fn comments_need_loading(&self) -> bool {
+        self.get_selected_issue()
+            .is_some_and(|issue| self.comments_issue_key.as_ref() != Some(&issue.key))
+    }
+
+    /// Whether the history shown belongs to another issue than the selected one.
+    pub fn history_needs_loading(&self) -> bool {
+        self.get_selected_issue()
+            .is_some_and(|issue| self.history_issue_key.as_ref() != Some(&issue.key))
+    }
+
+    /// Whether the next page of issues should be fetched: the selection is near the end of the
+    /// list, or anywhere on the board, whose columns need every issue.
+    pub fn needs_more_issues(&self) -> bool {
+        // After a failure, wait for the user to retry instead of hammering Jira on every key
+        if self.is_loading
+            || self.loading_more_issues
+            || self.failed_task.is_some()
+            || self.next_issues_page.is_none()
+        {
+            return false;
+        }
+        match self.current_screen {
+            CurrentScreen::Backlog if self.issue_tree => {
+                let rows = self.tree_rows();
+                rows.iter()
+                    .position(|r| r.issue_index == self.selected_issue_index)
+                    .is_none_or(|pos| pos + 2 >= rows.len())
+            }
+            CurrentScreen::Backlog => {
+                self.selected_issue_index >= self.issues.len().saturating_sub(2)
+            }
+            CurrentScreen::Kanban => true,
+            _ => false,
+        }
+    }
+
+    /// Whether moving down the history should fetch the next page first.
+    pub fn needs_more_history(&self) -> bool {
+        !self.is_loading
+            && (self.history.len() as u64) < self.total_history
+            && self.selected_history_index + 2 >= self.history.len()
+    }
+
+    /// Whether moving down the comments list should fetch the next page first.
+    pub fn needs_more_comments(&self) -> bool {
+        !self.is_loading
+            && (self.comments.len() as u64) < self.total_comments
+            && self.selected_comment_index + 2 >= self.comments.len()
+    }
+
+    /// The comment typed in the composer, as Markdown.
+    pub fn comment_body(&self) -> Result<&str, AppError> {
+        let body = self.comment_input.trim();
+        if body.is_empty() {
+            return Err(AppError::Validation {
+                messages: vec!["The comment is empty".to_string()],
+                field_errors: Default::default(),
+            });
+        }
+        Ok(body)
+    }
+
+    pub fn get_selected_transition(&self) -> Option<&Transition> {
+        self.transitions.get(self.selected_transition_index)
+    }
+
+    pub fn get_selected_saved_query(&self) -> Option<&SavedQuery> {
+        self.saved_queries.get(self.selected_saved_query_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{
+        BoardColumn, FieldChange, FieldOption, IssueLevel, IssueStatus, IssueType,
+    };
+
+    fn failure(retry: Option<RetryableTask>) -> Action {
+        Action::TaskFailed(TaskFailure {
+            title: "❌ Error".to_string(),
+            message: "Failed to load issues: timeout".to_string(),
+            retry,
+        })
+    }
+
+    #[test]
+    fn task_failure_clears_loading_and_offers_retry() {
+        let mut app = App::new();
+        app.update(Action::LoadIssues(7));
+        assert!(app.is_loading);
+
+        app.update(failure(Some(RetryableTask::LoadIssues(7))));
+        assert!(!app.is_loading);
+        assert!(app.notification_message.is_some());
+        assert_eq!(app.failed_task, Some(RetryableTask::LoadIssues(7)));
+
+        app.update(Action::RetryTask(RetryableTask::LoadIssues(7)));
+        assert!(app.is_loading);
+        assert_eq!(app.failed_task, None);
+        assert_eq!(app.notification_message, None);
+        assert_eq!(app.current_board_id, Some(7));
+    }
+
+    #[test]
+    fn dismissing_the_notification_drops_the_retry() {
+        let mut app = App::new();
+        app.update(failure(Some(RetryableTask::LoadBoards)));
+        app.update(Action::HideNotification);
+        assert_eq!(app.failed_task, None);
+    }
+
+    fn board(id: u64) -> Board {
+        Board {
+            id,
+            name: format!("Board {}", id),
+            project_key: "PROJ".to_string(),
+            board_type: "scrum".to_string(),
+        }
+    }
+
+    #[test]
+    fn board_pages_stream_into_the_list() {
+        let mut app = App::new();
+        app.board_filter_name = "team".to_string();
+        app.update(Action::ApplyBoardFilter);
+        let filter = app.board_filter.clone();
+        assert_eq!(filter.name.as_deref(), Some("team"));
+
+        app.update(Action::BoardsLoaded(
+            filter.clone(),
+            Paginated::with_is_last(vec![board(1), board(2)], 0, false),
+        ));
+        app.update(Action::BoardsLoaded(
+            filter,
+            Paginated::with_is_last(vec![board(2), board(3)], 2, true),
+        ));
+        let ids: Vec<u64> = app.boards.iter().map(|b| b.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+
+        // Late pages of an older search are dropped
+        app.update(Action::BoardsLoaded(
+            BoardFilter::default(),
+            Paginated::with_is_last(vec![board(9)], 50, true),
+        ));
+        assert_eq!(app.boards.len(), 3);
+    }
+
+    fn issue(key: &str, status_id: &str) -> Issue {
+        Issue {
+            key: key.to_string(),
+            summary: format!("Summary of {}", key),
+            description: None,
+            status: IssueStatus::Todo,
+            status_id: Some(status_id.to_string()),
+            issue_type: IssueType::default(),
+            parent_key: None,
+            subtask_keys: Vec::new(),
+            links: Vec::new(),
+            attachments: Vec::new(),
+            watchers: Watchers::default(),
+            votes: Votes::default(),
+            labels: Vec::new(),
+            fields: HashMap::new(),
+            assignee: None,
+            priority: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn backlog(board_id: u64) -> IssueSource {
+        IssueSource::Board {
+            board_id,
+            sprint_id: None,
+        }
+    }
+
+    fn kanban_app() -> App {
+        let mut app = App::new();
+        app.update(Action::LoadIssues(7));
+        app.update(Action::ToggleKanban);
+        app.update(Action::BoardConfigurationLoaded(BoardConfiguration {
+            board_id: 7,
+            columns: ["To Do", "Doing", "Done"]
+                .iter()
+                .enumerate()
+                .map(|(i, name)| BoardColumn {
+                    name: name.to_string(),
+                    status_ids: vec![(i + 1).to_string()],
+                })
+                .collect(),
+        }));
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(
+                vec![
+                    issue("P-1", "1"),
+                    issue("P-2", "2"),
+                    issue("P-3", "1"),
+                    issue("P-4", "3"),
+                ],
+                4,
+                0,
+            ),
+        ));
+        app
+    }
+
+    #[test]
+    fn kanban_selection_moves_within_and_across_columns() {
+        let mut app = kanban_app();
+        assert_eq!(app.current_screen, CurrentScreen::Kanban);
+        assert_eq!(app.kanban_columns(), vec![vec![0, 2], vec![1], vec![3]]);
+
+        app.update(Action::SelectNext);
+        assert_eq!(app.selected_issue_index, 2);
+        app.update(Action::SelectNext);
+        assert_eq!(app.selected_issue_index, 2);
+
+        // The row is kept when possible, clamped otherwise
+        app.update(Action::SelectNextColumn);
+        assert_eq!(app.selected_issue_index, 1);
+        app.update(Action::SelectNextColumn);
+        assert_eq!(app.selected_kanban_column(), Some(2));
+        app.update(Action::SelectNextColumn);
+        assert_eq!(app.selected_issue_index, 3);
+
+        // Switching views keeps the selected issue
+        app.update(Action::ToggleKanban);
+        assert_eq!(app.current_screen, CurrentScreen::Backlog);
+        assert_eq!(app.selected_issue_index, 3);
+        app.update(Action::ToggleKanban);
+        assert_eq!(app.current_screen, CurrentScreen::Kanban);
+        assert_eq!(app.get_selected_issue().unwrap().key, "P-4");
+    }
+
+    #[test]
+    fn board_pages_are_fetched_one_at_a_time_and_land_behind_the_detail() {
+        let mut app = kanban_app();
+        assert!(!app.needs_more_issues());
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("P-1", "1"), issue("P-2", "2")], 4, 0),
+        ));
+        assert!(app.needs_more_issues());
+
+        // A second trigger before the page lands does not fetch it again
+        app.update(Action::LoadMoreIssues);
+        assert!(app.loading_more_issues);
+        assert!(!app.needs_more_issues());
+        app.update(Action::SelectNext);
+        assert!(!app.needs_more_issues());
+
+        app.update(Action::ViewIssueDetail);
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("P-3", "1"), issue("P-4", "3")], 4, 2),
+        ));
+        assert!(!app.loading_more_issues);
+        assert_eq!(app.issues.len(), 4);
+        assert_eq!(app.current_screen, CurrentScreen::IssueDetail);
+        assert!(!app.needs_more_issues());
+    }
+
+    #[test]
+    fn pages_of_a_board_left_behind_are_dropped() {
+        let mut app = kanban_app();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("P-1", "1")], 2, 0),
+        ));
+        app.update(Action::LoadMoreIssues);
+
+        app.update(Action::LoadIssues(8));
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("P-2", "1")], 2, 1),
+        ));
+        assert!(app.issues.is_empty());
+
+        // A page asked for before a new result set started is dropped too
+        app.update(Action::IssuesLoaded(
+            backlog(8),
+            Paginated::new(vec![issue("Q-1", "1")], 2, 1),
+        ));
+        assert!(app.issues.is_empty());
+        app.update(Action::IssuesLoaded(
+            backlog(8),
+            Paginated::new(vec![issue("Q-1", "1")], 2, 0),
+        ));
+        assert_eq!(app.issues.len(), 1);
+    }
+
+    #[test]
+    fn transitioned_cards_change_column() {
+        let mut app = kanban_app();
+        app.update(Action::MoveIssueToColumn(1));
+        assert!(app.is_loading);
+
+        app.update(Action::IssueTransitioned(
+            "P-1".to_string(),
+            Transition {
+                id: "21".to_string(),
+                name: "Start".to_string(),
+                to_status: IssueStatus::InProgress,
+                to_status_id: Some("2".to_string()),
+            },
+        ));
+        assert!(!app.is_loading);
+        assert_eq!(app.current_screen, CurrentScreen::Kanban);
+        assert_eq!(app.selected_kanban_column(), Some(1));
+        assert_eq!(app.kanban_columns()[1], vec![0, 1]);
+    }
+
+    fn sprint(id: SprintId, state: SprintState) -> Sprint {
+        Sprint {
+            id,
+            name: format!("Sprint {}", id),
+            state,
+            goal: None,
+            start_date: None,
+            end_date: None,
+        }
+    }
+
+    #[test]
+    fn issues_moved_out_of_the_shown_sprint_leave_the_list() {
+        let mut app = App::new();
+        app.update(Action::LoadIssues(7));
+        app.update(Action::OpenSprintPicker);
+        app.update(Action::SprintsLoaded(
+            7,
+            vec![
+                sprint(10, SprintState::Active),
+                sprint(11, SprintState::Future),
+                sprint(9, SprintState::Closed),
+            ],
+        ));
+        assert_eq!(app.sprint_picker_entries().len(), 4);
+
+        app.update(Action::SelectNext);
+        assert_eq!(app.get_selected_sprint_entry(), Some(Some(10)));
+        app.update(Action::SelectSprint(Some(10)));
+        assert_eq!(app.current_screen, CurrentScreen::Backlog);
+        assert_eq!(app.current_sprint_id(), Some(10));
+        assert!(app.is_loading);
+
+        let sprint = IssueSource::Board {
+            board_id: 7,
+            sprint_id: Some(10),
+        };
+        app.update(Action::IssuesLoaded(
+            sprint,
+            Paginated::new(vec![issue("P-1", "1"), issue("P-2", "1")], 2, 0),
+        ));
+        app.selected_issue_index = 1;
+
+        // Closed sprints cannot receive issues
+        let sprints = app.sprints.clone();
+        app.update(Action::OpenMoveToSprint);
+        app.update(Action::SprintsLoaded(7, sprints));
+        assert_eq!(app.sprint_picker_entries().len(), 3);
+        assert_eq!(app.get_selected_sprint_entry(), Some(None));
+
+        app.update(Action::MoveIssueToSprint(None));
+        assert_eq!(app.current_screen, CurrentScreen::Backlog);
+        app.update(Action::IssueSprintChanged("P-2".to_string(), None));
+        assert_eq!(app.issues.len(), 1);
+        assert_eq!(app.total_issues, Some(1));
+        assert_eq!(app.selected_issue_index, 0);
+
+        // Another board starts without a sprint
+        app.update(Action::LoadIssues(8));
+        assert_eq!(app.current_sprint_id(), None);
+    }
+
+    fn child(key: &str, parent: Option<&str>, level: IssueLevel, status: IssueStatus) -> Issue {
+        Issue {
+            status,
+            issue_type: IssueType {
+                name: format!("{:?}", level),
+                level,
+                ..IssueType::default()
+            },
+            parent_key: parent.map(str::to_string),
+            ..issue(key, "1")
+        }
+    }
+
+    #[test]
+    fn tree_groups_children_under_loaded_parents() {
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(
+                vec![
+                    child("S-1", Some("E-1"), IssueLevel::Standard, IssueStatus::Done),
+                    child("T-1", Some("S-1"), IssueLevel::Subtask, IssueStatus::Todo),
+                    child("E-1", None, IssueLevel::Epic, IssueStatus::InProgress),
+                    child("S-2", Some("E-9"), IssueLevel::Standard, IssueStatus::Todo),
+                    child("S-3", Some("E-1"), IssueLevel::Standard, IssueStatus::Todo),
+                ],
+                5,
+                0,
+            ),
+        ));
+        app.update(Action::ToggleIssueTree);
+
+        let order = |app: &App| -> Vec<(String, usize)> {
+            app.tree_rows()
+                .iter()
+                .map(|r| (app.issues[r.issue_index].key.clone(), r.depth))
+                .collect()
+        };
+        let expected = [("E-1", 0), ("S-1", 1), ("T-1", 2), ("S-3", 1), ("S-2", 0)];
+        let expected: Vec<(String, usize)> =
+            expected.iter().map(|(k, d)| (k.to_string(), *d)).collect();
+        assert_eq!(order(&app), expected);
+        let epic = &app.tree_rows()[0];
+        assert_eq!((epic.done_children, epic.children), (1, 2));
+
+        // Navigation follows the tree, not the list order
+        app.selected_issue_index = 2;
+        app.update(Action::SelectNext);
+        assert_eq!(app.get_selected_issue().unwrap().key, "S-1");
+
+        // Left on a leaf goes to the parent, then folds it
+        app.update(Action::SelectNext);
+        app.update(Action::CollapseIssue);
+        assert_eq!(app.get_selected_issue().unwrap().key, "S-1");
+        app.update(Action::CollapseIssue);
+        assert_eq!(app.tree_rows().len(), 4);
+        app.update(Action::ToggleIssueCollapsed);
+        assert_eq!(app.tree_rows().len(), 5);
+    }
+
+    fn field(id: &str, kind: FieldKind, required: bool) -> FieldMeta {
+        FieldMeta {
+            id: id.to_string(),
+            name: id.to_string(),
+            kind,
+            required,
+            has_default: false,
+            allowed_values: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn created_issues_need_their_required_fields_and_land_on_top() {
+        let mut app = App::new();
+        app.boards = vec![board(7)];
+        app.update(Action::LoadIssues(7));
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("PROJ-1", "1")], 1, 0),
+        ));
+
+        app.update(Action::OpenCreateIssue);
+        assert_eq!(app.create_project_key.as_deref(), Some("PROJ"));
+        let subtask = IssueType {
+            id: "3".to_string(),
+            name: "Sub-task".to_string(),
+            level: IssueLevel::Subtask,
+        };
+        let story = IssueType {
+            id: "10".to_string(),
+            name: "Story".to_string(),
+            level: IssueLevel::Standard,
+        };
+        app.update(Action::CreateIssueTypesLoaded(
+            "PROJ".to_string(),
+            vec![subtask, story],
+        ));
+        assert_eq!(app.get_selected_create_issue_type().unwrap().id, "10");
+
+        let mut priority = field("priority", FieldKind::Choice, false);
+        priority.allowed_values = vec![FieldOption {
+            id: "2".to_string(),
+            name: "High".to_string(),
+        }];
+        app.update(Action::CreateFieldsLoaded(
+            "10".to_string(),
+            vec![
+                field(
+                    "project",
+                    FieldKind::Unsupported("project".to_string()),
+                    true,
+                ),
+                field("customfield_1", FieldKind::Text, false),
+                priority,
+                field("summary", FieldKind::Text, true),
+            ],
+        ));
+        let ids: Vec<&str> = app
+            .create_fields
+            .iter()
+            .map(|f| f.meta.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["summary", "priority"]);
+        assert!(app.new_issue().is_err());
+
+        app.update(Action::NextCreateField);
+        for ch in "New".chars() {
+            app.update(Action::InputCreateChar(ch));
+        }
+        app.update(Action::NextCreateField);
+        app.update(Action::NextCreateOption);
+        let new_issue = app.new_issue().unwrap();
+        assert_eq!(new_issue.issue_type_id, "10");
+        assert_eq!(
+            new_issue.fields.iter().map(|(_, v)| v).collect::<Vec<_>>(),
+            vec![
+                &FieldValue::Text("New".to_string()),
+                &FieldValue::Choice("2".to_string())
+            ]
+        );
+
+        app.update(Action::SubmitCreateIssue);
+        app.update(Action::IssueCreated(issue("PROJ-2", "1")));
+        assert_eq!(app.current_screen, CurrentScreen::Backlog);
+        assert_eq!(app.issues[0].key, "PROJ-2");
+        assert_eq!(app.selected_issue_index, 0);
+        assert_eq!(app.total_issues, Some(2));
+        assert!(!app.is_loading);
+    }
+
+    #[test]
+    fn issue_edits_show_at_once_and_roll_back_on_failure() {
+        let mut app = App::new();
+        let mut original = issue("PROJ-1", "1");
+        original.priority = Some("High".to_string());
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![original.clone()], 1, 0),
+        ));
+        app.update(Action::ViewIssueDetail);
+        app.update(Action::StartIssueEdit);
+
+        let mut priority = field("priority", FieldKind::Choice, false);
+        priority.allowed_values = ["Low", "High"]
+            .iter()
+            .enumerate()
+            .map(|(id, name)| FieldOption {
+                id: id.to_string(),
+                name: name.to_string(),
+            })
+            .collect();
+        app.update(Action::EditFieldsLoaded(
+            "PROJ-1".to_string(),
+            vec![priority, field("summary", FieldKind::Text, true)],
+        ));
+        let ids: Vec<&str> = app.edit_fields.iter().map(|f| f.meta.id.as_str()).collect();
+        assert_eq!(ids, vec!["summary", "priority"]);
+        assert_eq!(app.edit_fields[1].chosen, vec![1]);
+        assert!(app.issue_changes().unwrap().is_empty());
+
+        // Emptying a required field is refused
+        for _ in 0..original.summary.len() {
+            app.update(Action::DeleteEditChar);
+        }
+        assert!(app.edit_fields[0].is_dirty());
+        assert!(app.issue_changes().is_err());
+        for ch in "Renamed".chars() {
+            app.update(Action::InputEditChar(ch));
+        }
+
+        app.update(Action::NextEditField);
+        app.update(Action::PreviousEditOption);
+        let changes = app.issue_changes().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].1, Some(FieldValue::Choice("0".to_string())));
+
+        app.update(Action::SubmitIssueEdit);
+        assert_eq!(app.current_screen, CurrentScreen::IssueDetail);
+        assert_eq!(app.issues[0].summary, "Renamed");
+        assert_eq!(app.issues[0].priority.as_deref(), Some("Low"));
+
+        // A watch toggled while the update is on its way survives the rollback
+        let watchers = Watchers {
+            count: 1,
+            is_watching: true,
+            users: Vec::new(),
+        };
+        app.update(Action::WatchersLoaded(
+            "PROJ-1".to_string(),
+            watchers.clone(),
+        ));
+
+        let changed = vec!["summary".to_string(), "priority".to_string()];
+        app.update(Action::IssueUpdateFailed(original.clone(), changed));
+        assert_eq!(app.issues[0].summary, original.summary);
+        assert_eq!(app.issues[0].priority, original.priority);
+        assert_eq!(app.issues[0].watchers, watchers);
+    }
+
+    fn comment(id: &str, markdown: &str) -> Comment {
+        Comment {
+            id: id.to_string(),
+            author: "Ana Lopez".to_string(),
+            body: RichText::from_markdown(markdown),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn comments_are_paged_and_mentions_completed() {
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("PROJ-1", "1")], 1, 0),
+        ));
+        app.update(Action::ViewIssueDetail);
+        assert!(app.comments_need_loading());
+        app.update(Action::ShowDetailTab(DetailTab::Comments));
+        assert!(app.is_loading);
+
+        let page = vec![comment("1", "first"), comment("2", "second")];
+        app.update(Action::CommentsLoaded(
+            "PROJ-1".to_string(),
+            Paginated::new(page, 3, 0),
+        ));
+        assert!(!app.comments_need_loading());
+        assert!(app.needs_more_comments());
+        app.update(Action::LoadMoreComments);
+        assert_eq!(app.selected_comment_index, 1);
+        app.update(Action::CommentsLoaded(
+            "PROJ-1".to_string(),
+            Paginated::new(vec![comment("3", "third")], 3, 2),
+        ));
+        assert_eq!(app.comments.len(), 3);
+        assert!(!app.needs_more_comments());
+
+        app.update(Action::OpenCommentComposer);
+        for ch in "thanks @an".chars() {
+            app.update(Action::InputCommentChar(ch));
+        }
+        assert_eq!(mention_query(&app.comment_input), Some("an"));
+        let ana = User {
+            account_id: "abc-123".to_string(),
+            display_name: "Ana Lopez".to_string(),
+        };
+        // Answers to an older query are ignored
+        app.update(Action::MentionSuggestionsLoaded(
+            "a".to_string(),
+            vec![ana.clone()],
+        ));
+        assert!(app.mention_suggestions.is_empty());
+        app.update(Action::MentionSuggestionsLoaded(
+            "an".to_string(),
+            vec![ana],
+        ));
+        app.update(Action::AcceptMention);
+        assert_eq!(app.comment_input, "thanks [@Ana Lopez](mention:abc-123) ");
+        assert_eq!(mention_query(&app.comment_input), None);
+        assert!(app.comment_body().is_ok());
+
+        app.update(Action::SubmitComment);
+        app.update(Action::CommentAdded(comment("4", "thanks")));
+        assert_eq!(app.current_screen, CurrentScreen::IssueDetail);
+        assert_eq!(app.comments.len(), 4);
+        assert_eq!(app.total_comments, 4);
+        assert_eq!(app.selected_comment_index, 3);
+        assert!(app.comment_input.is_empty());
+
+        app.update(Action::EditSelectedComment);
+        assert_eq!(app.comment_input, "thanks");
+        app.update(Action::CloseCommentComposer);
+
+        app.update(Action::CommentDeleted("4".to_string()));
+        assert_eq!(app.comments.len(), 3);
+        assert_eq!(app.selected_comment_index, 2);
+    }
+
+    fn change(id: &str, field: &str, from: Option<&str>, to: Option<&str>) -> ChangelogEntry {
+        ChangelogEntry {
+            id: id.to_string(),
+            author: "Ana Lopez".to_string(),
+            created_at: chrono::Utc::now(),
+            changes: vec![FieldChange {
+                field: field.to_string(),
+                from: from.map(str::to_string),
+                to: to.map(str::to_string),
+            }],
+        }
+    }
+
+    #[test]
+    fn history_is_loaded_per_issue_and_paged() {
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("PROJ-1", "1"), issue("PROJ-2", "2")], 2, 0),
+        ));
+        app.update(Action::ViewIssueDetail);
+        app.update(Action::ShowDetailTab(DetailTab::History));
+        assert!(app.is_loading);
+
+        let page = vec![
+            change("1", "status", Some("To Do"), Some("In Progress")),
+            change("2", "assignee", None, Some("Ana Lopez")),
+        ];
+        app.update(Action::HistoryLoaded(
+            "PROJ-1".to_string(),
+            Paginated::new(page, 3, 0),
+        ));
+        assert!(!app.history_needs_loading());
+        assert!(app.needs_more_history());
+        app.update(Action::LoadMoreHistory);
+        assert_eq!(app.selected_history_index, 1);
+        app.update(Action::HistoryLoaded(
+            "PROJ-1".to_string(),
+            Paginated::new(vec![change("3", "labels", Some("ui"), None)], 3, 2),
+        ));
+        assert_eq!(app.history.len(), 3);
+        assert!(!app.needs_more_history());
+        app.update(Action::SelectNext);
+        assert_eq!(app.selected_history_index, 2);
+
+        // Another issue starts over
+        app.update(Action::GoToBacklog);
+        app.update(Action::SelectNext);
+        app.update(Action::ViewIssueDetail);
+        assert!(app.history_needs_loading());
+        app.update(Action::ShowDetailTab(DetailTab::History));
+        assert!(app.history.is_empty());
+        assert_eq!(app.selected_history_index, 0);
+    }
+
+    fn user(account_id: &str, display_name: &str) -> User {
+        User {
+            account_id: account_id.to_string(),
+            display_name: display_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn fuzzy_matches_prefer_word_starts_and_runs() {
+        assert_eq!(fuzzy_score("", "Ana Lopez"), Some(0));
+        assert!(fuzzy_score("alz", "Ana Lopez").is_some());
+        assert_eq!(fuzzy_score("zla", "Ana Lopez"), None);
+        assert!(fuzzy_score("lo", "Ana Lopez") > fuzzy_score("lo", "Carlos Ruiz"));
+        assert!(fuzzy_score("AL", "Ana Lopez") > fuzzy_score("al", "Natalia"));
+    }
+
+    #[test]
+    fn user_picker_ranks_users_and_assigns_the_choice() {
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("PROJ-1", "1")], 1, 0),
+        ));
+        app.update(Action::OpenUserPicker(UserPickerMode::AssignIssue));
+        assert_eq!(app.current_screen, CurrentScreen::UserPicker);
+        app.update(Action::UsersLoaded(
+            String::new(),
+            vec![user("1", "Carlos Ruiz"), user("2", "Ana Lopez")],
+        ));
+        // "Unassigned" comes first until a name is typed
+        assert_eq!(app.user_picker_entries().len(), 3);
+        assert_eq!(app.get_selected_user_entry(), Some(None));
+
+        for ch in "lo".chars() {
+            app.update(Action::InputUserQueryChar(ch));
+        }
+        let names: Vec<&str> = app
+            .user_picker_entries()
+            .into_iter()
+            .flatten()
+            .map(|u| u.display_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Ana Lopez", "Carlos Ruiz"]);
+
+        // An answer for a query typed past does not replace closer results
+        app.update(Action::UsersLoaded(
+            "lo".to_string(),
+            vec![user("2", "Ana Lopez")],
+        ));
+        app.update(Action::UsersLoaded(String::new(), Vec::new()));
+        assert_eq!(app.users.len(), 1);
+
+        app.update(Action::AssignIssue(Some(user("2", "Ana Lopez"))));
+        assert_eq!(app.current_screen, CurrentScreen::Backlog);
+        app.update(Action::IssueAssigned(
+            "PROJ-1".to_string(),
+            Some(user("2", "Ana Lopez")),
+        ));
+        assert_eq!(app.issues[0].assignee, Some(user("2", "Ana Lopez")));
+        assert!(!app.is_loading);
+    }
+
+    #[test]
+    fn assignee_filter_picks_a_specific_user() {
+        let mut app = App::new();
+        app.current_screen = CurrentScreen::Backlog;
+        app.update(Action::OpenFilterModal);
+        app.update(Action::OpenUserPicker(UserPickerMode::FilterAssignee));
+        // Without an issue there is no "Unassigned" entry, and nothing before a name is typed
+        assert!(app.user_picker_entries().is_empty());
+
+        app.update(Action::InputUserQueryChar('a'));
+        app.update(Action::UsersLoaded(
+            "a".to_string(),
+            vec![user("2", "Ana Lopez")],
+        ));
+        app.update(Action::FilterByAssignee(user("2", "Ana Lopez")));
+        assert_eq!(app.current_screen, CurrentScreen::FilterModal);
+        assert_eq!(app.filter_assignee.label(), "Ana Lopez");
+
+        // Leaving the filter modal still goes back to the backlog
+        app.update(Action::CloseFilterModal);
+        assert_eq!(app.current_screen, CurrentScreen::Backlog);
+        app.update(Action::OpenFilterModal);
+        app.update(Action::CycleAssigneeFilter);
+        assert_eq!(app.filter_assignee, AssigneeFilter::CurrentUser);
+    }
+
+    #[test]
+    fn watchers_and_votes_update_every_copy_of_the_issue() {
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("PROJ-1", "1")], 1, 0),
+        ));
+        app.update(Action::ViewIssueDetail);
+        app.issue_stack.push((issue("PROJ-1", "1"), 0));
+
+        app.update(Action::ToggleWatch);
+        assert!(app.is_loading);
+        let watchers = Watchers {
+            count: 2,
+            is_watching: true,
+            users: vec![user("1", "Me"), user("2", "Ana Lopez")],
+        };
+        app.update(Action::WatchersLoaded(
+            "PROJ-1".to_string(),
+            watchers.clone(),
+        ));
+        assert!(!app.is_loading);
+        assert_eq!(app.issues[0].watchers, watchers);
+        assert_eq!(app.get_selected_issue().unwrap().watchers, watchers);
+
+        app.update(Action::ToggleVote);
+        let votes = Votes {
+            count: 1,
+            has_voted: true,
+        };
+        app.update(Action::VotesLoaded("PROJ-1".to_string(), votes.clone()));
+        assert_eq!(app.issues[0].votes, votes);
+    }
+
+    #[test]
+    fn watcher_filter_restricts_the_backlog_to_watched_issues() {
+        let mut app = App::new();
+        app.update(Action::OpenFilterModal);
+        app.update(Action::NextFilterField);
+        assert_eq!(app.filter_focused_field, FilterField::Watcher);
+        app.update(Action::CycleWatcherFilter);
+        assert_eq!(app.filter_watcher, WatcherFilter::Watching);
+        assert_eq!(
+            app.issue_filter().to_jql(),
+            "assignee = currentUser() AND watcher = currentUser() ORDER BY updated DESC"
+        );
+        app.update(Action::CycleWatcherFilter);
+        assert_eq!(app.filter_watcher, WatcherFilter::All);
+    }
+
+    #[test]
+    fn custom_fields_become_columns_and_typed_filters() {
+        let mut app = App::new();
+        app.field_columns = vec!["customfield_10016".to_string()];
+        app.field_filters = vec!["CUSTOMFIELD_10030".to_string()];
+        app.update(Action::CustomFieldsLoaded(vec![
+            field("customfield_10016", FieldKind::Number, false),
+            field("customfield_10030", FieldKind::Choice, false),
+        ]));
+        assert_eq!(app.column_fields()[0].id, "customfield_10016");
+
+        app.update(Action::OpenFilterModal);
+        for _ in 0..3 {
+            app.update(Action::NextFilterField);
+        }
+        assert_eq!(app.filter_focused_field, FilterField::Field(0));
+        for ch in "Core".chars() {
+            app.update(Action::InputFilterValueChar(ch));
+        }
+        app.update(Action::InputFilterValueChar('x'));
+        app.update(Action::DeleteFilterValueChar);
+        assert_eq!(
+            app.issue_filter().to_jql(),
+            r#"assignee = currentUser() AND cf[10030] = "Core" ORDER BY updated DESC"#
+        );
+        app.update(Action::NextFilterField);
+        assert_eq!(app.filter_focused_field, FilterField::OrderBy);
+    }
+
+    fn blocks() -> IssueLinkType {
+        IssueLinkType {
+            id: "10000".to_string(),
+            name: "Blocks".to_string(),
+            inward: "is blocked by".to_string(),
+            outward: "blocks".to_string(),
+        }
+    }
+
+    fn link(id: &str, direction: LinkDirection, key: &str) -> IssueLink {
+        IssueLink {
+            id: id.to_string(),
+            link_type: blocks(),
+            direction,
+            issue_key: key.to_string(),
+            issue_summary: format!("Summary of {}", key),
+            issue_status: IssueStatus::Todo,
+        }
+    }
+
+    #[test]
+    fn linked_issues_are_stacked_and_closed_in_order() {
+        let mut first = issue("PROJ-1", "1");
+        first.links = vec![
+            link("1", LinkDirection::Inward, "PROJ-5"),
+            link("2", LinkDirection::Outward, "PROJ-2"),
+        ];
+        let mut second = issue("PROJ-2", "1");
+        second.links = vec![link("2", LinkDirection::Inward, "PROJ-1")];
+
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![first], 1, 0),
+        ));
+        app.update(Action::ViewIssueDetail);
+        app.update(Action::ShowDetailTab(DetailTab::Links));
+        app.update(Action::SelectNext);
+        assert_eq!(app.get_selected_link().unwrap().issue_key, "PROJ-2");
+
+        app.update(Action::OpenSelectedLink);
+        assert!(app.is_loading);
+        app.update(Action::LinkedIssueLoaded(second));
+        assert_eq!(app.get_selected_issue().unwrap().key, "PROJ-2");
+        assert_eq!(app.issue_trail(), vec!["PROJ-1", "PROJ-2"]);
+        assert_eq!(app.detail_tab, DetailTab::Description);
+
+        // Changes reach the copy being shown
+        app.update(Action::IssueAssigned("PROJ-2".to_string(), None));
+        app.update(Action::ShowDetailTab(DetailTab::Links));
+        app.update(Action::IssueLinkDeleted("2".to_string()));
+        assert!(app.get_selected_issue().unwrap().links.is_empty());
+
+        // Esc goes back to the link the issue was opened from
+        app.update(Action::CloseLinkedIssue);
+        assert_eq!(app.current_screen, CurrentScreen::IssueDetail);
+        assert_eq!(app.get_selected_issue().unwrap().key, "PROJ-1");
+        assert_eq!(app.issues[0].links.len(), 1);
+        assert_eq!(app.selected_link_index, 0);
+        assert_eq!(app.detail_tab, DetailTab::Links);
+    }
+
+    #[test]
+    fn link_picker_offers_both_readings_of_each_type() {
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![issue("PROJ-1", "1")], 1, 0),
+        ));
+        app.update(Action::ViewIssueDetail);
+        app.update(Action::OpenLinkPicker);
+        assert_eq!(app.current_screen, CurrentScreen::LinkPicker);
+        assert!(app.is_loading);
+
+        let mut relates = blocks();
+        relates.name = "Relates".to_string();
+        relates.inward = "relates to".to_string();
+        relates.outward = "relates to".to_string();
+        app.update(Action::LinkTypesLoaded(vec![blocks(), relates]));
+        let descriptions: Vec<&str> = app
+            .link_relations()
+            .into_iter()
+            .map(|(link_type, direction)| link_type.description(direction))
+            .collect();
+        assert_eq!(descriptions, vec!["blocks", "is blocked by", "relates to"]);
+
+        // Nothing to link to yet
+        app.update(Action::SelectNext);
+        app.update(Action::SubmitIssueLink);
+        assert_eq!(app.current_screen, CurrentScreen::LinkPicker);
+
+        for ch in "proj-9".chars() {
+            app.update(Action::InputLinkTargetChar(ch));
+        }
+        assert_eq!(app.link_target_input, "PROJ-9");
+        assert_eq!(
+            app.get_selected_link_relation().map(|(_, d)| d),
+            Some(LinkDirection::Inward)
+        );
+        app.update(Action::SubmitIssueLink);
+        assert_eq!(app.current_screen, CurrentScreen::IssueDetail);
+        assert_eq!(app.detail_tab, DetailTab::Links);
+
+        let mut linked = issue("PROJ-1", "1");
+        linked.links = vec![link("7", LinkDirection::Inward, "PROJ-9")];
+        app.update(Action::IssueLinked(linked));
+        assert_eq!(app.issues[0].links.len(), 1);
+        assert!(!app.is_loading);
+    }
+
+    #[test]
+    fn attachments_are_downloaded_one_at_a_time_and_uploaded_from_a_file() {
+        let mut with_attachment = issue("PROJ-1", "1");
+        with_attachment.attachments = vec![Attachment {
+            id: "10".to_string(),
+            filename: "crash.log".to_string(),
+            size: 2048,
+            mime_type: "text/plain".to_string(),
+            author: "Ana Lopez".to_string(),
+            created_at: chrono::Utc::now(),
+            content_url: String::new(),
+        }];
+        let mut app = App::new();
+        app.update(Action::IssuesLoaded(
+            backlog(7),
+            Paginated::new(vec![with_attachment], 1, 0),
+        ));
+        app.update(Action::ViewIssueDetail);
+        app.update(Action::ShowDetailTab(DetailTab::Attachments));
+
+        app.update(Action::DownloadSelectedAttachment);
+        assert_eq!(app.attachment_download, Some(("10".to_string(), 0)));
+        app.update(Action::AttachmentDownloadProgress("10".to_string(), 1024));
+        assert_eq!(app.attachment_download, Some(("10".to_string(), 1024)));
+        app.update(Action::AttachmentDownloadEnded("10".to_string()));
+        assert_eq!(app.attachment_download, None);
+
+        app.update(Action::OpenAttachmentUpload);
+        assert_eq!(app.current_screen, CurrentScreen::AttachmentUpload);
+        // Nothing is uploaded until the path names a file
+        app.update(Action::SubmitAttachmentUpload);
+        assert_eq!(app.current_screen, CurrentScreen::AttachmentUpload);
+        for ch in "/no/such/file".chars() {
+            app.update(Action::InputAttachmentPathChar(ch));
+        }
+        assert!(app.attachment_path().is_err());
+
+        app.attachment_path_input = env!("CARGO_MANIFEST_DIR").to_string() + "/Cargo.toml";
+        app.update(Action::SubmitAttachmentUpload);
+        assert_eq!(app.current_screen, CurrentScreen::IssueDetail);
+        assert!(app.is_loading);
+
+        let mut uploaded = app.issues[0].attachments[0].clone();
+        uploaded.id = "11".to_string();
+        app.update(Action::AttachmentsAdded(
+            "PROJ-1".to_string(),
+            vec![uploaded],
+        ));
+        assert_eq!(app.issues[0].attachments.len(), 2);
+        app.update(Action::SelectNext);
+        assert_eq!(app.get_selected_attachment().unwrap().id, "11");
     }
 }
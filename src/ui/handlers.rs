@@ -1,44 +1,120 @@
 use chrono::{Local, TimeZone, Utc};
 use log::error;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::application::use_cases::{
-    AddWorklogUseCase, DeleteWorklogUseCase, GetBacklogUseCase, GetBoardsUseCase,
-    GetWorklogsUseCase, UpdateWorklogUseCase,
+    AddCommentUseCase, AddWorklogUseCase, AssignIssueUseCase, CreateIssueUseCase,
+    DeleteCommentUseCase, DeleteIssueLinkUseCase, DeleteSavedQueryUseCase, DeleteWorklogUseCase,
+    DownloadAttachmentUseCase, GetBacklogUseCase, GetBoardConfigurationUseCase, GetBoardsUseCase,
+    GetChangelogUseCase, GetCommentsUseCase, GetCreateFieldsUseCase, GetCreateIssueTypesUseCase,
+    GetCurrentUserUseCase, GetCustomFieldsUseCase, GetEditFieldsUseCase, GetIssueLinkTypesUseCase,
+    GetIssueUseCase, GetSavedQueriesUseCase, GetSprintsUseCase, GetTransitionsUseCase,
+    GetWatchersUseCase, GetWorklogsUseCase, LinkIssuesUseCase, MoveIssueToSprintUseCase,
+    SaveQueryUseCase, SearchAssignableUsersUseCase, SearchIssuesUseCase, SearchUsersUseCase,
+    TransitionIssueUseCase, UpdateCommentUseCase, UpdateIssueUseCase, UpdateWorklogUseCase,
+    UploadAttachmentUseCase, VoteIssueUseCase, WatchIssueUseCase,
 };
-use crate::domain::models::{IssueFilter, Worklog};
-use crate::ui::app::{Action, App, CurrentScreen};
+use crate::domain::errors::AppError;
+use crate::domain::models::{
+    BoardId, PageCursor, SavedQuery, SavedQuerySource, SprintId, User, Worklog,
+};
+use crate::ui::app::{
+    Action, App, CurrentScreen, DetailTab, IssueSource, RetryableTask, TaskFailure, UserPickerMode,
+    default_issue_type_index, mention_query,
+};
+
+/// Boards requested per page (the Agile API caps it at 50).
+const BOARDS_PAGE_SIZE: u64 = 50;
+
+/// Comments requested per page.
+const COMMENTS_PAGE_SIZE: u64 = 20;
+
+/// Changelog entries requested per page (Jira Cloud caps it at 100).
+const HISTORY_PAGE_SIZE: u64 = 50;
 
 /// Handles side effects for actions that require async network calls.
 /// This function spawns tokio tasks to avoid blocking the UI render loop.
 pub fn handle_side_effects(
     action: &Action,
+    app: &App,
     get_boards_uc: Arc<GetBoardsUseCase>,
     get_backlog_uc: Arc<GetBacklogUseCase>,
     tx: UnboundedSender<Action>,
 ) {
     match action {
-        Action::LoadBoards => {
+        Action::LoadBoards | Action::ApplyBoardFilter => {
+            let filter = if matches!(action, Action::ApplyBoardFilter) {
+                app.board_filter_input()
+            } else {
+                app.board_filter.clone()
+            };
+
+            // Every page is sent as soon as it arrives, so large instances fill the list progressively
             tokio::spawn(async move {
-                match get_boards_uc.execute().await {
-                    Ok(boards) => {
-                        let _ = tx.send(Action::BoardsLoaded(boards));
+                let mut cursor = PageCursor::First;
+                loop {
+                    match get_boards_uc
+                        .execute(filter.clone(), cursor, BOARDS_PAGE_SIZE)
+                        .await
+                    {
+                        Ok(page) => {
+                            let next = page.next.clone();
+                            if tx.send(Action::BoardsLoaded(filter.clone(), page)).is_err() {
+                                break;
+                            }
+                            match next {
+                                Some(next) => cursor = next,
+                                None => break,
+                            }
+                        }
+                        Err(e) => {
+                            report_failure(
+                                &tx,
+                                "Failed to load boards",
+                                &e,
+                                Some(RetryableTask::LoadBoards),
+                            );
+                            break;
+                        }
                     }
-                    Err(e) => error!("Error loading boards: {}", e),
                 }
             });
         }
 
-        Action::LoadIssues(board_id) => {
-            let bid = *board_id;
+        Action::LoadIssues(_) | Action::SelectSprint(_) => {
+            let (bid, sprint_id) = match action {
+                // Reopening the same board keeps the sprint being shown
+                Action::LoadIssues(board_id) if app.current_board_id == Some(*board_id) => {
+                    (*board_id, app.current_sprint_id())
+                }
+                Action::LoadIssues(board_id) => (*board_id, None),
+                Action::SelectSprint(sprint_id) => match app.current_board_id {
+                    Some(board_id) => (board_id, *sprint_id),
+                    None => return,
+                },
+                _ => return,
+            };
+            let filter = app.issue_filter();
             tokio::spawn(async move {
-                let filter = IssueFilter::default_active_user();
-                match get_backlog_uc.execute(bid, 0, 20, filter).await {
+                match get_backlog_uc
+                    .execute(bid, sprint_id, PageCursor::First, 20, filter)
+                    .await
+                {
                     Ok(p) => {
-                        let _ = tx.send(Action::IssuesLoaded(p));
+                        let source = IssueSource::Board {
+                            board_id: bid,
+                            sprint_id,
+                        };
+                        let _ = tx.send(Action::IssuesLoaded(source, p));
                     }
-                    Err(e) => error!("Error loading issues: {}", e),
+                    Err(e) => report_failure(
+                        &tx,
+                        "Failed to load issues",
+                        &e,
+                        Some(RetryableTask::LoadIssues(bid)),
+                    ),
                 }
             });
         }
@@ -59,18 +135,27 @@ pub fn handle_filter_application(
     tx: UnboundedSender<Action>,
 ) {
     if let Some(board_id) = app.current_board_id {
-        let filter = IssueFilter::from_options(
-            app.filter_assignee.clone(),
-            app.filter_status.clone(),
-            app.filter_order_by.clone(),
-        );
+        let filter = app.issue_filter();
+        let sprint_id = app.current_sprint_id();
 
         tokio::spawn(async move {
-            match get_backlog_uc.execute(board_id, 0, 20, filter).await {
+            match get_backlog_uc
+                .execute(board_id, sprint_id, PageCursor::First, 20, filter)
+                .await
+            {
                 Ok(p) => {
-                    let _ = tx.send(Action::IssuesLoaded(p));
+                    let source = IssueSource::Board {
+                        board_id,
+                        sprint_id,
+                    };
+                    let _ = tx.send(Action::IssuesLoaded(source, p));
                 }
-                Err(e) => error!("Error applying filter: {}", e),
+                Err(e) => report_failure(
+                    &tx,
+                    "Failed to apply filter",
+                    &e,
+                    Some(RetryableTask::LoadIssues(board_id)),
+                ),
             }
         });
     }
@@ -79,37 +164,430 @@ pub fn handle_filter_application(
 /// Checks if infinite scroll should be triggered and loads more issues if needed.
 ///
 /// This is called after state updates to check if the user has scrolled near
-/// the bottom of the list and there are more items to load.
+/// the bottom of the list and there are more items to load. The page is marked as on its
+/// way at once, so the following keys do not fetch it again.
 pub fn check_infinite_scroll(
+    app: &mut App,
+    get_backlog_uc: Arc<GetBacklogUseCase>,
+    search_issues_uc: Arc<SearchIssuesUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if app.needs_more_issues() {
+        handle_load_more_issues(app, get_backlog_uc, search_issues_uc, tx);
+        app.update(Action::LoadMoreIssues);
+    }
+}
+
+/// Loads the next page of the current backlog or JQL search, if there is one and no other
+/// page is on its way.
+pub fn handle_load_more_issues(
     app: &App,
     get_backlog_uc: Arc<GetBacklogUseCase>,
+    search_issues_uc: Arc<SearchIssuesUseCase>,
     tx: UnboundedSender<Action>,
 ) {
-    if app.current_screen == CurrentScreen::Backlog
-        && !app.is_loading
-        && app.issues.len() < app.total_issues as usize
-        && app.selected_issue_index >= app.issues.len().saturating_sub(2)
-        && let Some(board_id) = app.current_board_id
-    {
-        let start_at = app.issues.len() as u64;
+    if app.loading_more_issues {
+        return;
+    }
+    let (Some(cursor), Some(source)) = (app.next_issues_page.clone(), app.issue_source()) else {
+        return;
+    };
 
-        let filter = IssueFilter::from_options(
-            app.filter_assignee.clone(),
-            app.filter_status.clone(),
-            app.filter_order_by.clone(),
-        );
+    if let IssueSource::Search(jql) = source {
+        tokio::spawn(async move {
+            match search_issues_uc.execute(&jql, cursor, 20).await {
+                Ok(p) => {
+                    let _ = tx.send(Action::IssuesLoaded(IssueSource::Search(jql), p));
+                }
+                Err(e) => report_failure(
+                    &tx,
+                    "Failed to load more issues",
+                    &e,
+                    Some(RetryableTask::LoadMoreIssues),
+                ),
+            }
+        });
+    } else if let IssueSource::Board {
+        board_id,
+        sprint_id,
+    } = source
+    {
+        let filter = app.issue_filter();
 
         tokio::spawn(async move {
-            match get_backlog_uc.execute(board_id, start_at, 20, filter).await {
+            match get_backlog_uc
+                .execute(board_id, sprint_id, cursor, 20, filter)
+                .await
+            {
                 Ok(p) => {
-                    let _ = tx.send(Action::IssuesLoaded(p));
+                    let _ = tx.send(Action::IssuesLoaded(source, p));
+                }
+                Err(e) => report_failure(
+                    &tx,
+                    "Failed to load more issues",
+                    &e,
+                    Some(RetryableTask::LoadMoreIssues),
+                ),
+            }
+        });
+    }
+}
+
+/// Loads the column layout of a board when the Kanban view needs it and it is not cached.
+pub fn handle_kanban_configuration(
+    action: &Action,
+    app: &App,
+    get_board_configuration_uc: Arc<GetBoardConfigurationUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let board_id = match action {
+        Action::ToggleKanban if app.current_screen == CurrentScreen::Backlog => {
+            app.current_board_id
+        }
+        Action::LoadIssues(board_id) if app.kanban_view => Some(*board_id),
+        _ => None,
+    };
+    let Some(board_id) = board_id else {
+        return;
+    };
+    if app
+        .board_configuration
+        .as_ref()
+        .is_some_and(|configuration| configuration.board_id == board_id)
+    {
+        return;
+    }
+
+    handle_load_board_configuration(board_id, get_board_configuration_uc, tx);
+}
+
+/// Handles loading the columns of a board
+pub fn handle_load_board_configuration(
+    board_id: BoardId,
+    get_board_configuration_uc: Arc<GetBoardConfigurationUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_board_configuration_uc.execute(board_id).await {
+            Ok(configuration) => {
+                let _ = tx.send(Action::BoardConfigurationLoaded(configuration));
+            }
+            Err(e) => report_failure(
+                &tx,
+                "Failed to load board columns",
+                &e,
+                Some(RetryableTask::LoadBoardConfiguration(board_id)),
+            ),
+        }
+    });
+}
+
+/// Handles moving the selected card to another Kanban column.
+///
+/// Jira has no "move to column" operation: the issue is transitioned to a status mapped to
+/// the target column, using the first available transition that leads there.
+pub fn handle_move_issue_to_column(
+    column: usize,
+    app: &App,
+    get_transitions_uc: Arc<GetTransitionsUseCase>,
+    transition_issue_uc: Arc<TransitionIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let (Some(issue), Some(target)) = (
+        app.get_selected_issue(),
+        app.board_configuration
+            .as_ref()
+            .and_then(|configuration| configuration.columns.get(column)),
+    ) else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+    let target = target.clone();
+
+    tokio::spawn(async move {
+        let transitions = match get_transitions_uc.execute(&issue_key).await {
+            Ok(transitions) => transitions,
+            Err(e) => return report_failure(&tx, "Failed to load transitions", &e, None),
+        };
+        let Some(transition) = transitions.into_iter().find(|t| {
+            t.to_status_id
+                .as_ref()
+                .is_some_and(|id| target.status_ids.contains(id))
+        }) else {
+            let e = AppError::Validation {
+                messages: vec![format!("No transition leads to column '{}'", target.name)],
+                field_errors: Default::default(),
+            };
+            return report_failure(&tx, &format!("Cannot move {}", issue_key), &e, None);
+        };
+
+        match transition_issue_uc
+            .execute(&issue_key, &transition.id)
+            .await
+        {
+            Ok(_) => {
+                let _ = tx.send(Action::IssueTransitioned(issue_key.clone(), transition));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("{} moved to '{}'", issue_key, target.name),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to transition issue", &e, None),
+        }
+    });
+}
+
+/// Handles loading the sprints of the current board for the sprint picker
+pub fn handle_load_sprints(
+    app: &App,
+    get_sprints_uc: Arc<GetSprintsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if let Some(board_id) = app.current_board_id {
+        tokio::spawn(async move {
+            match get_sprints_uc.execute(board_id).await {
+                Ok(sprints) => {
+                    let _ = tx.send(Action::SprintsLoaded(board_id, sprints));
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::CloseSprintPicker);
+                    report_failure(
+                        &tx,
+                        "Failed to load sprints",
+                        &e,
+                        Some(RetryableTask::LoadSprints(board_id)),
+                    );
                 }
-                Err(e) => error!("Pagination error: {}", e),
             }
         });
     }
 }
 
+/// Handles moving the selected issue to a sprint, or to the backlog for `None`
+pub fn handle_move_issue_to_sprint(
+    sprint_id: Option<SprintId>,
+    app: &App,
+    move_issue_to_sprint_uc: Arc<MoveIssueToSprintUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+    let destination = match sprint_id.and_then(|id| app.sprints.iter().find(|s| s.id == id)) {
+        Some(sprint) => format!("'{}'", sprint.name),
+        None => "the backlog".to_string(),
+    };
+
+    tokio::spawn(async move {
+        match move_issue_to_sprint_uc.execute(&issue_key, sprint_id).await {
+            Ok(_) => {
+                let _ = tx.send(Action::IssueSprintChanged(issue_key.clone(), sprint_id));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("{} moved to {}", issue_key, destination),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to move issue", &e, None),
+        }
+    });
+}
+
+/// Handles entering edit mode in the issue detail by loading the editable fields
+pub fn handle_start_issue_edit(
+    app: &App,
+    get_edit_fields_uc: Arc<GetEditFieldsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+
+    tokio::spawn(async move {
+        match get_edit_fields_uc.execute(&issue_key).await {
+            Ok(fields) => {
+                let _ = tx.send(Action::EditFieldsLoaded(issue_key, fields));
+            }
+            Err(e) => {
+                let _ = tx.send(Action::CancelIssueEdit);
+                report_failure(
+                    &tx,
+                    "Failed to load editable fields",
+                    &e,
+                    Some(RetryableTask::LoadEditFields),
+                );
+            }
+        }
+    });
+}
+
+/// Handles saving the issue edit form.
+///
+/// Only the changed fields are sent. The list shows them right away, so the issue as it
+/// was is kept to put those fields back if Jira rejects the update.
+pub fn handle_submit_issue_edit(
+    app: &App,
+    update_issue_uc: Arc<UpdateIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(original) = app.get_selected_issue().cloned() else {
+        return;
+    };
+    let changes = match app.issue_changes() {
+        Ok(changes) => changes,
+        Err(e) => return report_failure(&tx, "Cannot save issue", &e, None),
+    };
+    if changes.is_empty() {
+        return;
+    }
+    let field_ids: Vec<String> = changes.iter().map(|(meta, _)| meta.id.clone()).collect();
+
+    tokio::spawn(async move {
+        match update_issue_uc.execute(&original.key, changes).await {
+            Ok(_) => {
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("{} updated", original.key),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => {
+                let context = format!("Failed to update {}", original.key);
+                let _ = tx.send(Action::IssueUpdateFailed(original, field_ids));
+                report_failure(&tx, &context, &e, None);
+            }
+        }
+    });
+}
+
+/// Handles opening the create form: loads the project issue types, then the fields of the
+/// preselected type.
+pub fn handle_open_create_issue(
+    app: &App,
+    get_create_issue_types_uc: Arc<GetCreateIssueTypesUseCase>,
+    get_create_fields_uc: Arc<GetCreateFieldsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(project_key) = app.create_issue_project() else {
+        return;
+    };
+
+    tokio::spawn(async move {
+        let issue_types = match get_create_issue_types_uc.execute(&project_key).await {
+            Ok(issue_types) => issue_types,
+            Err(e) => {
+                let _ = tx.send(Action::CloseCreateIssue);
+                return report_failure(
+                    &tx,
+                    "Failed to load issue types",
+                    &e,
+                    Some(RetryableTask::LoadCreateIssue),
+                );
+            }
+        };
+        let Some(issue_type) = issue_types
+            .get(default_issue_type_index(&issue_types))
+            .cloned()
+        else {
+            let _ = tx.send(Action::CloseCreateIssue);
+            let e = AppError::Validation {
+                messages: vec![format!("No issue type can be created in {}", project_key)],
+                field_errors: Default::default(),
+            };
+            return report_failure(&tx, "Cannot create issue", &e, None);
+        };
+        let _ = tx.send(Action::CreateIssueTypesLoaded(
+            project_key.clone(),
+            issue_types,
+        ));
+
+        load_create_fields(&project_key, &issue_type.id, &get_create_fields_uc, &tx).await;
+    });
+}
+
+/// Handles switching the issue type of the create form
+pub fn handle_load_create_fields(
+    issue_type_id: &str,
+    app: &App,
+    get_create_fields_uc: Arc<GetCreateFieldsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(project_key) = app.create_project_key.clone() else {
+        return;
+    };
+    let issue_type_id = issue_type_id.to_string();
+
+    tokio::spawn(async move {
+        load_create_fields(&project_key, &issue_type_id, &get_create_fields_uc, &tx).await;
+    });
+}
+
+async fn load_create_fields(
+    project_key: &str,
+    issue_type_id: &str,
+    get_create_fields_uc: &GetCreateFieldsUseCase,
+    tx: &UnboundedSender<Action>,
+) {
+    match get_create_fields_uc
+        .execute(project_key, issue_type_id)
+        .await
+    {
+        Ok(fields) => {
+            let _ = tx.send(Action::CreateFieldsLoaded(
+                issue_type_id.to_string(),
+                fields,
+            ));
+        }
+        Err(e) => report_failure(tx, "Failed to load the create form", &e, None),
+    }
+}
+
+/// Handles submitting the create form, after checking the required fields locally
+pub fn handle_create_issue(
+    app: &App,
+    create_issue_uc: Arc<CreateIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let new_issue = match app.new_issue() {
+        Ok(new_issue) => new_issue,
+        Err(e) => return report_failure(&tx, "Cannot create issue", &e, None),
+    };
+
+    tokio::spawn(async move {
+        match create_issue_uc.execute(new_issue).await {
+            Ok(issue) => {
+                let key = issue.key.clone();
+                let _ = tx.send(Action::IssueCreated(issue));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("{} created", key),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to create issue", &e, None),
+        }
+    });
+}
+
 /// Handles worklog submission by creating a Worklog and sending it to Jira.
 pub fn handle_worklog_submission(
     app: &App,
@@ -170,19 +648,7 @@ pub fn handle_worklog_submission(
                     });
                 }
                 Err(e) => {
-                    let _ = tx.send(Action::ShowNotification(
-                        "❌ Error".to_string(),
-                        format!("Failed to log time: {}", e),
-                        false,
-                    ));
-                    error!("Error adding worklog: {}", e);
-
-                    // Auto-dismiss error notification after 5 seconds
-                    let tx_dismiss = tx_clone.clone();
-                    tokio::spawn(async move {
-                        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                        let _ = tx_dismiss.send(Action::HideNotification);
-                    });
+                    report_failure(&tx, "Failed to log time", &e, None);
                 }
             }
         });
@@ -202,12 +668,12 @@ pub fn handle_load_worklogs(
                 let _ = tx.send(Action::WorklogsLoaded(paginated));
             }
             Err(e) => {
-                let _ = tx.send(Action::ShowNotification(
-                    "❌ Error".to_string(),
-                    format!("Failed to load time entries: {}", e),
-                    false,
-                ));
-                error!("Error loading worklogs: {}", e);
+                report_failure(
+                    &tx,
+                    "Failed to load time entries",
+                    &e,
+                    Some(RetryableTask::LoadWorklogs(issue_key)),
+                );
             }
         }
     });
@@ -279,7 +745,12 @@ pub fn handle_update_worklog(
                         Ok(paginated) => {
                             let _ = tx.send(Action::WorklogsLoaded(paginated));
                         }
-                        Err(e) => error!("Error reloading worklogs: {}", e),
+                        Err(e) => report_failure(
+                            &tx,
+                            "Failed to reload time entries",
+                            &e,
+                            Some(RetryableTask::LoadWorklogs(issue_key)),
+                        ),
                     }
 
                     // Auto-dismiss notification after 3 seconds
@@ -290,19 +761,7 @@ pub fn handle_update_worklog(
                     });
                 }
                 Err(e) => {
-                    let _ = tx.send(Action::ShowNotification(
-                        "❌ Error".to_string(),
-                        format!("Failed to update time entry: {}", e),
-                        false,
-                    ));
-                    error!("Error updating worklog: {}", e);
-
-                    // Auto-dismiss error notification after 5 seconds
-                    let tx_dismiss = tx_clone.clone();
-                    tokio::spawn(async move {
-                        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                        let _ = tx_dismiss.send(Action::HideNotification);
-                    });
+                    report_failure(&tx, "Failed to update time entry", &e, None);
                 }
             }
         });
@@ -336,7 +795,12 @@ pub fn handle_delete_worklog(
                         Ok(paginated) => {
                             let _ = tx.send(Action::WorklogsLoaded(paginated));
                         }
-                        Err(e) => error!("Error reloading worklogs: {}", e),
+                        Err(e) => report_failure(
+                            &tx,
+                            "Failed to reload time entries",
+                            &e,
+                            Some(RetryableTask::LoadWorklogs(issue_key)),
+                        ),
                     }
 
                     // Auto-dismiss notification after 3 seconds
@@ -347,21 +811,1045 @@ pub fn handle_delete_worklog(
                     });
                 }
                 Err(e) => {
-                    let _ = tx.send(Action::ShowNotification(
-                        "❌ Error".to_string(),
-                        format!("Failed to delete time entry: {}", e),
-                        false,
-                    ));
-                    error!("Error deleting worklog: {}", e);
-
-                    // Auto-dismiss error notification after 5 seconds
-                    let tx_dismiss = tx_clone.clone();
-                    tokio::spawn(async move {
-                        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                        let _ = tx_dismiss.send(Action::HideNotification);
-                    });
+                    report_failure(&tx, "Failed to delete time entry", &e, None);
                 }
             }
         });
     }
 }
+
+/// Loads the comments of the selected issue when the comments tab shows them for the first
+/// time, or the next page when the end of the list is reached.
+pub fn handle_load_comments(
+    action: &Action,
+    app: &App,
+    get_comments_uc: Arc<GetCommentsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let start_at = match action {
+        Action::ShowDetailTab(DetailTab::Comments) if app.comments_need_loading() => 0,
+        Action::LoadMoreComments => app.comments.len() as u64,
+        _ => return,
+    };
+    load_comments(issue.key.clone(), start_at, get_comments_uc, tx);
+}
+
+/// Loads a page of the comments of an issue.
+pub fn load_comments(
+    issue_key: String,
+    start_at: u64,
+    get_comments_uc: Arc<GetCommentsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_comments_uc
+            .execute(&issue_key, start_at, COMMENTS_PAGE_SIZE)
+            .await
+        {
+            Ok(page) => {
+                let _ = tx.send(Action::CommentsLoaded(issue_key, page));
+            }
+            Err(e) => report_failure(
+                &tx,
+                "Failed to load comments",
+                &e,
+                Some(RetryableTask::LoadComments(issue_key)),
+            ),
+        }
+    });
+}
+
+/// Loads the history of the selected issue when the history tab shows it for the first time,
+/// or the next page when the end of the list is reached.
+pub fn handle_load_history(
+    action: &Action,
+    app: &App,
+    get_changelog_uc: Arc<GetChangelogUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let start_at = match action {
+        Action::ShowDetailTab(DetailTab::History) if app.history_needs_loading() => 0,
+        Action::LoadMoreHistory => app.history.len() as u64,
+        _ => return,
+    };
+    load_history(issue.key.clone(), start_at, get_changelog_uc, tx);
+}
+
+/// Loads a page of the history of an issue.
+pub fn load_history(
+    issue_key: String,
+    start_at: u64,
+    get_changelog_uc: Arc<GetChangelogUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_changelog_uc
+            .execute(&issue_key, start_at, HISTORY_PAGE_SIZE)
+            .await
+        {
+            Ok(page) => {
+                let _ = tx.send(Action::HistoryLoaded(issue_key, page));
+            }
+            Err(e) => report_failure(
+                &tx,
+                "Failed to load history",
+                &e,
+                Some(RetryableTask::LoadHistory(issue_key)),
+            ),
+        }
+    });
+}
+
+/// Handles saving the comment composer: a new comment, or the edit of an existing one.
+pub fn handle_submit_comment(
+    app: &App,
+    add_comment_uc: Arc<AddCommentUseCase>,
+    update_comment_uc: Arc<UpdateCommentUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let body = match app.comment_body() {
+        Ok(body) => body.to_string(),
+        Err(e) => return report_failure(&tx, "Cannot save comment", &e, None),
+    };
+    let issue_key = issue.key.clone();
+    let comment_id = app.comment_being_edited.as_ref().map(|c| c.id.clone());
+
+    tokio::spawn(async move {
+        let (result, message) = match &comment_id {
+            Some(id) => (
+                update_comment_uc
+                    .execute(&issue_key, id, &body)
+                    .await
+                    .map(Action::CommentUpdated),
+                "Comment updated",
+            ),
+            None => (
+                add_comment_uc
+                    .execute(&issue_key, &body)
+                    .await
+                    .map(Action::CommentAdded),
+                "Comment added",
+            ),
+        };
+
+        match result {
+            Ok(action) => {
+                let _ = tx.send(action);
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("{} on {}", message, issue_key),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to save comment", &e, None),
+        }
+    });
+}
+
+/// Handles deleting the selected comment
+pub fn handle_delete_comment(
+    app: &App,
+    delete_comment_uc: Arc<DeleteCommentUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let (Some(issue), Some(comment)) = (app.get_selected_issue(), app.get_selected_comment())
+    else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+    let comment_id = comment.id.clone();
+
+    tokio::spawn(async move {
+        match delete_comment_uc.execute(&issue_key, &comment_id).await {
+            Ok(_) => {
+                let _ = tx.send(Action::CommentDeleted(comment_id));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    "Comment deleted".to_string(),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to delete comment", &e, None),
+        }
+    });
+}
+
+/// Looks up the users matching the mention being typed in the comment composer.
+///
+/// Called after the input has been updated. Failures are only logged: the suggestions are a
+/// convenience and typing goes on.
+pub fn handle_mention_search(
+    app: &App,
+    search_users_uc: Arc<SearchUsersUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(query) = mention_query(&app.comment_input).filter(|q| !q.is_empty()) else {
+        return;
+    };
+    let query = query.to_string();
+
+    tokio::spawn(async move {
+        match search_users_uc.execute(&query).await {
+            Ok(users) => {
+                let _ = tx.send(Action::MentionSuggestionsLoaded(query, users));
+            }
+            Err(e) => error!("Failed to search users for '{}': {}", query, e),
+        }
+    });
+}
+
+/// Searches the users of the user picker for the query typed so far.
+///
+/// Called after the query has been updated. Assignable users are listed even before a name
+/// is typed; the assignee filter searches all users and needs one.
+pub fn handle_user_search(
+    app: &App,
+    search_assignable_users_uc: Arc<SearchAssignableUsersUseCase>,
+    search_users_uc: Arc<SearchUsersUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if app.current_screen != CurrentScreen::UserPicker {
+        return;
+    }
+    let query = app.user_query.clone();
+    let issue_key = match app.user_picker_mode {
+        UserPickerMode::AssignIssue => match app.get_selected_issue() {
+            Some(issue) => Some(issue.key.clone()),
+            None => return,
+        },
+        UserPickerMode::FilterAssignee if query.is_empty() => return,
+        UserPickerMode::FilterAssignee => None,
+    };
+
+    tokio::spawn(async move {
+        let result = match &issue_key {
+            Some(issue_key) => search_assignable_users_uc.execute(issue_key, &query).await,
+            None => search_users_uc.execute(&query).await,
+        };
+        match result {
+            Ok(users) => {
+                let _ = tx.send(Action::UsersLoaded(query, users));
+            }
+            Err(e) => report_failure(&tx, "Failed to search users", &e, None),
+        }
+    });
+}
+
+/// Handles assigning the selected issue to the user chosen in the picker
+pub fn handle_assign_issue(
+    user: Option<User>,
+    app: &App,
+    assign_issue_uc: Arc<AssignIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+    tokio::spawn(assign_issue(issue_key, user, assign_issue_uc, tx));
+}
+
+/// Handles assigning the selected issue to the authenticated user, looking them up once
+pub fn handle_assign_to_me(
+    app: &App,
+    get_current_user_uc: Arc<GetCurrentUserUseCase>,
+    assign_issue_uc: Arc<AssignIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+    let current_user = app.current_user.clone();
+
+    tokio::spawn(async move {
+        let user = match current_user {
+            Some(user) => user,
+            None => match get_current_user_uc.execute().await {
+                Ok(user) => {
+                    let _ = tx.send(Action::CurrentUserLoaded(user.clone()));
+                    user
+                }
+                Err(e) => return report_failure(&tx, "Failed to get the current user", &e, None),
+            },
+        };
+        assign_issue(issue_key, Some(user), assign_issue_uc, tx).await;
+    });
+}
+
+async fn assign_issue(
+    issue_key: String,
+    user: Option<User>,
+    assign_issue_uc: Arc<AssignIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let account_id = user.as_ref().map(|u| u.account_id.as_str());
+    match assign_issue_uc.execute(&issue_key, account_id).await {
+        Ok(_) => {
+            let message = match &user {
+                Some(user) => format!("{} assigned to {}", issue_key, user.display_name),
+                None => format!("{} unassigned", issue_key),
+            };
+            let _ = tx.send(Action::IssueAssigned(issue_key, user));
+            let _ = tx.send(Action::ShowNotification(
+                "✅ Success".to_string(),
+                message,
+                true,
+            ));
+
+            // Auto-dismiss notification after 3 seconds
+            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+            let _ = tx.send(Action::HideNotification);
+        }
+        Err(e) => {
+            let context = format!("Failed to assign {}", issue_key);
+            report_failure(&tx, &context, &e, None);
+        }
+    }
+}
+
+/// Loads the metadata of the configured custom fields, used to show and filter by them
+pub fn load_custom_fields(
+    get_custom_fields_uc: Arc<GetCustomFieldsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_custom_fields_uc.execute().await {
+            Ok(fields) => {
+                let _ = tx.send(Action::CustomFieldsLoaded(fields));
+            }
+            Err(e) => report_failure(
+                &tx,
+                "Failed to load custom fields",
+                &e,
+                Some(RetryableTask::LoadCustomFields),
+            ),
+        }
+    });
+}
+
+/// Loads the watchers of the issue shown by the detail view, when it is opened
+pub fn handle_load_watchers(
+    app: &App,
+    get_watchers_uc: Arc<GetWatchersUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if let Some(issue) = app.get_selected_issue() {
+        load_watchers(issue.key.clone(), get_watchers_uc, tx);
+    }
+}
+
+pub fn load_watchers(
+    issue_key: String,
+    get_watchers_uc: Arc<GetWatchersUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_watchers_uc.execute(&issue_key).await {
+            Ok(watchers) => {
+                let _ = tx.send(Action::WatchersLoaded(issue_key, watchers));
+            }
+            Err(e) => report_failure(
+                &tx,
+                "Failed to load watchers",
+                &e,
+                Some(RetryableTask::LoadWatchers(issue_key)),
+            ),
+        }
+    });
+}
+
+/// Handles watching or unwatching the selected issue as the current user
+pub fn handle_toggle_watch(
+    app: &App,
+    get_current_user_uc: Arc<GetCurrentUserUseCase>,
+    watch_issue_uc: Arc<WatchIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+    let watch = !issue.watchers.is_watching;
+    let current_user = app.current_user.clone();
+
+    tokio::spawn(async move {
+        let user = match current_user {
+            Some(user) => user,
+            None => match get_current_user_uc.execute().await {
+                Ok(user) => {
+                    let _ = tx.send(Action::CurrentUserLoaded(user.clone()));
+                    user
+                }
+                Err(e) => return report_failure(&tx, "Failed to get the current user", &e, None),
+            },
+        };
+        match watch_issue_uc
+            .execute(&issue_key, &user.account_id, watch)
+            .await
+        {
+            Ok(watchers) => {
+                let message = if watch {
+                    format!("Watching {}", issue_key)
+                } else {
+                    format!("Stopped watching {}", issue_key)
+                };
+                let _ = tx.send(Action::WatchersLoaded(issue_key, watchers));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    message,
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => {
+                let context = format!("Failed to update watchers of {}", issue_key);
+                report_failure(&tx, &context, &e, None);
+            }
+        }
+    });
+}
+
+/// Handles voting for the selected issue, or withdrawing the vote
+pub fn handle_toggle_vote(
+    app: &App,
+    vote_issue_uc: Arc<VoteIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(issue) = app.get_selected_issue() else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+    let vote = !issue.votes.has_voted;
+
+    tokio::spawn(async move {
+        match vote_issue_uc.execute(&issue_key, vote).await {
+            Ok(votes) => {
+                let message = if vote {
+                    format!("Voted for {}", issue_key)
+                } else {
+                    format!("Vote for {} withdrawn", issue_key)
+                };
+                let _ = tx.send(Action::VotesLoaded(issue_key, votes));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    message,
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => {
+                let context = format!("Failed to vote for {}", issue_key);
+                report_failure(&tx, &context, &e, None);
+            }
+        }
+    });
+}
+
+/// Handles opening the issue at the other end of the selected link
+pub fn handle_open_linked_issue(
+    app: &App,
+    get_issue_uc: Arc<GetIssueUseCase>,
+    get_watchers_uc: Arc<GetWatchersUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(link) = app.get_selected_link() else {
+        return;
+    };
+    load_linked_issue(link.issue_key.clone(), get_issue_uc, get_watchers_uc, tx);
+}
+
+/// Loads a linked issue, then its watchers once it is shown.
+pub fn load_linked_issue(
+    issue_key: String,
+    get_issue_uc: Arc<GetIssueUseCase>,
+    get_watchers_uc: Arc<GetWatchersUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_issue_uc.execute(&issue_key).await {
+            Ok(issue) => {
+                let _ = tx.send(Action::LinkedIssueLoaded(issue));
+                load_watchers(issue_key, get_watchers_uc, tx);
+            }
+            Err(e) => report_failure(
+                &tx,
+                &format!("Failed to open {}", issue_key),
+                &e,
+                Some(RetryableTask::LoadLinkedIssue(issue_key)),
+            ),
+        }
+    });
+}
+
+/// Handles opening the link picker, loading the link types the first time
+pub fn handle_open_link_picker(
+    app: &App,
+    get_link_types_uc: Arc<GetIssueLinkTypesUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if app.link_types.is_empty() && app.get_selected_issue().is_some() {
+        load_link_types(get_link_types_uc, tx);
+    }
+}
+
+pub fn load_link_types(
+    get_link_types_uc: Arc<GetIssueLinkTypesUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_link_types_uc.execute().await {
+            Ok(link_types) => {
+                let _ = tx.send(Action::LinkTypesLoaded(link_types));
+            }
+            Err(e) => report_failure(
+                &tx,
+                "Failed to load link types",
+                &e,
+                Some(RetryableTask::LoadLinkTypes),
+            ),
+        }
+    });
+}
+
+/// Handles linking the selected issue to the key typed in the link picker
+pub fn handle_link_issues(
+    app: &App,
+    link_issues_uc: Arc<LinkIssuesUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let (Some(issue), Some((link_type, direction))) =
+        (app.get_selected_issue(), app.get_selected_link_relation())
+    else {
+        return;
+    };
+    let other_key = app.link_target_input.trim().to_string();
+    if other_key.is_empty() {
+        return;
+    }
+    let issue_key = issue.key.clone();
+    let link_type = link_type.clone();
+
+    tokio::spawn(async move {
+        match link_issues_uc
+            .execute(&issue_key, &link_type, direction, &other_key)
+            .await
+        {
+            Ok(issue) => {
+                let _ = tx.send(Action::IssueLinked(issue));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!(
+                        "{} {} {}",
+                        issue_key,
+                        link_type.description(direction),
+                        other_key
+                    ),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to link issues", &e, None),
+        }
+    });
+}
+
+/// Handles removing the selected link of the issue detail
+pub fn handle_delete_issue_link(
+    app: &App,
+    delete_issue_link_uc: Arc<DeleteIssueLinkUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let Some(link) = app.get_selected_link() else {
+        return;
+    };
+    let link_id = link.id.clone();
+    let other_key = link.issue_key.clone();
+
+    tokio::spawn(async move {
+        match delete_issue_link_uc.execute(&link_id).await {
+            Ok(_) => {
+                let _ = tx.send(Action::IssueLinkDeleted(link_id));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("Link to {} removed", other_key),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to delete link", &e, None),
+        }
+    });
+}
+
+/// Handles downloading the selected attachment, reporting its progress
+pub fn handle_download_attachment(
+    app: &App,
+    download_attachment_uc: Arc<DownloadAttachmentUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if app.attachment_download.is_some() {
+        return;
+    }
+    let Some(attachment) = app.get_selected_attachment().cloned() else {
+        return;
+    };
+
+    tokio::spawn(async move {
+        // Progress is sent once per percent, not for every chunk
+        let last_percent = AtomicU64::new(0);
+        let progress_tx = tx.clone();
+        let on_progress = |received: u64| {
+            let percent = received * 100 / attachment.size.max(1);
+            if percent != last_percent.swap(percent, Ordering::Relaxed) {
+                let _ = progress_tx.send(Action::AttachmentDownloadProgress(
+                    attachment.id.clone(),
+                    received,
+                ));
+            }
+        };
+        let result = download_attachment_uc
+            .execute(&attachment, on_progress)
+            .await;
+        let _ = tx.send(Action::AttachmentDownloadEnded(attachment.id.clone()));
+
+        match result {
+            Ok(path) => {
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("Saved to {}", path.display()),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(
+                &tx,
+                &format!("Failed to download {}", attachment.filename),
+                &e,
+                None,
+            ),
+        }
+    });
+}
+
+/// Handles attaching the file typed in the upload popup to the selected issue
+pub fn handle_upload_attachment(
+    app: &App,
+    upload_attachment_uc: Arc<UploadAttachmentUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let (Some(issue), Ok(path)) = (app.get_selected_issue(), app.attachment_path()) else {
+        return;
+    };
+    let issue_key = issue.key.clone();
+
+    tokio::spawn(async move {
+        match upload_attachment_uc.execute(&issue_key, &path).await {
+            Ok(attachments) => {
+                let names: Vec<String> = attachments.iter().map(|a| a.filename.clone()).collect();
+                let _ = tx.send(Action::AttachmentsAdded(issue_key.clone(), attachments));
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("{} attached to {}", names.join(", "), issue_key),
+                    true,
+                ));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => report_failure(&tx, "Failed to upload attachment", &e, None),
+        }
+    });
+}
+
+/// Handles loading the available transitions for the selected issue
+pub fn handle_load_transitions(
+    app: &App,
+    get_transitions_uc: Arc<GetTransitionsUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if let Some(issue) = app.get_selected_issue() {
+        let issue_key = issue.key.clone();
+        tokio::spawn(async move {
+            match get_transitions_uc.execute(&issue_key).await {
+                Ok(transitions) => {
+                    let _ = tx.send(Action::TransitionsLoaded(transitions));
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::CloseTransitionModal);
+                    report_failure(
+                        &tx,
+                        "Failed to load transitions",
+                        &e,
+                        Some(RetryableTask::LoadTransitions),
+                    );
+                }
+            }
+        });
+    }
+}
+
+/// Handles applying the selected transition to the selected issue
+pub fn handle_apply_transition(
+    app: &App,
+    transition_issue_uc: Arc<TransitionIssueUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if let (Some(issue), Some(transition)) =
+        (app.get_selected_issue(), app.get_selected_transition())
+    {
+        let issue_key = issue.key.clone();
+        let transition = transition.clone();
+
+        tokio::spawn(async move {
+            match transition_issue_uc
+                .execute(&issue_key, &transition.id)
+                .await
+            {
+                Ok(_) => {
+                    let _ = tx.send(Action::ShowNotification(
+                        "✅ Success".to_string(),
+                        format!("{} moved via '{}'", issue_key, transition.name),
+                        true,
+                    ));
+                    let _ = tx.send(Action::IssueTransitioned(issue_key, transition));
+
+                    // Auto-dismiss notification after 3 seconds
+                    tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                    let _ = tx.send(Action::HideNotification);
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::CloseTransitionModal);
+                    report_failure(&tx, "Failed to transition issue", &e, None);
+                }
+            }
+        });
+    }
+}
+
+/// Handles running a JQL query from the search screen
+pub fn handle_jql_search(
+    jql: &str,
+    search_issues_uc: Arc<SearchIssuesUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let jql = jql.trim().to_string();
+    if jql.is_empty() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        match search_issues_uc.execute(&jql, PageCursor::First, 20).await {
+            Ok(p) => {
+                let _ = tx.send(Action::IssuesLoaded(IssueSource::Search(jql), p));
+            }
+            Err(e) => {
+                let _ = tx.send(Action::SearchFailed);
+                report_failure(&tx, "Search failed", &e, Some(RetryableTask::Search(jql)));
+            }
+        }
+    });
+}
+
+/// Handles loading local saved queries and Jira filters
+pub fn handle_load_saved_queries(
+    get_saved_queries_uc: Arc<GetSavedQueriesUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match get_saved_queries_uc.execute().await {
+            Ok(queries) => {
+                let _ = tx.send(Action::SavedQueriesLoaded(queries));
+            }
+            Err(e) => {
+                report_failure(
+                    &tx,
+                    "Failed to load saved queries",
+                    &e,
+                    Some(RetryableTask::LoadSavedQueries),
+                );
+            }
+        }
+    });
+}
+
+/// Handles saving the current JQL under the typed name
+pub fn handle_save_query(
+    app: &App,
+    save_query_uc: Arc<SaveQueryUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    let name = app.jql_name_input.trim().to_string();
+    let jql = app.jql_input.trim().to_string();
+    if name.is_empty() || jql.is_empty() {
+        return;
+    }
+
+    let query = SavedQuery {
+        name,
+        jql,
+        source: SavedQuerySource::Local,
+    };
+
+    tokio::spawn(async move {
+        match save_query_uc.execute(query.clone()).await {
+            Ok(_) => {
+                let _ = tx.send(Action::ShowNotification(
+                    "✅ Success".to_string(),
+                    format!("Query '{}' saved", query.name),
+                    true,
+                ));
+                let _ = tx.send(Action::QuerySaved(query));
+
+                // Auto-dismiss notification after 3 seconds
+                tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+                let _ = tx.send(Action::HideNotification);
+            }
+            Err(e) => {
+                report_failure(&tx, "Failed to save query", &e, None);
+            }
+        }
+    });
+}
+
+/// Handles deleting the selected local saved query. Jira filters are left untouched.
+pub fn handle_delete_saved_query(
+    app: &App,
+    delete_saved_query_uc: Arc<DeleteSavedQueryUseCase>,
+    tx: UnboundedSender<Action>,
+) {
+    if let Some(query) = app.get_selected_saved_query() {
+        if query.source != SavedQuerySource::Local {
+            let _ = tx.send(Action::ShowNotification(
+                "❌ Error".to_string(),
+                "Jira filters can only be deleted from Jira".to_string(),
+                false,
+            ));
+            let tx_dismiss = tx.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+                let _ = tx_dismiss.send(Action::HideNotification);
+            });
+            return;
+        }
+
+        let name = query.name.clone();
+        tokio::spawn(async move {
+            match delete_saved_query_uc.execute(&name).await {
+                Ok(_) => {
+                    let _ = tx.send(Action::SavedQueryDeleted(name));
+                }
+                Err(e) => {
+                    report_failure(&tx, "Failed to delete query", &e, None);
+                }
+            }
+        });
+    }
+}
+
+/// Reports a failed background task over the action channel.
+///
+/// `retry` is only offered when trying again may help; otherwise the notification
+/// is dismissed automatically after 5 seconds.
+fn report_failure(
+    tx: &UnboundedSender<Action>,
+    context: &str,
+    e: &AppError,
+    retry: Option<RetryableTask>,
+) {
+    error!("{}: {}", context, e);
+    let retry = retry.filter(|_| e.is_transient());
+    let auto_dismiss = retry.is_none();
+    let _ = tx.send(Action::TaskFailed(TaskFailure {
+        title: error_title(e).to_string(),
+        message: format!("{}: {}", context, e),
+        retry,
+    }));
+
+    if auto_dismiss {
+        let tx_dismiss = tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+            let _ = tx_dismiss.send(Action::HideNotification);
+        });
+    }
+}
+
+/// Notification title for each kind of error.
+fn error_title(e: &AppError) -> &'static str {
+    match e {
+        AppError::Validation { .. } => "❌ Invalid Request",
+        AppError::Unauthorized => "🔒 Unauthorized",
+        AppError::Forbidden(_) => "🔒 Permission Denied",
+        AppError::RateLimited { .. } => "⏳ Rate Limited",
+        AppError::Conflict(_) => "⚠️ Conflict",
+        AppError::ServerError { .. } => "❌ Jira Server Error",
+        _ => "❌ Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Paginated;
+    use crate::infrastructure::config::{AuthMethod, HttpConfig, JiraConfig, JiraDeployment};
+    use crate::infrastructure::jira::client::JiraClient;
+    use crate::infrastructure::jira::dtos::IssueDto;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn issue_json(key: &str) -> serde_json::Value {
+        serde_json::json!({
+            "key": key,
+            "fields": {
+                "summary": "Story",
+                "status": { "name": "To Do" },
+                "created": "2026-10-01T10:00:00.000+0000",
+                "updated": "2026-10-01T10:00:00.000+0000"
+            }
+        })
+    }
+
+    fn client(server: &MockServer) -> Arc<JiraClient> {
+        Arc::new(
+            JiraClient::new(JiraConfig {
+                base_url: server.uri(),
+                deployment: JiraDeployment::Cloud,
+                auth: AuthMethod::Pat,
+                email: None,
+                api_token: "token".to_string(),
+                http: HttpConfig::default(),
+                custom_fields: Vec::new(),
+            })
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn scrolling_fetches_each_page_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/search/jql"))
+            .and(query_param("nextPageToken", "page-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [issue_json("PROJ-2")],
+                "isLast": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let repo = client(&server);
+        let get_backlog_uc = Arc::new(GetBacklogUseCase::new(repo.clone()));
+        let search_issues_uc = Arc::new(SearchIssuesUseCase::new(repo));
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let mut app = App::new();
+        app.jql_input = "project = PROJ".to_string();
+        app.update(Action::RunJqlSearch);
+        let first = Action::IssuesLoaded(
+            IssueSource::Search("project = PROJ".to_string()),
+            Paginated::with_token(
+                vec![
+                    serde_json::from_value::<IssueDto>(issue_json("PROJ-1"))
+                        .unwrap()
+                        .into(),
+                ],
+                true,
+                Some("page-2".to_string()),
+            ),
+        );
+        app.update(first);
+
+        // Two keys pressed before the page lands
+        for _ in 0..2 {
+            check_infinite_scroll(
+                &mut app,
+                get_backlog_uc.clone(),
+                search_issues_uc.clone(),
+                tx.clone(),
+            );
+        }
+        let page = rx.recv().await.unwrap();
+        assert!(matches!(
+            page,
+            Action::IssuesLoaded(IssueSource::Search(_), _)
+        ));
+        app.update(page);
+        let keys: Vec<&str> = app.issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["PROJ-1", "PROJ-2"]);
+        assert!(!app.needs_more_issues());
+    }
+
+    #[tokio::test]
+    async fn linked_issues_load_their_watchers() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_json("PROJ-2")))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/PROJ-2/watchers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "watchCount": 1,
+                "isWatching": true,
+                "watchers": [{ "accountId": "abc-123", "displayName": "Ana Lopez" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let repo = client(&server);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        load_linked_issue(
+            "PROJ-2".to_string(),
+            Arc::new(GetIssueUseCase::new(repo.clone())),
+            Arc::new(GetWatchersUseCase::new(repo)),
+            tx,
+        );
+        assert!(
+            matches!(rx.recv().await, Some(Action::LinkedIssueLoaded(issue)) if issue.key == "PROJ-2")
+        );
+        match rx.recv().await {
+            Some(Action::WatchersLoaded(key, watchers)) => {
+                assert_eq!(key, "PROJ-2");
+                assert_eq!(watchers.count, 1);
+                assert_eq!(watchers.users[0].display_name, "Ana Lopez");
+            }
+            other => panic!("expected the watchers, got {:?}", other),
+        }
+    }
+}
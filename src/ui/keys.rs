@@ -1,15 +1,42 @@
-use crate::ui::app::{Action, App, CurrentScreen, FilterField, WorklogField};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::domain::models::FieldKind;
+use crate::ui::app::{
+    Action, App, BoardFilterField, CurrentScreen, DetailTab, FilterField, JqlSearchField,
+    LoginField, SprintPickerMode, UserPickerMode, WorklogField,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Maps a physical key event to an application action based on context.
 pub fn from_event(key: KeyEvent, app: &App) -> Option<Action> {
+    // A failure notification offering a retry takes `r` and `Esc`
+    if let Some(task) = &app.failed_task {
+        match key.code {
+            KeyCode::Char('r') => return Some(Action::RetryTask(task.clone())),
+            KeyCode::Esc => return Some(Action::HideNotification),
+            _ => {}
+        }
+    }
+
     match app.current_screen {
         CurrentScreen::Dashboard | CurrentScreen::BoardsList => match_boards_keys(key, app),
         CurrentScreen::Backlog => match_backlog_keys(key, app),
-        CurrentScreen::IssueDetail => match_detail_keys(key),
+        CurrentScreen::Kanban => match_kanban_keys(key, app),
+        CurrentScreen::SprintPicker => match_sprint_picker_keys(key, app),
+        CurrentScreen::IssueDetail => match_detail_keys(key, app),
+        CurrentScreen::CommentComposer => match_comment_composer_keys(key, app),
+        CurrentScreen::UserPicker => match_user_picker_keys(key, app),
+        CurrentScreen::LinkPicker => match_link_picker_keys(key),
+        CurrentScreen::AttachmentUpload => match_attachment_upload_keys(key),
         CurrentScreen::FilterModal => match_filter_modal_keys(key, app),
+        CurrentScreen::BoardFilterModal => match_board_filter_modal_keys(key, app),
         CurrentScreen::WorklogModal => match_worklog_modal_keys(key, app),
         CurrentScreen::WorklogListModal => match_worklog_list_modal_keys(key),
+        CurrentScreen::TransitionModal => match_transition_modal_keys(key),
+        CurrentScreen::EditIssue => match_edit_issue_keys(key, app),
+        CurrentScreen::CreateIssue => match_create_issue_keys(key, app),
+        CurrentScreen::JqlSearch => match_jql_search_keys(key, app),
+        CurrentScreen::ConfigError => match_config_error_keys(key),
+        CurrentScreen::Login => match_login_keys(key, app),
+        CurrentScreen::Unlock => match_unlock_keys(key),
         _ => match_global_keys(key),
     }
 }
@@ -21,6 +48,39 @@ fn match_global_keys(key: KeyEvent) -> Option<Action> {
     }
 }
 
+fn match_config_error_keys(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => Some(Action::Quit),
+        _ => None,
+    }
+}
+
+fn match_login_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    match (&app.login_focused_field, key.code) {
+        (_, KeyCode::Esc) => Some(Action::Quit),
+        (_, KeyCode::Enter) => Some(Action::SubmitLogin),
+        (_, KeyCode::Tab | KeyCode::Down) => Some(Action::NextLoginField),
+        (_, KeyCode::BackTab | KeyCode::Up) => Some(Action::PreviousLoginField),
+        (LoginField::Storage, KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')) => {
+            Some(Action::CycleLoginStorage)
+        }
+        (LoginField::Storage, _) => None,
+        (_, KeyCode::Char(ch)) => Some(Action::InputLoginChar(ch)),
+        (_, KeyCode::Backspace) => Some(Action::DeleteLoginChar),
+        _ => None,
+    }
+}
+
+fn match_unlock_keys(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc => Some(Action::Quit),
+        KeyCode::Enter => Some(Action::SubmitUnlock),
+        KeyCode::Char(ch) => Some(Action::InputLoginChar(ch)),
+        KeyCode::Backspace => Some(Action::DeleteLoginChar),
+        _ => None,
+    }
+}
+
 fn match_boards_keys(key: KeyEvent, app: &App) -> Option<Action> {
     match key.code {
         // Global Overrides
@@ -28,7 +88,9 @@ fn match_boards_keys(key: KeyEvent, app: &App) -> Option<Action> {
 
         // Context Specific
         KeyCode::Char('b') => Some(Action::LoadBoards),
+        KeyCode::Char('f') => Some(Action::OpenBoardFilter),
         KeyCode::Enter => app.get_selected_board().map(|b| Action::LoadIssues(b.id)),
+        KeyCode::Char('/') => Some(Action::OpenJqlSearch),
 
         // Navigation
         KeyCode::Down | KeyCode::Char('j') => Some(Action::SelectNext),
@@ -38,7 +100,7 @@ fn match_boards_keys(key: KeyEvent, app: &App) -> Option<Action> {
     }
 }
 
-fn match_backlog_keys(key: KeyEvent, _app: &App) -> Option<Action> {
+fn match_backlog_keys(key: KeyEvent, app: &App) -> Option<Action> {
     match key.code {
         KeyCode::Esc => Some(Action::GoToBoards),
         KeyCode::Char('b') => Some(Action::GoToBoards),
@@ -46,6 +108,151 @@ fn match_backlog_keys(key: KeyEvent, _app: &App) -> Option<Action> {
 
         KeyCode::Enter => Some(Action::ViewIssueDetail),
         KeyCode::Char('f') => Some(Action::OpenFilterModal),
+        KeyCode::Char('/') => Some(Action::OpenJqlSearch),
+        KeyCode::Char('v') if app.search_jql.is_none() => Some(Action::ToggleKanban),
+        KeyCode::Char('s' | 'm') => match_sprint_keys(key, app),
+        KeyCode::Char('t') => Some(Action::ToggleIssueTree),
+        KeyCode::Char('c') if app.create_issue_project().is_some() => Some(Action::OpenCreateIssue),
+        KeyCode::Char('A' | 'i') => match_assign_keys(key, app),
+
+        // Tree mode: fold and unfold parents
+        KeyCode::Char(' ') if app.issue_tree => Some(Action::ToggleIssueCollapsed),
+        KeyCode::Left | KeyCode::Char('h') if app.issue_tree => Some(Action::CollapseIssue),
+        KeyCode::Right | KeyCode::Char('l') if app.issue_tree => Some(Action::ExpandIssue),
+
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::SelectNext),
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::SelectPrevious),
+
+        _ => None,
+    }
+}
+
+fn match_kanban_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    let column = app.selected_kanban_column();
+    let column_count = app
+        .board_configuration
+        .as_ref()
+        .map_or(0, |configuration| configuration.columns.len());
+    let move_to = |target: Option<usize>| {
+        target
+            .filter(|&c| c < column_count)
+            .map(Action::MoveIssueToColumn)
+    };
+
+    match key.code {
+        KeyCode::Esc => Some(Action::GoToBoards),
+        KeyCode::Char('b') => Some(Action::GoToBoards),
+        KeyCode::Char('q') => Some(Action::Quit),
+
+        KeyCode::Enter => Some(Action::ViewIssueDetail),
+        KeyCode::Char('f') => Some(Action::OpenFilterModal),
+        KeyCode::Char('/') => Some(Action::OpenJqlSearch),
+        KeyCode::Char('v') => Some(Action::ToggleKanban),
+        KeyCode::Char('c') if app.create_issue_project().is_some() => Some(Action::OpenCreateIssue),
+        KeyCode::Char('s' | 'm') => match_sprint_keys(key, app),
+        KeyCode::Char('A' | 'i') => match_assign_keys(key, app),
+
+        // Move the selected card to the neighbouring column
+        KeyCode::Char('H') => move_to(column.and_then(|c| c.checked_sub(1))),
+        KeyCode::Char('L') => move_to(column.map(|c| c + 1)),
+        KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            move_to(column.and_then(|c| c.checked_sub(1)))
+        }
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            move_to(column.map(|c| c + 1))
+        }
+
+        KeyCode::Left | KeyCode::Char('h') => Some(Action::SelectPreviousColumn),
+        KeyCode::Right | KeyCode::Char('l') => Some(Action::SelectNextColumn),
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::SelectNext),
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::SelectPrevious),
+
+        _ => None,
+    }
+}
+
+/// `s` picks the sprint to show, `m` moves the selected issue to another sprint.
+fn match_sprint_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    if app.search_jql.is_some() || !app.current_board_has_sprints() {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('s') => Some(Action::OpenSprintPicker),
+        KeyCode::Char('m') if app.get_selected_issue().is_some() => Some(Action::OpenMoveToSprint),
+        _ => None,
+    }
+}
+
+/// `A` picks the assignee of the selected issue, `i` assigns it to the current user.
+fn match_assign_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    app.get_selected_issue()?;
+    match key.code {
+        KeyCode::Char('A') => Some(Action::OpenUserPicker(UserPickerMode::AssignIssue)),
+        KeyCode::Char('i') => Some(Action::AssignToMe),
+        _ => None,
+    }
+}
+
+fn match_user_picker_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc => Some(Action::CloseUserPicker),
+        KeyCode::Enter => {
+            let entry = app.get_selected_user_entry()?.cloned();
+            match app.user_picker_mode {
+                UserPickerMode::AssignIssue => Some(Action::AssignIssue(entry)),
+                UserPickerMode::FilterAssignee => entry.map(Action::FilterByAssignee),
+            }
+        }
+
+        // Letters go to the search, so only the arrows navigate
+        KeyCode::Down => Some(Action::SelectNext),
+        KeyCode::Up => Some(Action::SelectPrevious),
+
+        KeyCode::Char(ch) => Some(Action::InputUserQueryChar(ch)),
+        KeyCode::Backspace => Some(Action::DeleteUserQueryChar),
+        _ => None,
+    }
+}
+
+fn match_link_picker_keys(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc => Some(Action::CloseLinkPicker),
+        KeyCode::Enter => Some(Action::SubmitIssueLink),
+
+        // Letters go to the issue key, so only the arrows navigate
+        KeyCode::Down => Some(Action::SelectNext),
+        KeyCode::Up => Some(Action::SelectPrevious),
+
+        KeyCode::Char(ch) if ch.is_ascii_alphanumeric() || ch == '-' => {
+            Some(Action::InputLinkTargetChar(ch))
+        }
+        KeyCode::Backspace => Some(Action::DeleteLinkTargetChar),
+        _ => None,
+    }
+}
+
+fn match_attachment_upload_keys(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc => Some(Action::CloseAttachmentUpload),
+        KeyCode::Enter => Some(Action::SubmitAttachmentUpload),
+        KeyCode::Char(ch) => Some(Action::InputAttachmentPathChar(ch)),
+        KeyCode::Backspace => Some(Action::DeleteAttachmentPathChar),
+        _ => None,
+    }
+}
+
+fn match_sprint_picker_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc => Some(Action::CloseSprintPicker),
+        KeyCode::Char('q') => Some(Action::Quit),
+
+        KeyCode::Enter => {
+            let entry = app.get_selected_sprint_entry()?;
+            match app.sprint_picker_mode {
+                SprintPickerMode::Browse => Some(Action::SelectSprint(entry)),
+                SprintPickerMode::MoveIssue => Some(Action::MoveIssueToSprint(entry)),
+            }
+        }
 
         KeyCode::Down | KeyCode::Char('j') => Some(Action::SelectNext),
         KeyCode::Up | KeyCode::Char('k') => Some(Action::SelectPrevious),
@@ -54,12 +261,48 @@ fn match_backlog_keys(key: KeyEvent, _app: &App) -> Option<Action> {
     }
 }
 
-fn match_detail_keys(key: KeyEvent) -> Option<Action> {
+fn match_detail_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    let comments = app.detail_tab == DetailTab::Comments;
+    let links = app.detail_tab == DetailTab::Links;
+    let attachments = app.detail_tab == DetailTab::Attachments;
+    let history = app.detail_tab == DetailTab::History;
     match key.code {
+        // Issues opened through links are closed first
+        KeyCode::Esc if !app.issue_stack.is_empty() => Some(Action::CloseLinkedIssue),
         KeyCode::Esc => Some(Action::GoToBacklog),
         KeyCode::Char('q') => Some(Action::Quit),
+        KeyCode::Tab => Some(Action::ShowDetailTab(app.detail_tab.next())),
         KeyCode::Char('w') => Some(Action::OpenWorklogModal),
         KeyCode::Char('l') => Some(Action::OpenWorklogListModal),
+        KeyCode::Char('t') => Some(Action::OpenTransitionModal),
+        KeyCode::Char('A' | 'i') => match_assign_keys(key, app),
+        KeyCode::Char('W') => Some(Action::ToggleWatch),
+        KeyCode::Char('v') => Some(Action::ToggleVote),
+
+        // The comments tab acts on the selected comment
+        KeyCode::Char('a') if comments => Some(Action::OpenCommentComposer),
+        KeyCode::Char('e') if comments => Some(Action::EditSelectedComment),
+        KeyCode::Char('d') if comments => Some(Action::DeleteSelectedComment),
+        KeyCode::Down | KeyCode::Char('j') if comments && app.needs_more_comments() => {
+            Some(Action::LoadMoreComments)
+        }
+
+        // The links tab acts on the selected link
+        KeyCode::Enter if links => Some(Action::OpenSelectedLink),
+        KeyCode::Char('a') if links => Some(Action::OpenLinkPicker),
+        KeyCode::Char('d') if links => Some(Action::DeleteSelectedLink),
+
+        // The attachments tab downloads the selected attachment
+        KeyCode::Enter | KeyCode::Char('s') if attachments => {
+            Some(Action::DownloadSelectedAttachment)
+        }
+        KeyCode::Char('u') if attachments => Some(Action::OpenAttachmentUpload),
+
+        KeyCode::Down | KeyCode::Char('j') if history && app.needs_more_history() => {
+            Some(Action::LoadMoreHistory)
+        }
+
+        KeyCode::Char('e') => Some(Action::StartIssueEdit),
 
         // Scroll
         KeyCode::Down | KeyCode::Char('j') => Some(Action::SelectNext),
@@ -70,11 +313,18 @@ fn match_detail_keys(key: KeyEvent) -> Option<Action> {
 }
 
 fn match_filter_modal_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    // Custom fields are typed in, so only the arrows and Tab move between fields there
+    let typing = matches!(app.filter_focused_field, FilterField::Field(_));
     match key.code {
         KeyCode::Esc => Some(Action::CloseFilterModal),
+        KeyCode::Enter => Some(Action::ApplyFilter),
+        KeyCode::Char(ch) if typing => Some(Action::InputFilterValueChar(ch)),
+        KeyCode::Backspace if typing => Some(Action::DeleteFilterValueChar),
         KeyCode::Char('q') => Some(Action::Quit),
 
-        KeyCode::Enter => Some(Action::ApplyFilter),
+        KeyCode::Char('u') if app.filter_focused_field == FilterField::Assignee => {
+            Some(Action::OpenUserPicker(UserPickerMode::FilterAssignee))
+        }
 
         KeyCode::Tab | KeyCode::Down | KeyCode::Char('j') => Some(Action::NextFilterField),
         KeyCode::BackTab | KeyCode::Up | KeyCode::Char('k') => Some(Action::NextFilterField),
@@ -82,8 +332,10 @@ fn match_filter_modal_keys(key: KeyEvent, app: &App) -> Option<Action> {
         KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l') => {
             match app.filter_focused_field {
                 FilterField::Assignee => Some(Action::CycleAssigneeFilter),
+                FilterField::Watcher => Some(Action::CycleWatcherFilter),
                 FilterField::Status => Some(Action::CycleStatusFilter),
                 FilterField::OrderBy => Some(Action::CycleOrderByFilter),
+                FilterField::Field(_) => None,
             }
         }
 
@@ -91,10 +343,37 @@ fn match_filter_modal_keys(key: KeyEvent, app: &App) -> Option<Action> {
     }
 }
 
+fn match_board_filter_modal_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    match (&app.board_filter_focused_field, key.code) {
+        (_, KeyCode::Esc) => Some(Action::CloseBoardFilter),
+        (_, KeyCode::Enter) => Some(Action::ApplyBoardFilter),
+        (_, KeyCode::Tab | KeyCode::BackTab | KeyCode::Down | KeyCode::Up) => {
+            Some(Action::NextBoardFilterField)
+        }
+        (BoardFilterField::Type, KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')) => {
+            Some(Action::CycleBoardTypeFilter)
+        }
+        (BoardFilterField::Type, _) => None,
+        (_, KeyCode::Char(ch)) => Some(Action::InputBoardFilterChar(ch)),
+        (_, KeyCode::Backspace) => Some(Action::DeleteBoardFilterChar),
+        _ => None,
+    }
+}
+
 fn match_worklog_modal_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key.code {
+            KeyCode::Char('s') => Some(Action::SubmitWorklog),
+            _ => None,
+        };
+    }
+
+    let in_comment = app.worklog_focused_field == WorklogField::Comment;
     match key.code {
         KeyCode::Esc => Some(Action::CloseWorklogModal),
 
+        // The comment is Markdown: Enter starts a new line there, Ctrl+S saves
+        KeyCode::Enter if in_comment => Some(Action::InputWorklogChar('\n')),
         KeyCode::Enter => Some(Action::SubmitWorklog),
 
         KeyCode::Tab | KeyCode::Down => Some(Action::NextWorklogField),
@@ -117,9 +396,7 @@ fn match_worklog_modal_keys(key: KeyEvent, app: &App) -> Option<Action> {
             }
         }
 
-        KeyCode::Char(ch) if ch.is_alphanumeric() || ch == ' ' => {
-            Some(Action::InputWorklogChar(ch))
-        }
+        KeyCode::Char(ch) if in_comment => Some(Action::InputWorklogChar(ch)),
 
         KeyCode::Backspace => Some(Action::DeleteWorklogChar),
 
@@ -141,3 +418,135 @@ fn match_worklog_list_modal_keys(key: KeyEvent) -> Option<Action> {
         _ => None,
     }
 }
+
+fn match_transition_modal_keys(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc => Some(Action::CloseTransitionModal),
+        KeyCode::Char('q') => Some(Action::Quit),
+
+        KeyCode::Enter => Some(Action::ApplyTransition),
+
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::SelectNext),
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::SelectPrevious),
+
+        _ => None,
+    }
+}
+
+fn match_edit_issue_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key.code {
+            KeyCode::Char('s') => Some(Action::SubmitIssueEdit),
+            _ => None,
+        };
+    }
+
+    let kind = app.focused_edit_field().map(|field| &field.meta.kind);
+    match (kind, key.code) {
+        (_, KeyCode::Esc) => Some(Action::CancelIssueEdit),
+        // The description is multi-line: Enter starts a new line there
+        (Some(FieldKind::RichText), KeyCode::Enter) => Some(Action::InputEditChar('\n')),
+        (_, KeyCode::Enter) => Some(Action::SubmitIssueEdit),
+        (_, KeyCode::Tab | KeyCode::Down) => Some(Action::NextEditField),
+        (_, KeyCode::BackTab | KeyCode::Up) => Some(Action::PreviousEditField),
+
+        (Some(FieldKind::Choice | FieldKind::MultiChoice), KeyCode::Left) => {
+            Some(Action::PreviousEditOption)
+        }
+        (Some(FieldKind::Choice | FieldKind::MultiChoice), KeyCode::Right) => {
+            Some(Action::NextEditOption)
+        }
+        (Some(FieldKind::Choice | FieldKind::MultiChoice | FieldKind::Unsupported(_)), _) => None,
+
+        (Some(_), KeyCode::Char(ch)) => Some(Action::InputEditChar(ch)),
+        (Some(_), KeyCode::Backspace) => Some(Action::DeleteEditChar),
+        _ => None,
+    }
+}
+
+fn match_comment_composer_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key.code {
+            KeyCode::Char('s') => Some(Action::SubmitComment),
+            _ => None,
+        };
+    }
+
+    // The suggestions of the mention being typed take the navigation keys
+    let suggesting = !app.mention_suggestions.is_empty();
+    match key.code {
+        KeyCode::Esc => Some(Action::CloseCommentComposer),
+        KeyCode::Tab | KeyCode::Enter if suggesting => Some(Action::AcceptMention),
+        KeyCode::Down if suggesting => Some(Action::SelectNextMention),
+        KeyCode::Up if suggesting => Some(Action::SelectPreviousMention),
+        KeyCode::Enter => Some(Action::InputCommentChar('\n')),
+        KeyCode::Char(ch) => Some(Action::InputCommentChar(ch)),
+        KeyCode::Backspace => Some(Action::DeleteCommentChar),
+        _ => None,
+    }
+}
+
+fn match_create_issue_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    let kind = app.focused_create_field().map(|field| &field.meta.kind);
+    match (kind, key.code) {
+        (_, KeyCode::Esc) => Some(Action::CloseCreateIssue),
+        (_, KeyCode::Enter) => Some(Action::SubmitCreateIssue),
+        (_, KeyCode::Tab | KeyCode::Down) => Some(Action::NextCreateField),
+        (_, KeyCode::BackTab | KeyCode::Up) => Some(Action::PreviousCreateField),
+
+        // The issue type row switches the form to another type
+        (None, KeyCode::Left | KeyCode::Right) => app
+            .adjacent_create_issue_type(key.code == KeyCode::Right)
+            .map(|issue_type| Action::SelectCreateIssueType(issue_type.id.clone())),
+        (None, _) => None,
+
+        (Some(FieldKind::Choice | FieldKind::MultiChoice), KeyCode::Left) => {
+            Some(Action::PreviousCreateOption)
+        }
+        (Some(FieldKind::Choice | FieldKind::MultiChoice), KeyCode::Right) => {
+            Some(Action::NextCreateOption)
+        }
+        (Some(FieldKind::MultiChoice), KeyCode::Char(' ')) => Some(Action::ToggleCreateOption),
+        (Some(FieldKind::Choice | FieldKind::MultiChoice | FieldKind::Unsupported(_)), _) => None,
+
+        (Some(_), KeyCode::Char(ch)) => Some(Action::InputCreateChar(ch)),
+        (Some(_), KeyCode::Backspace) => Some(Action::DeleteCreateChar),
+        _ => None,
+    }
+}
+
+fn match_jql_search_keys(key: KeyEvent, app: &App) -> Option<Action> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key.code {
+            KeyCode::Char('s') => Some(Action::StartSaveQuery),
+            _ => None,
+        };
+    }
+
+    match (&app.jql_focused_field, key.code) {
+        (JqlSearchField::SaveName, KeyCode::Esc) => Some(Action::NextJqlSearchField),
+        (_, KeyCode::Esc) => Some(Action::CloseJqlSearch),
+        (_, KeyCode::Tab | KeyCode::BackTab) => Some(Action::NextJqlSearchField),
+
+        (JqlSearchField::Query, KeyCode::Enter) => Some(Action::RunJqlSearch),
+        (JqlSearchField::SaveName, KeyCode::Enter) => Some(Action::ConfirmSaveQuery),
+        (JqlSearchField::Query | JqlSearchField::SaveName, KeyCode::Char(ch)) => {
+            Some(Action::InputJqlChar(ch))
+        }
+        (JqlSearchField::Query | JqlSearchField::SaveName, KeyCode::Backspace) => {
+            Some(Action::DeleteJqlChar)
+        }
+
+        (JqlSearchField::SavedQueries, KeyCode::Enter) => Some(Action::RunSavedQuery),
+        (JqlSearchField::SavedQueries, KeyCode::Char('d')) => Some(Action::DeleteSavedQuery),
+        (JqlSearchField::SavedQueries, KeyCode::Char('q')) => Some(Action::Quit),
+        (JqlSearchField::SavedQueries, KeyCode::Down | KeyCode::Char('j')) => {
+            Some(Action::SelectNext)
+        }
+        (JqlSearchField::SavedQueries, KeyCode::Up | KeyCode::Char('k')) => {
+            Some(Action::SelectPrevious)
+        }
+
+        _ => None,
+    }
+}
@@ -5,7 +5,7 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-use crate::ui::app::{App, CurrentScreen};
+use crate::ui::app::{App, CurrentScreen, DetailTab, UserPickerMode};
 use crate::ui::widgets;
 
 /// Main render function - entry point for all UI rendering
@@ -22,13 +22,49 @@ pub fn render(app: &App, frame: &mut Frame) {
 /// Renders the title bar with context-specific help text
 fn render_title(frame: &mut Frame, area: Rect, app: &App) {
     let title_text = match app.current_screen {
-        CurrentScreen::BoardsList => " Boards List | 'b' Load | Enter to Select | 'q' Quit ",
-        CurrentScreen::Backlog => " Backlog | 'f' Filter | Enter View Details | 'b' Back ",
+        CurrentScreen::BoardsList => {
+            " Boards List | 'b' Load | 'f' Search Boards | Enter to Select | '/' JQL Search | 'q' Quit "
+        }
+        CurrentScreen::BoardFilterModal => {
+            " Search Boards | Tab to Switch | Type to Edit | Enter to Search | Esc Cancel "
+        }
+        CurrentScreen::Backlog => {
+            " Backlog | 'c' Create | 'A' Assign | 'i' Assign to Me | 'f' Filter | 't' Tree | 's' Sprint | 'm' Move to Sprint | 'v' Board View | '/' JQL Search | Enter View Details | 'b' Back "
+        }
+        CurrentScreen::Kanban => {
+            " Board | h/l Column | j/k Card | H/L Move Card | 'c' Create | 'A' Assign | 'i' Take | 's' Sprint | 'v' List View | 'f' Filter | Enter Details | 'b' Back "
+        }
+        CurrentScreen::SprintPicker => " Sprints | Enter Select | Esc Cancel ",
+        CurrentScreen::UserPicker => " Users | Type to Search | Enter Select | Esc Cancel ",
+        CurrentScreen::AttachmentUpload => {
+            " Upload Attachment | Type a Path | Enter Upload | Esc Cancel "
+        }
+        CurrentScreen::LinkPicker => {
+            " Link Issue | Type the Key | Up/Down Relation | Enter Link | Esc Cancel "
+        }
+        CurrentScreen::IssueDetail if app.detail_tab == DetailTab::Comments => {
+            " Comments | 'a' Add | 'e' Edit | 'd' Delete | j/k Select | Tab Links | Esc Back "
+        }
+        CurrentScreen::IssueDetail if app.detail_tab == DetailTab::Attachments => {
+            " Attachments | Enter Download | 'u' Upload | j/k Select | Tab History | Esc Back "
+        }
+        CurrentScreen::IssueDetail if app.detail_tab == DetailTab::History => {
+            " History | j/k Select | Tab Description | Esc Back "
+        }
+        CurrentScreen::IssueDetail if app.detail_tab == DetailTab::Links => {
+            " Links | Enter Open | 'a' Add | 'd' Delete | j/k Select | Tab Attachments | Esc Back "
+        }
         CurrentScreen::IssueDetail => {
-            " Issue Details | 'w' Log Time | 'l' List Times | Up/Down Scroll | Esc Back "
+            " Issue Details | 'e' Edit | 't' Transition | 'A' Assign | 'i' Assign to Me | 'W' Watch | 'v' Vote | 'w' Log Time | 'l' List Times | Tab Comments | Up/Down Scroll | Esc Back "
+        }
+        CurrentScreen::CommentComposer => {
+            " Comment | Type Markdown | '@' Mention | Ctrl+S Save | Esc Cancel "
+        }
+        CurrentScreen::EditIssue => {
+            " Edit Issue | Tab Switch Field | Type to Edit | Left/Right Change | Ctrl+S Save | Esc Cancel "
         }
         CurrentScreen::FilterModal => {
-            " Filter Modal | Tab to Switch | Left/Right to Change | Enter to Apply "
+            " Filter Modal | Tab to Switch | Left/Right to Change | 'u' Pick Assignee | Enter to Apply "
         }
         CurrentScreen::WorklogModal => {
             " Log Time | Tab Switch Field | Type to Edit | Enter Save | Esc Cancel "
@@ -36,6 +72,18 @@ fn render_title(frame: &mut Frame, area: Rect, app: &App) {
         CurrentScreen::WorklogListModal => {
             " Worklog List | Enter or 'e' Edit | 'd' Delete | Esc Close "
         }
+        CurrentScreen::TransitionModal => " Transition Issue | Enter Apply | Esc Cancel ",
+        CurrentScreen::CreateIssue => {
+            " Create Issue | Tab Switch Field | Type to Edit | Enter Create | Esc Cancel "
+        }
+        CurrentScreen::JqlSearch => {
+            " JQL Search | Enter Run | Tab Saved Queries | Ctrl+S Save | Esc Back "
+        }
+        CurrentScreen::ConfigError => " Configuration Error | 'q' Quit ",
+        CurrentScreen::Login => {
+            " Connect to Jira | Tab Switch Field | Left/Right Storage | Enter Save | Esc Quit "
+        }
+        CurrentScreen::Unlock => " Unlock Credentials | Enter Unlock | Esc Quit ",
         _ => " Rust Jira TUI ",
     };
 
@@ -56,20 +104,28 @@ fn render_body(frame: &mut Frame, area: Rect, app: &App) {
         CurrentScreen::Dashboard | CurrentScreen::BoardsList => {
             widgets::boards::render(frame, area, app);
         }
-        CurrentScreen::Backlog => {
-            widgets::backlog::render(frame, area, app);
+        CurrentScreen::Backlog | CurrentScreen::Kanban => {
+            render_issues(frame, area, app, &app.current_screen);
         }
-        CurrentScreen::IssueDetail => {
+        CurrentScreen::SprintPicker => {
+            if let Some(prev_screen) = &app.previous_screen {
+                render_issues(frame, area, app, prev_screen);
+            }
+            widgets::sprint_picker::render(frame, area, app);
+        }
+        CurrentScreen::IssueDetail | CurrentScreen::EditIssue => {
             widgets::issue_detail::render(frame, area, app);
         }
         CurrentScreen::FilterModal => {
-            if let Some(prev_screen) = &app.previous_screen
-                && prev_screen == &CurrentScreen::Backlog
-            {
-                widgets::backlog::render(frame, area, app)
+            if let Some(prev_screen) = &app.previous_screen {
+                render_issues(frame, area, app, prev_screen);
             }
             widgets::filter_modal::render(frame, area, app);
         }
+        CurrentScreen::BoardFilterModal => {
+            widgets::boards::render(frame, area, app);
+            widgets::board_filter_modal::render(frame, area, app);
+        }
         CurrentScreen::WorklogModal => {
             if let Some(prev_screen) = &app.previous_screen {
                 match prev_screen {
@@ -90,6 +146,63 @@ fn render_body(frame: &mut Frame, area: Rect, app: &App) {
             }
             widgets::worklog_list_modal::render(frame, area, app);
         }
+        CurrentScreen::JqlSearch => {
+            widgets::jql_search::render(frame, area, app);
+        }
+        CurrentScreen::ConfigError => {
+            widgets::config_error::render(frame, area, app);
+        }
+        CurrentScreen::Login => {
+            widgets::login::render(frame, area, app);
+        }
+        CurrentScreen::Unlock => {
+            widgets::login::render_unlock(frame, area, app);
+        }
+        CurrentScreen::CreateIssue => {
+            if let Some(prev_screen) = &app.previous_screen {
+                render_issues(frame, area, app, prev_screen);
+            }
+            widgets::create_issue::render(frame, area, app);
+        }
+        CurrentScreen::UserPicker => {
+            match app.user_picker_mode {
+                // Opened from the filter modal, itself over the issues
+                UserPickerMode::FilterAssignee => {
+                    if let Some(prev_screen) = &app.previous_screen {
+                        render_issues(frame, area, app, prev_screen);
+                    }
+                    widgets::filter_modal::render(frame, area, app);
+                }
+                UserPickerMode::AssignIssue => match &app.previous_screen {
+                    Some(CurrentScreen::IssueDetail) => {
+                        widgets::issue_detail::render(frame, area, app)
+                    }
+                    Some(prev_screen) => render_issues(frame, area, app, prev_screen),
+                    None => {}
+                },
+            }
+            widgets::user_picker::render(frame, area, app);
+        }
+        CurrentScreen::CommentComposer => {
+            widgets::issue_detail::render(frame, area, app);
+            widgets::comment_composer::render(frame, area, app);
+        }
+        CurrentScreen::AttachmentUpload => {
+            widgets::issue_detail::render(frame, area, app);
+            widgets::attachment_upload::render(frame, area, app);
+        }
+        CurrentScreen::LinkPicker => {
+            widgets::issue_detail::render(frame, area, app);
+            widgets::link_picker::render(frame, area, app);
+        }
+        CurrentScreen::TransitionModal => {
+            if let Some(prev_screen) = &app.previous_screen
+                && prev_screen == &CurrentScreen::IssueDetail
+            {
+                widgets::issue_detail::render(frame, area, app)
+            }
+            widgets::transition_modal::render(frame, area, app);
+        }
         _ => {
             let block = Block::default()
                 .borders(Borders::ALL)
@@ -103,6 +216,37 @@ fn render_body(frame: &mut Frame, area: Rect, app: &App) {
     }
 
     if let (Some(title), Some(message)) = (&app.notification_title, &app.notification_message) {
-        widgets::notification::render(frame, area, title, message, app.notification_is_success);
+        widgets::notification::render(
+            frame,
+            area,
+            title,
+            message,
+            app.notification_is_success,
+            app.failed_task.is_some(),
+        );
+    }
+}
+
+/// Renders the issue list or board, below the header of the sprint being shown
+fn render_issues(frame: &mut Frame, area: Rect, app: &App, screen: &CurrentScreen) {
+    let area = match &app.current_sprint {
+        Some(sprint) if app.search_jql.is_none() => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(widgets::sprint_header::HEIGHT),
+                    Constraint::Min(1),
+                ])
+                .split(area);
+            widgets::sprint_header::render(frame, chunks[0], sprint);
+            chunks[1]
+        }
+        _ => area,
+    };
+
+    match screen {
+        CurrentScreen::Backlog => widgets::backlog::render(frame, area, app),
+        CurrentScreen::Kanban => widgets::kanban::render(frame, area, app),
+        _ => {}
     }
 }
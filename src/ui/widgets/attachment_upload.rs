@@ -0,0 +1,63 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::ui::app::App;
+
+use super::utils::centered_rect;
+
+/// Renders the upload popup: the path of the file to attach, checked as it is typed
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(60, 25, area);
+    frame.render_widget(Clear, popup_area);
+
+    let title = match app.get_selected_issue() {
+        Some(issue) => format!(" Attach a File to {} ", issue.key),
+        None => " Attach a File ".to_string(),
+    };
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Path
+            Constraint::Min(1),    // Validation
+            Constraint::Length(1), // Help
+        ])
+        .split(inner_area);
+
+    let input = Line::from(vec![
+        Span::styled(" Path: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            app.attachment_path_input.clone(),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled("█", Style::default().fg(Color::Yellow)),
+    ]);
+    frame.render_widget(Paragraph::new(input), chunks[0]);
+
+    let status = match app.attachment_path() {
+        Ok(_) => Span::styled(" Ready to upload", Style::default().fg(Color::Green)),
+        Err(_) if app.attachment_path_input.trim().is_empty() => Span::styled(
+            " Absolute, relative or ~/ path",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Err(e) => Span::styled(format!(" {}", e), Style::default().fg(Color::Red)),
+    };
+    frame.render_widget(Paragraph::new(Line::from(status)), chunks[1]);
+
+    let help_text = Paragraph::new(" Enter: Upload | Esc: Cancel ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help_text, chunks[2]);
+}
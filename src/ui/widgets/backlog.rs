@@ -5,37 +5,53 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 
-use crate::domain::models::IssueStatus;
-use crate::ui::app::App;
+use crate::domain::models::{FieldMeta, Issue, IssueLevel, IssueStatus};
+use crate::ui::app::{App, TreeRow};
 
-/// Renders the backlog/issues list view
+use super::field_value;
+
+/// Renders the backlog/issues list view, flat or as an epic/parent tree
 pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    let items: Vec<ListItem> = app
-        .issues
-        .iter()
-        .map(|i| {
-            let status_style = match i.status {
-                IssueStatus::Todo => Style::default().fg(Color::Gray),
-                IssueStatus::InProgress => Style::default().fg(Color::Yellow),
-                IssueStatus::Done => Style::default().fg(Color::Green),
-                _ => Style::default().fg(Color::Magenta),
+    let columns = app.column_fields();
+    let (items, selected): (Vec<ListItem>, Option<usize>) = if app.issue_tree {
+        let rows = app.tree_rows();
+        let selected = rows
+            .iter()
+            .position(|r| r.issue_index == app.selected_issue_index);
+        (
+            rows.iter()
+                .map(|row| tree_item(app, row, &columns))
+                .collect(),
+            selected,
+        )
+    } else {
+        let items = app
+            .issues
+            .iter()
+            .map(|i| ListItem::new(Line::from(issue_spans(i, &columns))))
+            .collect();
+        (items, Some(app.selected_issue_index))
+    };
+
+    let mut title = match &app.search_jql {
+        Some(jql) => {
+            // The enhanced search endpoint does not report a total
+            let total = match (app.total_issues, &app.next_issues_page) {
+                (Some(total), _) => total.to_string(),
+                (None, Some(_)) => "more".to_string(),
+                (None, None) => app.issues.len().to_string(),
             };
-            let status_str = format!("{:?}", i.status);
-            let priority = i.priority.as_deref().unwrap_or("-");
-            let content = Line::from(vec![
-                Span::styled(
-                    format!("{:<10}", i.key),
-                    Style::default().add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(format!("[{:<12}] ", status_str), status_style),
-                Span::raw(format!("({:^8}) ", priority)),
-                Span::raw(&i.summary),
-            ]);
-            ListItem::new(content)
-        })
-        .collect();
-
-    let title = " Backlog / Issues ";
+            format!(" Search: {} ({}/{}) ", jql, app.issues.len(), total)
+        }
+        None => " Backlog / Issues ".to_string(),
+    };
+    if app.issue_tree {
+        title.push_str("[tree] ");
+    }
+    if !columns.is_empty() {
+        let names: Vec<&str> = columns.iter().map(|field| field.name.as_str()).collect();
+        title.push_str(&format!("[{}] ", names.join(" | ")));
+    }
     let list = List::new(items)
         .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(
@@ -46,6 +62,77 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         );
 
     let mut state = ListState::default();
-    state.select(Some(app.selected_issue_index));
+    state.select(selected);
     frame.render_stateful_widget(list, area, &mut state);
 }
+
+/// Width of a custom field column, values are cut to fit.
+const FIELD_COLUMN_WIDTH: usize = 12;
+
+fn issue_spans<'a>(i: &'a Issue, columns: &[&FieldMeta]) -> Vec<Span<'a>> {
+    let status_style = match i.status {
+        IssueStatus::Todo => Style::default().fg(Color::Gray),
+        IssueStatus::InProgress => Style::default().fg(Color::Yellow),
+        IssueStatus::Done => Style::default().fg(Color::Green),
+        _ => Style::default().fg(Color::Magenta),
+    };
+    let status_str = format!("{:?}", i.status);
+    let priority = i.priority.as_deref().unwrap_or("-");
+    let mut spans = vec![
+        Span::styled(
+            format!("{:<10}", i.key),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!("[{:<12}] ", status_str), status_style),
+        Span::raw(format!("({:^8}) ", priority)),
+    ];
+    for field in columns {
+        let mut span = match i.fields.get(&field.id) {
+            Some(value) => field_value::span(value),
+            None => Span::styled("-", Style::default().fg(Color::DarkGray)),
+        };
+        span.content = format!(
+            "{:<width$.width$} ",
+            span.content,
+            width = FIELD_COLUMN_WIDTH
+        )
+        .into();
+        spans.push(span);
+    }
+    spans.push(Span::raw(&i.summary));
+    spans
+}
+
+/// An issue indented under its parent, with a fold marker and, for epics, their progress
+fn tree_item<'a>(app: &'a App, row: &TreeRow, columns: &[&FieldMeta]) -> ListItem<'a> {
+    let issue = &app.issues[row.issue_index];
+    let marker = match (row.children, row.collapsed) {
+        (0, _) => "  ",
+        (_, true) => "▸ ",
+        (_, false) => "▾ ",
+    };
+
+    let mut spans = vec![Span::raw(format!("{}{}", "  ".repeat(row.depth), marker))];
+    if issue.issue_type.level == IssueLevel::Epic {
+        spans.push(Span::styled(
+            "EPIC ",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    spans.extend(issue_spans(issue, columns));
+
+    if issue.issue_type.level == IssueLevel::Epic {
+        spans.push(Span::styled(
+            format!("  {}/{} done", row.done_children, row.children),
+            Style::default().fg(Color::Green),
+        ));
+    } else if row.collapsed {
+        spans.push(Span::styled(
+            format!("  (+{})", row.children),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
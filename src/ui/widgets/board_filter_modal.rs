@@ -0,0 +1,101 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::ui::app::{App, BoardFilterField};
+
+use super::utils::centered_rect;
+
+/// Renders the board search modal as a popup overlay
+pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let popup_area = centered_rect(60, 50, area);
+
+    frame.render_widget(Clear, popup_area);
+
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Search Boards ")
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(3),
+        ])
+        .split(inner_area);
+
+    let focused = &app.board_filter_focused_field;
+    render_input(
+        frame,
+        chunks[0],
+        " Name contains ",
+        &app.board_filter_name,
+        *focused == BoardFilterField::Name,
+    );
+    render_input(
+        frame,
+        chunks[1],
+        " Project key ",
+        &app.board_filter_project,
+        *focused == BoardFilterField::Project,
+    );
+
+    let type_style = if *focused == BoardFilterField::Type {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let type_text = Line::from(vec![
+        Span::raw(" Type: "),
+        Span::styled(format!("< {} >", app.board_filter_type.label()), type_style),
+    ]);
+    let type_block =
+        Paragraph::new(type_text).block(Block::default().borders(Borders::ALL).title(" Type "));
+    frame.render_widget(type_block, chunks[2]);
+
+    let help_text = Paragraph::new(
+        " Tab: Switch field | ←/→: Change type | Enter: Search (empty = all) | Esc: Cancel ",
+    )
+    .style(Style::default().fg(Color::DarkGray))
+    .alignment(Alignment::Center);
+
+    frame.render_widget(help_text, chunks[4]);
+}
+
+fn render_input(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    title: &str,
+    value: &str,
+    focused: bool,
+) {
+    let border_color = if focused { Color::Yellow } else { Color::White };
+    let mut spans = vec![Span::styled(
+        value.to_string(),
+        Style::default().fg(Color::White),
+    )];
+    if focused {
+        spans.push(Span::styled("█", Style::default().fg(Color::Yellow)));
+    }
+
+    let input = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(border_color)),
+    );
+    frame.render_widget(input, area);
+}
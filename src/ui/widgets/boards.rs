@@ -6,17 +6,20 @@ use ratatui::{
     widgets::{Block, Borders, Row, Table, TableState},
 };
 
+use crate::domain::models::BoardFilter;
 use crate::ui::app::App;
 
 /// Renders the boards list view
 pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     if app.boards.is_empty() {
+        let title = if app.board_filter == BoardFilter::default() {
+            " Boards (Press 'b' to load) ".to_string()
+        } else {
+            format!("{}- no matches ", title(app))
+        };
         let empty_rows: Vec<Row> = vec![];
-        let empty = Table::new(empty_rows, [Constraint::Percentage(100)]).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Boards (Press 'b' to load) "),
-        );
+        let empty = Table::new(empty_rows, [Constraint::Percentage(100)])
+            .block(Block::default().borders(Borders::ALL).title(title));
         frame.render_widget(empty, area);
         return;
     }
@@ -52,7 +55,7 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
 
     let table = Table::new(rows, widths)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title(" Boards "))
+        .block(Block::default().borders(Borders::ALL).title(title(app)))
         .highlight_style(
             Style::default()
                 .bg(Color::Blue)
@@ -65,3 +68,24 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     state.select(Some(app.selected_board_index));
     frame.render_stateful_widget(table, area, &mut state);
 }
+
+/// Block title with the number of boards and the active search criteria.
+fn title(app: &App) -> String {
+    let filter = &app.board_filter;
+    let mut criteria = Vec::new();
+    if let Some(name) = &filter.name {
+        criteria.push(format!("name ~ \"{}\"", name));
+    }
+    if let Some(project) = &filter.project_key {
+        criteria.push(format!("project {}", project));
+    }
+    if let Some(board_type) = filter.board_type.to_query() {
+        criteria.push(board_type.to_string());
+    }
+
+    if criteria.is_empty() {
+        format!(" Boards ({}) ", app.boards.len())
+    } else {
+        format!(" Boards ({}) | {} ", app.boards.len(), criteria.join(", "))
+    }
+}
@@ -0,0 +1,92 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::ui::app::App;
+
+use super::utils::centered_rect;
+
+/// Renders the comment composer as a popup overlay, with the users matching the mention
+/// being typed below the input
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 60, area);
+    frame.render_widget(Clear, popup_area);
+
+    let action = if app.comment_being_edited.is_some() {
+        "Edit Comment"
+    } else {
+        "Add Comment"
+    };
+    let title = match app.get_selected_issue() {
+        Some(issue) => format!(" {} on {} (Markdown) ", action, issue.key),
+        None => format!(" {} (Markdown) ", action),
+    };
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let suggestions_height = if app.mention_suggestions.is_empty() {
+        0
+    } else {
+        app.mention_suggestions.len().min(5) as u16 + 2
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),                     // Input
+            Constraint::Length(suggestions_height), // Mention suggestions
+            Constraint::Length(1),                  // Help
+        ])
+        .split(inner_area);
+
+    let mut text = Text::styled(app.comment_input.clone(), Style::default().fg(Color::White));
+    let cursor = Span::styled("█", Style::default().fg(Color::Yellow));
+    match text.lines.last_mut() {
+        Some(line) if !app.comment_input.ends_with('\n') => line.spans.push(cursor),
+        _ => text.lines.push(Line::from(cursor)),
+    }
+    let input = Paragraph::new(text).wrap(Wrap { trim: false });
+    frame.render_widget(input, chunks[0]);
+
+    if !app.mention_suggestions.is_empty() {
+        let items: Vec<ListItem> = app
+            .mention_suggestions
+            .iter()
+            .map(|user| ListItem::new(format!("@{}", user.display_name)))
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Mention ")
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .style(Style::default().fg(Color::White))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let mut state = ListState::default().with_selected(Some(app.selected_mention_index));
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+
+    let help = if app.mention_suggestions.is_empty() {
+        " @name: Mention | Enter: New line | Ctrl+S: Save | Esc: Cancel "
+    } else {
+        " Up/Down: Choose | Tab/Enter: Insert mention | Ctrl+S: Save | Esc: Cancel "
+    };
+    let help_text = Paragraph::new(help)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help_text, chunks[2]);
+}
@@ -0,0 +1,81 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::ui::app::App;
+
+/// Example shown to help the user write a working `config.toml`.
+const EXAMPLE: &str = r#"default_profile = "work"
+
+[profiles.work]
+base_url = "https://your-domain.atlassian.net"
+email = "you@example.com"
+api_token = "your_api_token"
+default_board = 42
+
+[profiles.onprem]
+base_url = "https://jira.your-company.com"
+deployment = "server"
+api_token = "your_personal_access_token""#;
+
+/// Renders the start-up configuration error screen
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Configuration Error ")
+        .style(Style::default().fg(Color::Red));
+
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let message = app.config_error.as_deref().unwrap_or("Unknown error");
+    let mut lines = vec![Line::from(Span::styled(
+        "❌ jira-tui could not start with the current configuration:",
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(message.lines().map(|line| {
+        Line::from(Span::styled(
+            line.to_string(),
+            Style::default().fg(Color::White),
+        ))
+    }));
+    let error = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(error, chunks[0]);
+
+    let mut help = vec![
+        Line::from(Span::styled(
+            "Create the config file with one or more profiles, for example:",
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(""),
+    ];
+    help.extend(
+        EXAMPLE
+            .lines()
+            .map(|line| Line::from(Span::styled(line, Style::default().fg(Color::Green)))),
+    );
+    help.push(Line::from(""));
+    help.push(Line::from(Span::styled(
+        "Select a profile with --profile <name>. JIRA_BASE_URL, JIRA_EMAIL, JIRA_API_TOKEN, \
+         JIRA_DEPLOYMENT and JIRA_AUTH override the profile values.",
+        Style::default().fg(Color::Cyan),
+    )));
+    let help = Paragraph::new(help).wrap(Wrap { trim: false });
+    frame.render_widget(help, chunks[1]);
+
+    let footer = Paragraph::new(" q/Esc: Quit ").style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(footer, chunks[2]);
+}
@@ -0,0 +1,84 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::ui::app::App;
+
+use super::form_field;
+use super::utils::centered_rect;
+
+/// Renders the create issue form as a popup overlay
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(80, 80, area);
+    frame.render_widget(Clear, popup_area);
+
+    let title = match &app.create_project_key {
+        Some(project_key) => format!(" Create Issue in {} ", project_key),
+        None => " Create Issue ".to_string(),
+    };
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Issue type
+            Constraint::Min(1),    // Fields
+            Constraint::Length(1), // Help
+        ])
+        .split(inner_area);
+
+    let type_focused = app.create_focused_field == 0;
+    let issue_type = app
+        .get_selected_create_issue_type()
+        .map_or("Loading...".to_string(), |t| t.name.clone());
+    let type_line = Line::from(vec![
+        Span::styled(
+            " Issue Type: ",
+            if type_focused {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            },
+        ),
+        Span::styled("◀ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            issue_type,
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" ▶", Style::default().fg(Color::DarkGray)),
+    ]);
+    frame.render_widget(Paragraph::new(type_line), chunks[0]);
+
+    let lines: Vec<Line> = app
+        .create_fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| form_field::line(field, app.create_focused_field == i + 1))
+        .collect();
+
+    // Keep the focused field in view
+    let height = chunks[1].height as usize;
+    let scroll = app.create_focused_field.saturating_sub(height.max(1));
+    frame.render_widget(Paragraph::new(lines).scroll((scroll as u16, 0)), chunks[1]);
+
+    let help_text = Paragraph::new(
+        " Tab: Next field | Left/Right: Change | Space: Toggle option | Enter: Create | Esc: Cancel ",
+    )
+    .style(Style::default().fg(Color::DarkGray))
+    .alignment(Alignment::Center);
+    frame.render_widget(help_text, chunks[2]);
+}
@@ -0,0 +1,48 @@
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+use crate::domain::models::FieldValue;
+
+/// Renders a field value, colored after its kind.
+pub fn span(value: &FieldValue) -> Span<'static> {
+    Span::styled(text(value), style(value))
+}
+
+/// The value as shown to the user.
+pub fn text(value: &FieldValue) -> String {
+    match value {
+        FieldValue::Text(text) | FieldValue::RichText(text) | FieldValue::IssueKey(text) => {
+            text.clone()
+        }
+        // Story points and estimates are mostly whole numbers
+        FieldValue::Number(number) if number.fract() == 0.0 => format!("{:.0}", number),
+        FieldValue::Number(number) => number.to_string(),
+        FieldValue::Option(option) => option.name.clone(),
+        FieldValue::Options(options) => options
+            .iter()
+            .map(|option| option.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        FieldValue::Labels(labels) => labels.join(", "),
+        FieldValue::Account(user) => user.display_name.clone(),
+        FieldValue::Date(date) => date.format("%Y-%m-%d").to_string(),
+        // Values typed in a form only know IDs
+        FieldValue::Choice(id) | FieldValue::User(id) => id.clone(),
+        FieldValue::Choices(ids) => ids.join(", "),
+    }
+}
+
+fn style(value: &FieldValue) -> Style {
+    match value {
+        FieldValue::Number(_) => Style::default().fg(Color::LightBlue),
+        FieldValue::Option(_)
+        | FieldValue::Options(_)
+        | FieldValue::Choice(_)
+        | FieldValue::Choices(_) => Style::default().fg(Color::Cyan),
+        FieldValue::Account(_) | FieldValue::User(_) => Style::default().fg(Color::Green),
+        FieldValue::Date(_) => Style::default().fg(Color::Yellow),
+        _ => Style::default(),
+    }
+}
@@ -12,7 +12,10 @@ use super::utils::centered_rect;
 
 /// Renders the filter modal as a popup overlay
 pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    let popup_area = centered_rect(70, 50, area);
+    let fields = app.filter_fields();
+    // Each custom field filter adds a row
+    let height = (50 + 10 * fields.len() as u16).min(90);
+    let popup_area = centered_rect(70, height, area);
 
     frame.render_widget(Clear, popup_area);
 
@@ -24,18 +27,16 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let inner_area = popup_block.inner(popup_area);
     frame.render_widget(popup_block, popup_area);
 
+    let mut constraints = vec![Constraint::Length(3); 4 + fields.len()];
+    constraints.extend([Constraint::Length(1), Constraint::Length(3)]);
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(1),
-            Constraint::Length(3),
-        ])
+        .constraints(constraints)
         .split(inner_area);
+    let order_chunk = 3 + fields.len();
 
     let assignee_focused = app.filter_focused_field == FilterField::Assignee;
+    let watcher_focused = app.filter_focused_field == FilterField::Watcher;
     let status_focused = app.filter_focused_field == FilterField::Status;
     let order_focused = app.filter_focused_field == FilterField::OrderBy;
 
@@ -47,6 +48,14 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         Style::default().fg(Color::White)
     };
 
+    let watcher_style = if watcher_focused {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
     let status_style = if status_focused {
         Style::default()
             .fg(Color::Yellow)
@@ -71,11 +80,24 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         ),
     ]);
 
-    let assignee_block = Paragraph::new(assignee_text)
-        .block(Block::default().borders(Borders::ALL).title(" Assignee "));
+    let assignee_block = Paragraph::new(assignee_text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Assignee ('u' pick a user) "),
+    );
 
     frame.render_widget(assignee_block, chunks[0]);
 
+    let watcher_text = Line::from(vec![
+        Span::raw(" Watched issues: "),
+        Span::styled(format!("< {} >", app.filter_watcher.label()), watcher_style),
+    ]);
+
+    let watcher_block = Paragraph::new(watcher_text)
+        .block(Block::default().borders(Borders::ALL).title(" Watcher "));
+
+    frame.render_widget(watcher_block, chunks[1]);
+
     let status_text = Line::from(vec![
         Span::raw(" Status: "),
         Span::styled(format!("< {} >", app.filter_status.label()), status_style),
@@ -84,7 +106,35 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let status_block =
         Paragraph::new(status_text).block(Block::default().borders(Borders::ALL).title(" Status "));
 
-    frame.render_widget(status_block, chunks[1]);
+    frame.render_widget(status_block, chunks[2]);
+
+    for (i, field) in fields.iter().enumerate() {
+        let focused = app.filter_focused_field == FilterField::Field(i);
+        let value = app.filter_field_value(field);
+        let mut spans = vec![Span::raw(format!(" {}: ", field.name))];
+        if focused {
+            spans.push(Span::styled(
+                value.to_string(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled("█", Style::default().fg(Color::Yellow)));
+        } else if value.is_empty() {
+            spans.push(Span::styled("any", Style::default().fg(Color::DarkGray)));
+        } else {
+            spans.push(Span::styled(
+                value.to_string(),
+                Style::default().fg(Color::White),
+            ));
+        }
+        let block = Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} (type a value) ", field.name)),
+        );
+        frame.render_widget(block, chunks[3 + i]);
+    }
 
     let order_text = Line::from(vec![
         Span::raw(" Order by: "),
@@ -94,7 +144,7 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let order_block =
         Paragraph::new(order_text).block(Block::default().borders(Borders::ALL).title(" Order "));
 
-    frame.render_widget(order_block, chunks[2]);
+    frame.render_widget(order_block, chunks[order_chunk]);
 
     let help_text = Paragraph::new(
         " Tab/j/k: Switch field | h/l/←/→: Change value | Enter: Apply | Esc: Cancel ",
@@ -102,5 +152,5 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     .style(Style::default().fg(Color::DarkGray))
     .alignment(Alignment::Center);
 
-    frame.render_widget(help_text, chunks[4]);
+    frame.render_widget(help_text, chunks[order_chunk + 2]);
 }
@@ -0,0 +1,104 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::domain::models::FieldKind;
+use crate::ui::app::FormField;
+
+/// Width of the label column, so that values line up
+const LABEL_WIDTH: usize = 18;
+
+/// Renders a form field as a single `label: value` line.
+pub fn line(field: &FormField, focused: bool) -> Line<'static> {
+    let required = if field.meta.needs_value() { "*" } else { "" };
+    let label_style = if focused {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
+    let mut spans = vec![Span::styled(
+        format!(
+            " {:<width$} ",
+            format!("{}{}:", field.meta.name, required),
+            width = LABEL_WIDTH
+        ),
+        label_style,
+    )];
+    spans.extend(value_spans(field, focused));
+    Line::from(spans)
+}
+
+fn value_spans(field: &FormField, focused: bool) -> Vec<Span<'static>> {
+    let options = &field.meta.allowed_values;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    match &field.meta.kind {
+        FieldKind::Choice => {
+            let chosen = field
+                .chosen
+                .first()
+                .and_then(|&i| options.get(i))
+                .map_or("None".to_string(), |option| option.name.clone());
+            vec![
+                Span::styled("◀ ", dim),
+                Span::styled(chosen, Style::default().fg(Color::White)),
+                Span::styled(" ▶", dim),
+            ]
+        }
+        FieldKind::MultiChoice => options
+            .iter()
+            .enumerate()
+            .flat_map(|(i, option)| {
+                let mark = if field.chosen.contains(&i) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                let style = if focused && field.cursor == i {
+                    Style::default().fg(Color::Black).bg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                [
+                    Span::styled(format!("{} {}", mark, option.name), style),
+                    Span::raw("  "),
+                ]
+            })
+            .collect(),
+        FieldKind::Unsupported(kind) => {
+            vec![Span::styled(
+                format!("({} fields are not supported)", kind),
+                dim,
+            )]
+        }
+        kind => {
+            let mut spans = vec![Span::styled(
+                field.input.clone(),
+                Style::default().fg(Color::White),
+            )];
+            if focused {
+                spans.push(Span::styled("█", Style::default().fg(Color::Yellow)));
+            } else if field.input.is_empty() {
+                spans.push(Span::styled(placeholder(kind), dim));
+            }
+            spans
+        }
+    }
+}
+
+/// Hint shown in empty text fields about the expected input.
+fn placeholder(kind: &FieldKind) -> &'static str {
+    match kind {
+        FieldKind::RichText => "Markdown",
+        FieldKind::Number => "number",
+        FieldKind::Labels => "space or comma separated",
+        FieldKind::User => "account ID (username on Jira Server)",
+        FieldKind::IssueKey => "issue key",
+        FieldKind::Date => "YYYY-MM-DD",
+        _ => "",
+    }
+}
@@ -1,20 +1,37 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Gauge, List, ListItem, ListState, Paragraph, Wrap},
 };
 
-use crate::domain::models::IssueStatus;
-use crate::ui::app::App;
+use crate::domain::models::{FieldKind, Issue, IssueStatus};
+use crate::ui::app::{App, CurrentScreen, DetailTab, FormField};
+
+use super::{field_value, form_field, rich_text};
 
 /// Renders the detailed view of a single issue
 pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     if let Some(issue) = app.get_selected_issue() {
+        let editing = app.current_screen == CurrentScreen::EditIssue;
+        // Rich text fields are edited in the description pane, the others replace the info lines
+        let (rich_fields, line_fields): (Vec<_>, Vec<_>) = app
+            .edit_fields
+            .iter()
+            .enumerate()
+            .partition(|(_, field)| field.meta.kind == FieldKind::RichText);
+        let meta_height = if editing {
+            (line_fields.len() as u16 + 3).max(8)
+        } else if app.custom_fields.is_empty() {
+            8
+        } else {
+            9
+        };
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(6), Constraint::Min(1)])
+            .constraints([Constraint::Length(meta_height), Constraint::Min(1)])
             .split(area);
 
         let status_color = match issue.status {
@@ -24,24 +41,52 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             _ => Color::Magenta,
         };
 
-        let meta_text = vec![
-            Line::from(vec![
-                Span::styled("KEY: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(&issue.key),
-                Span::raw("  |  "),
-                Span::styled("STATUS: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::styled(
-                    format!("{:?}", issue.status),
-                    Style::default().fg(status_color),
-                ),
-            ]),
+        let key_line = Line::from(vec![
+            Span::styled("KEY: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(&issue.key),
+            Span::raw("  |  "),
+            Span::styled("STATUS: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("{:?}", issue.status),
+                Style::default().fg(status_color),
+            ),
+        ]);
+
+        if editing {
+            let mut lines = vec![key_line.clone()];
+            lines.extend(
+                line_fields
+                    .iter()
+                    .map(|(i, field)| edit_line(field, app.edit_focused_field == *i)),
+            );
+            let meta_block = Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Edit Issue (● changed) ")
+                    .border_style(Style::default().fg(Color::Yellow)),
+            );
+            frame.render_widget(meta_block, chunks[0]);
+
+            if let Some((i, field)) = rich_fields.first() {
+                render_rich_text_input(frame, chunks[1], field, app.edit_focused_field == *i);
+                return;
+            }
+        }
+
+        let mut meta_text = vec![
+            key_line,
             Line::from(vec![
                 Span::styled("SUMMARY: ", Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(&issue.summary),
             ]),
             Line::from(vec![
                 Span::styled("ASSIGNEE: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(issue.assignee.as_deref().unwrap_or("Unassigned")),
+                Span::raw(
+                    issue
+                        .assignee
+                        .as_ref()
+                        .map_or("Unassigned", |user| user.display_name.as_str()),
+                ),
                 Span::raw("  |  "),
                 Span::styled("PRIORITY: ", Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(issue.priority.as_deref().unwrap_or("None")),
@@ -49,27 +94,78 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             Line::from(vec![
                 Span::styled("UPDATED: ", Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(issue.updated_at.format("%Y-%m-%d %H:%M").to_string()),
+                Span::raw("  |  "),
+                Span::styled("LABELS: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(if issue.labels.is_empty() {
+                    "-".to_string()
+                } else {
+                    issue.labels.join(", ")
+                }),
+            ]),
+            Line::from(vec![
+                Span::styled("TYPE: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(if issue.issue_type.name.is_empty() {
+                    "-"
+                } else {
+                    issue.issue_type.name.as_str()
+                }),
+                Span::raw("  |  "),
+                Span::styled("PARENT: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(issue.parent_key.as_deref().unwrap_or("-")),
+                Span::raw("  |  "),
+                Span::styled("SUBTASKS: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(if issue.subtask_keys.is_empty() {
+                    "-".to_string()
+                } else {
+                    issue.subtask_keys.join(", ")
+                }),
             ]),
+            watchers_line(issue),
         ];
+        if !app.custom_fields.is_empty() {
+            meta_text.push(custom_fields_line(app, issue));
+        }
 
+        // Issues opened through links show the way back
+        let title = if app.issue_stack.is_empty() {
+            " Issue Info ".to_string()
+        } else {
+            format!(
+                " Issue Info | {} (Esc back) ",
+                app.issue_trail().join(" › ")
+            )
+        };
         let meta_block = Paragraph::new(meta_text)
-            .block(Block::default().borders(Borders::ALL).title(" Issue Info "))
+            .block(Block::default().borders(Borders::ALL).title(title))
             .alignment(Alignment::Left);
 
-        frame.render_widget(meta_block, chunks[0]);
+        if !editing {
+            frame.render_widget(meta_block, chunks[0]);
+        }
 
-        let desc_text = issue
-            .description
-            .as_deref()
-            .unwrap_or("No description provided.");
+        match app.detail_tab {
+            DetailTab::Comments => return render_comments(frame, chunks[1], app),
+            DetailTab::Links => return render_links(frame, chunks[1], app),
+            DetailTab::Attachments => return render_attachments(frame, chunks[1], app),
+            DetailTab::History => return render_history(frame, chunks[1], app),
+            DetailTab::Description => {}
+        }
+
+        let desc_text = match &issue.description {
+            Some(description) => rich_text::to_text(description),
+            None => Text::styled(
+                "No description provided.",
+                Style::default().fg(Color::DarkGray),
+            ),
+        };
 
         let desc_block = Paragraph::new(desc_text)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Description "),
+                    .title(" Description | Comments (Tab) | Links | Attachments | History "),
             )
-            .wrap(Wrap { trim: true })
+            .wrap(Wrap { trim: false })
             .scroll((app.vertical_scroll, 0));
 
         frame.render_widget(desc_block, chunks[1]);
@@ -79,3 +175,397 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         frame.render_widget(p, area);
     }
 }
+
+/// The configured custom fields of the issue, each value colored after its kind
+fn custom_fields_line<'a>(app: &'a App, issue: &Issue) -> Line<'a> {
+    let mut spans = Vec::new();
+    for (i, field) in app.custom_fields.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw("  |  "));
+        }
+        spans.push(Span::styled(
+            format!("{}: ", field.name.to_uppercase()),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        spans.push(match issue.fields.get(&field.id) {
+            Some(value) => field_value::span(value),
+            None => Span::raw("-"),
+        });
+    }
+    Line::from(spans)
+}
+
+/// Watcher and vote counts, marking the ones of the current user
+fn watchers_line(issue: &Issue) -> Line<'_> {
+    let mine = Style::default().fg(Color::Green);
+    let mut spans = vec![
+        Span::styled("WATCHERS: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(issue.watchers.count.to_string()),
+    ];
+    if issue.watchers.is_watching {
+        spans.push(Span::styled(" (watching)", mine));
+    }
+    if !issue.watchers.users.is_empty() {
+        let names: Vec<&str> = issue
+            .watchers
+            .users
+            .iter()
+            .map(|user| user.display_name.as_str())
+            .collect();
+        spans.push(Span::styled(
+            format!(" {}", names.join(", ")),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    spans.push(Span::raw("  |  "));
+    spans.push(Span::styled(
+        "VOTES: ",
+        Style::default().add_modifier(Modifier::BOLD),
+    ));
+    spans.push(Span::raw(issue.votes.count.to_string()));
+    if issue.votes.has_voted {
+        spans.push(Span::styled(" (voted)", mine));
+    }
+    Line::from(spans)
+}
+
+/// A form line prefixed with a marker when the field was changed
+fn edit_line(field: &FormField, focused: bool) -> Line<'static> {
+    let marker = if field.is_dirty() { "●" } else { " " };
+    let mut line = form_field::line(field, focused);
+    line.spans
+        .insert(0, Span::styled(marker, Style::default().fg(Color::Yellow)));
+    line
+}
+
+/// Renders a Markdown input in place of the rendered description
+fn render_rich_text_input(frame: &mut Frame, area: Rect, field: &FormField, focused: bool) {
+    let marker = if field.is_dirty() { " ●" } else { "" };
+    let mut text = Text::raw(field.input.clone());
+    if focused {
+        let cursor = Span::styled("█", Style::default().fg(Color::Yellow));
+        match text.lines.last_mut() {
+            Some(line) if !field.input.ends_with('\n') => line.spans.push(cursor),
+            _ => text.lines.push(Line::from(cursor)),
+        }
+    }
+
+    let border_color = if focused { Color::Yellow } else { Color::White };
+    let input = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} (Markdown){} ", field.meta.name, marker))
+                .border_style(Style::default().fg(border_color)),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(input, area);
+}
+
+/// Renders the links of the issue as a list, grouped by how they relate to it
+fn render_links(frame: &mut Frame, area: Rect, app: &App) {
+    let links = app
+        .get_selected_issue()
+        .map(|issue| issue.links.as_slice())
+        .unwrap_or_default();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Links ({}) | Attachments (Tab) ", links.len()))
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if links.is_empty() {
+        let empty = Paragraph::new("No linked issues. Press 'a' to add one.")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = links
+        .iter()
+        .map(|link| {
+            let status_color = match link.issue_status {
+                IssueStatus::Todo => Color::Gray,
+                IssueStatus::InProgress => Color::Yellow,
+                IssueStatus::Done => Color::Green,
+                _ => Color::Magenta,
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<16} ", link.description()),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!("{:<10} ", link.issue_key),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<12} ", format!("{:?}", link.issue_status)),
+                    Style::default().fg(status_color),
+                ),
+                Span::raw(link.issue_summary.clone()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = ListState::default();
+    state.select(Some(app.selected_link_index));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Renders the attachments of the issue, with a progress bar while one is downloaded
+fn render_attachments(frame: &mut Frame, area: Rect, app: &App) {
+    let attachments = app
+        .get_selected_issue()
+        .map(|issue| issue.attachments.as_slice())
+        .unwrap_or_default();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " Attachments ({}) | History (Tab) ",
+            attachments.len()
+        ))
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if attachments.is_empty() {
+        let empty = Paragraph::new("No attachments. Press 'u' to upload a file.")
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let download = app.attachment_download.as_ref().and_then(|(id, received)| {
+        let attachment = attachments.iter().find(|a| a.id == *id)?;
+        Some((attachment, *received))
+    });
+    let list_area = match download {
+        Some((attachment, received)) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(3)])
+                .split(area);
+            let ratio = (received as f64 / attachment.size.max(1) as f64).min(1.0);
+            let gauge = Gauge::default()
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!(" Downloading {} ", attachment.filename)),
+                )
+                .gauge_style(Style::default().fg(Color::Green))
+                .ratio(ratio)
+                .label(format!(
+                    "{} / {}",
+                    format_size(received),
+                    format_size(attachment.size)
+                ));
+            frame.render_widget(gauge, chunks[1]);
+            chunks[0]
+        }
+        None => area,
+    };
+
+    let items: Vec<ListItem> = attachments
+        .iter()
+        .map(|attachment| {
+            let created = attachment.created_at.with_timezone(&chrono::Local);
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<32} ", attachment.filename),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:>9}  ", format_size(attachment.size)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!("{:<20} ", attachment.mime_type),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(format!(
+                    "{}  {}",
+                    attachment.author,
+                    created.format("%Y-%m-%d %H:%M")
+                )),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = ListState::default();
+    state.select(Some(app.selected_attachment_index));
+    frame.render_stateful_widget(list, list_area, &mut state);
+}
+
+/// A byte count in the largest unit that keeps it above 1 (`512 B`, `1.5 KB`, `3.2 MB`)
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Renders the comments of the issue, starting from the selected one
+fn render_comments(frame: &mut Frame, area: Rect, app: &App) {
+    let title = format!(
+        " Comments ({}/{}) | Links (Tab) ",
+        app.comments.len(),
+        app.total_comments
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if app.comments.is_empty() {
+        let message = if app.is_loading {
+            ""
+        } else {
+            "No comments yet. Press 'a' to add one."
+        };
+        let empty = Paragraph::new(message)
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Wrapped comments have no fixed height, so the list starts at the selection
+    let mut lines = Vec::new();
+    for (i, comment) in app
+        .comments
+        .iter()
+        .enumerate()
+        .skip(app.selected_comment_index)
+    {
+        let selected = i == app.selected_comment_index;
+        let header_style = if selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        let created = comment.created_at.with_timezone(&chrono::Local);
+        let mut header = vec![
+            Span::styled(if selected { "▶ " } else { "  " }, header_style),
+            Span::styled(comment.author.clone(), header_style),
+            Span::styled(
+                format!("  {}", created.format("%Y-%m-%d %H:%M")),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if comment.updated_at > comment.created_at {
+            header.push(Span::styled(
+                " (edited)",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(header));
+
+        for mut line in rich_text::to_text(&comment.body).lines {
+            line.spans.insert(0, Span::raw("  "));
+            lines.push(line);
+        }
+        lines.push(Line::default());
+    }
+
+    let comments = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(comments, area);
+}
+
+fn render_history(frame: &mut Frame, area: Rect, app: &App) {
+    let title = format!(
+        " History ({}/{}) | Description (Tab) ",
+        app.history.len(),
+        app.total_history
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if app.history.is_empty() {
+        let message = if app.is_loading {
+            ""
+        } else {
+            "No changes recorded yet."
+        };
+        let empty = Paragraph::new(message)
+            .block(block)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Like comments, entries have no fixed height so the list starts at the selection
+    let mut lines = Vec::new();
+    for (i, entry) in app
+        .history
+        .iter()
+        .enumerate()
+        .skip(app.selected_history_index)
+    {
+        let selected = i == app.selected_history_index;
+        let header_style = if selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        let created = entry.created_at.with_timezone(&chrono::Local);
+        lines.push(Line::from(vec![
+            Span::styled(if selected { "▶ " } else { "  " }, header_style),
+            Span::styled(entry.author.clone(), header_style),
+            Span::styled(
+                format!("  {}", created.format("%Y-%m-%d %H:%M")),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+
+        for change in &entry.changes {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}: ", change.field),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    change.from.clone().unwrap_or_else(|| "-".into()),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::raw(" → "),
+                Span::styled(
+                    change.to.clone().unwrap_or_else(|| "-".into()),
+                    Style::default().fg(Color::Green),
+                ),
+            ]));
+        }
+        lines.push(Line::default());
+    }
+
+    let history = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(history, area);
+}
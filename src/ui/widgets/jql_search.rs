@@ -0,0 +1,122 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::domain::models::SavedQuerySource;
+use crate::ui::app::{App, JqlSearchField};
+
+/// Renders the free-form JQL search screen with the saved queries list
+pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let naming = app.jql_focused_field == JqlSearchField::SaveName;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),                          // Query
+            Constraint::Length(if naming { 3 } else { 0 }), // Save name
+            Constraint::Min(3),                             // Saved queries
+            Constraint::Length(1),                          // Help
+        ])
+        .split(area);
+
+    let focused_border = |focused: bool| {
+        if focused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        }
+    };
+
+    // Query input
+    let query_focused = app.jql_focused_field == JqlSearchField::Query;
+    let query_text = if app.jql_input.is_empty() && !query_focused {
+        Line::from(Span::styled(
+            " e.g. project = APP AND status = \"In Progress\" ORDER BY updated DESC",
+            Style::default().fg(Color::DarkGray),
+        ))
+    } else {
+        let cursor = if query_focused { "█" } else { "" };
+        Line::from(format!(" {}{}", app.jql_input, cursor))
+    };
+    let query_block = Paragraph::new(query_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" JQL ")
+                .border_style(focused_border(query_focused)),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(query_block, chunks[0]);
+
+    // Save name input
+    if naming {
+        let name_block = Paragraph::new(format!(" {}█", app.jql_name_input)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Save as (Enter to confirm, Esc to cancel) ")
+                .border_style(focused_border(true)),
+        );
+        frame.render_widget(name_block, chunks[1]);
+    }
+
+    // Saved queries
+    let list_focused = app.jql_focused_field == JqlSearchField::SavedQueries;
+    let items: Vec<ListItem> = app
+        .saved_queries
+        .iter()
+        .map(|q| {
+            let (tag, tag_color) = match q.source {
+                SavedQuerySource::Local => ("[local]", Color::Green),
+                SavedQuerySource::JiraFilter(_) => ("[jira] ", Color::Blue),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", tag), Style::default().fg(tag_color)),
+                Span::styled(
+                    format!("{:<30}", q.name),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(q.jql.clone(), Style::default().fg(Color::Gray)),
+            ]))
+        })
+        .collect();
+
+    let list_title = if items.is_empty() {
+        " Saved Queries (none yet — Ctrl+S to save the current query) "
+    } else {
+        " Saved Queries "
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(list_title)
+                .border_style(focused_border(list_focused)),
+        )
+        .highlight_style(if list_focused {
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        });
+
+    let mut state = ListState::default();
+    state.select(Some(app.selected_saved_query_index));
+    frame.render_stateful_widget(list, chunks[2], &mut state);
+
+    let help = if list_focused {
+        " j/k: Navigate | Enter: Run | 'd': Delete local query | Tab: Edit query | Esc: Back "
+    } else {
+        " Enter: Run | Ctrl+S: Save query | Tab: Saved queries | Esc: Back "
+    };
+    let help_text = Paragraph::new(help)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help_text, chunks[3]);
+}
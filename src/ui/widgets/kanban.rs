@@ -0,0 +1,91 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+
+use crate::ui::app::App;
+
+/// Renders the board issues as one column per board column
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let outer = Block::default().borders(Borders::ALL).title(title(app));
+    let inner_area = outer.inner(area);
+    frame.render_widget(outer, area);
+
+    let Some(configuration) = &app.board_configuration else {
+        let placeholder = Paragraph::new(" Loading board columns... ")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(placeholder, inner_area);
+        return;
+    };
+    if configuration.columns.is_empty() {
+        let placeholder = Paragraph::new(" This board has no columns configured ")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(placeholder, inner_area);
+        return;
+    }
+
+    let columns = app.kanban_columns();
+    let selected_column = app.selected_kanban_column();
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, columns.len() as u32);
+            columns.len()
+        ])
+        .split(inner_area);
+
+    for (index, (column, cards)) in configuration.columns.iter().zip(&columns).enumerate() {
+        let is_selected = selected_column == Some(index);
+        let border_color = if is_selected {
+            Color::Yellow
+        } else {
+            Color::White
+        };
+
+        let items: Vec<ListItem> = cards
+            .iter()
+            .map(|&i| {
+                let issue = &app.issues[i];
+                ListItem::new(vec![
+                    Line::from(Span::styled(
+                        issue.key.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from(Span::raw(issue.summary.clone())),
+                ])
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ({}) ", column.name, cards.len()))
+                    .border_style(Style::default().fg(border_color)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let mut state = ListState::default();
+        if is_selected {
+            state.select(cards.iter().position(|&i| i == app.selected_issue_index));
+        }
+        frame.render_stateful_widget(list, areas[index], &mut state);
+    }
+}
+
+fn title(app: &App) -> String {
+    // Columns are only complete once every page has been loaded
+    match (app.total_issues, &app.next_issues_page) {
+        (Some(total), Some(_)) => format!(" Board ({}/{} issues loaded) ", app.issues.len(), total),
+        (None, Some(_)) => format!(" Board ({} issues loaded, more pending) ", app.issues.len()),
+        _ => format!(" Board ({} issues) ", app.issues.len()),
+    }
+}
@@ -0,0 +1,103 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::ui::app::App;
+
+use super::utils::centered_rect;
+
+/// Renders the link picker as a popup overlay: the relation to pick above the key to link to
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(50, 60, area);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = match app.get_selected_issue() {
+        Some(issue) => format!(" Link {} ", issue.key),
+        None => " Link Issue ".to_string(),
+    };
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Issue key
+            Constraint::Min(1),    // Relations
+            Constraint::Length(1), // Help
+        ])
+        .split(inner_area);
+
+    let target = Line::from(vec![
+        Span::styled(" Issue key: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            app.link_target_input.clone(),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled("█", Style::default().fg(Color::Yellow)),
+    ]);
+    frame.render_widget(Paragraph::new(target), chunks[0]);
+
+    let relations = app.link_relations();
+    if relations.is_empty() {
+        let message = if app.is_loading {
+            ""
+        } else {
+            "No link types available"
+        };
+        let empty = Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, chunks[1]);
+    } else {
+        // Reads as a sentence: "PROJ-1 is blocked by PROJ-2"
+        let key = app
+            .get_selected_issue()
+            .map(|issue| issue.key.as_str())
+            .unwrap_or_default();
+        let target = if app.link_target_input.is_empty() {
+            "..."
+        } else {
+            app.link_target_input.as_str()
+        };
+        let items: Vec<ListItem> = relations
+            .into_iter()
+            .map(|(link_type, direction)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("  {} ", key), Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        link_type.description(direction).to_string(),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(format!(" {}", target), Style::default().fg(Color::Gray)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = ListState::default();
+        state.select(Some(app.selected_link_type_index));
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+
+    let help_text = Paragraph::new(" Type the key | ↑/↓: Relation | Enter: Link | Esc: Cancel ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    frame.render_widget(help_text, chunks[2]);
+}
@@ -0,0 +1,133 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::ui::app::{App, LoginField};
+
+use super::utils::centered_rect;
+
+/// Renders the first-run login form
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 80, area);
+    frame.render_widget(Clear, popup_area);
+
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Connect to Jira ")
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let fields = app.login_fields();
+    let mut constraints: Vec<Constraint> = fields.iter().map(|_| Constraint::Length(3)).collect();
+    constraints.push(Constraint::Min(2)); // Error
+    constraints.push(Constraint::Length(1)); // Help
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    for (field, chunk) in fields.iter().zip(chunks.iter()) {
+        let (title, value) = match field {
+            LoginField::BaseUrl => (" Jira URL ", app.login_base_url.clone()),
+            LoginField::Email => (" Email ", app.login_email.clone()),
+            LoginField::Token => (" API Token ", mask(&app.login_token)),
+            LoginField::Storage => (
+                " Store token in ",
+                format!("◀ {} ▶", app.login_storage.label()),
+            ),
+            LoginField::Passphrase => (" Passphrase ", mask(&app.login_passphrase)),
+        };
+        render_input(
+            frame,
+            *chunk,
+            title,
+            value,
+            app.login_focused_field == *field,
+        );
+    }
+
+    render_error(frame, chunks[fields.len()], app);
+
+    let help_text = Paragraph::new(" Tab: Next field | Enter: Save and connect | Esc: Quit ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help_text, chunks[fields.len() + 1]);
+}
+
+/// Renders the passphrase prompt for the encrypted credentials file
+pub fn render_unlock(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(50, 40, area);
+    frame.render_widget(Clear, popup_area);
+
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Unlock Credentials ")
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(2),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    render_input(
+        frame,
+        chunks[0],
+        " Passphrase ",
+        mask(&app.login_passphrase),
+        true,
+    );
+    render_error(frame, chunks[1], app);
+
+    let help_text = Paragraph::new(" Enter: Unlock | Esc: Quit ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(help_text, chunks[2]);
+}
+
+fn render_input(frame: &mut Frame, area: Rect, title: &str, value: String, focused: bool) {
+    let border_color = if focused { Color::Yellow } else { Color::White };
+    let text = if focused {
+        Line::from(vec![
+            Span::styled(value, Style::default().fg(Color::White)),
+            Span::styled("█", Style::default().fg(Color::Yellow)),
+        ])
+    } else {
+        Line::from(Span::styled(value, Style::default().fg(Color::White)))
+    };
+
+    let input = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(border_color)),
+    );
+    frame.render_widget(input, area);
+}
+
+fn render_error(frame: &mut Frame, area: Rect, app: &App) {
+    if let Some(error) = &app.login_error {
+        let error = Paragraph::new(format!("❌ {}", error))
+            .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(error, area);
+    }
+}
+
+/// Hides secrets while keeping their length visible.
+fn mask(secret: &str) -> String {
+    "•".repeat(secret.chars().count())
+}
@@ -1,9 +1,25 @@
+pub mod attachment_upload;
 pub mod backlog;
+pub mod board_filter_modal;
 pub mod boards;
+pub mod comment_composer;
+pub mod config_error;
+pub mod create_issue;
+pub mod field_value;
 pub mod filter_modal;
+pub mod form_field;
 pub mod issue_detail;
+pub mod jql_search;
+pub mod kanban;
+pub mod link_picker;
 pub mod loading;
+pub mod login;
 pub mod notification;
+pub mod rich_text;
+pub mod sprint_header;
+pub mod sprint_picker;
+pub mod transition_modal;
+pub mod user_picker;
 pub mod utils;
 pub mod worklog_list_modal;
 pub mod worklog_modal;
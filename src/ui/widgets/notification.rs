@@ -2,7 +2,8 @@ use ratatui::{
     Frame,
     layout::Alignment,
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Clear, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
 use super::utils::centered_rect;
@@ -14,6 +15,7 @@ pub fn render(
     title: &str,
     message: &str,
     is_success: bool,
+    can_retry: bool,
 ) {
     let popup_area = centered_rect(60, 30, area);
 
@@ -26,9 +28,19 @@ pub fn render(
         .title(format!(" {} ", title))
         .style(Style::default().fg(color).add_modifier(Modifier::BOLD));
 
-    let text = Paragraph::new(message)
+    let mut lines: Vec<Line> = message.lines().map(Line::from).collect();
+    if can_retry {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "r: Retry | Esc: Dismiss",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let text = Paragraph::new(lines)
         .block(popup_block)
         .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
         .style(Style::default().fg(Color::White));
 
     frame.render_widget(text, popup_area);
@@ -0,0 +1,212 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+use crate::domain::rich_text::{Block, Inline, Mark, PanelKind, RichText};
+
+/// Converts a rich text document into styled ratatui text.
+///
+/// Blocks are separated by a blank line; nested content (lists, quotes, panels)
+/// is indented with a prefix that is repeated on every wrapped source line.
+pub fn to_text(doc: &RichText) -> Text<'static> {
+    let mut lines = Vec::new();
+    render_blocks(&doc.blocks, &[], &mut lines);
+    Text::from(lines)
+}
+
+fn render_blocks(blocks: &[Block], prefix: &[Span<'static>], out: &mut Vec<Line<'static>>) {
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            out.push(Line::from(prefix.to_vec()));
+        }
+        render_block(block, prefix, out);
+    }
+}
+
+fn render_block(block: &Block, prefix: &[Span<'static>], out: &mut Vec<Line<'static>>) {
+    match block {
+        Block::Paragraph(content) => push_inlines(content, Style::default(), prefix, out),
+        Block::Heading { level, content } => {
+            let style = match level {
+                1 => Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                2 => Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+                _ => Style::default().add_modifier(Modifier::BOLD),
+            };
+            push_inlines(content, style, prefix, out);
+        }
+        Block::BulletList(items) => {
+            for item in items {
+                render_list_item(item, "• ".to_string(), prefix, out);
+            }
+        }
+        Block::OrderedList { start, items } => {
+            for (i, item) in items.iter().enumerate() {
+                render_list_item(item, format!("{}. ", *start as usize + i), prefix, out);
+            }
+        }
+        Block::Code { language, text } => {
+            let code_style = Style::default().fg(Color::Green).bg(Color::Black);
+            if let Some(lang) = language.as_deref().filter(|l| !l.is_empty()) {
+                let mut spans = prefix.to_vec();
+                spans.push(Span::styled(
+                    format!(" {} ", lang),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                out.push(Line::from(spans));
+            }
+            for code_line in text.lines() {
+                let mut spans = prefix.to_vec();
+                spans.push(Span::styled("  ", code_style));
+                spans.push(Span::styled(code_line.to_string(), code_style));
+                out.push(Line::from(spans));
+            }
+        }
+        Block::Blockquote(content) => {
+            let mut nested = prefix.to_vec();
+            nested.push(Span::styled("│ ", Style::default().fg(Color::DarkGray)));
+            render_blocks(content, &nested, out);
+        }
+        Block::Panel { kind, content } => {
+            let (label, color) = match kind {
+                PanelKind::Info => ("ℹ Info", Color::Blue),
+                PanelKind::Note => ("✎ Note", Color::Magenta),
+                PanelKind::Warning => ("⚠ Warning", Color::Yellow),
+                PanelKind::Error => ("✖ Error", Color::Red),
+                PanelKind::Success => ("✔ Success", Color::Green),
+            };
+            let mut nested = prefix.to_vec();
+            nested.push(Span::styled("┃ ", Style::default().fg(color)));
+            let mut header = nested.clone();
+            header.push(Span::styled(
+                label,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+            out.push(Line::from(header));
+            render_blocks(content, &nested, out);
+        }
+        Block::Table(rows) => {
+            let border = Style::default().fg(Color::DarkGray);
+            for row in rows {
+                let mut spans = prefix.to_vec();
+                spans.push(Span::styled("│ ", border));
+                for cell in &row.cells {
+                    let text = RichText {
+                        blocks: cell.content.clone(),
+                    }
+                    .to_markdown()
+                    .replace('\n', " ");
+                    let style = if cell.is_header {
+                        Style::default().add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    };
+                    spans.push(Span::styled(text, style));
+                    spans.push(Span::styled(" │ ", border));
+                }
+                out.push(Line::from(spans));
+            }
+        }
+        Block::Rule => {
+            let mut spans = prefix.to_vec();
+            spans.push(Span::styled(
+                "─".repeat(40),
+                Style::default().fg(Color::DarkGray),
+            ));
+            out.push(Line::from(spans));
+        }
+    }
+}
+
+fn render_list_item(
+    item: &[Block],
+    bullet: String,
+    prefix: &[Span<'static>],
+    out: &mut Vec<Line<'static>>,
+) {
+    let indent = " ".repeat(bullet.chars().count());
+    let start = out.len();
+    let mut nested = prefix.to_vec();
+    nested.push(Span::raw(indent));
+    for block in item {
+        render_block(block, &nested, out);
+    }
+
+    // Replace the indentation of the item's first line with the bullet itself
+    match out.get_mut(start) {
+        Some(first) => {
+            if let Some(span) = first.spans.get_mut(prefix.len()) {
+                *span = Span::styled(bullet, Style::default().fg(Color::Cyan));
+            }
+        }
+        None => {
+            let mut spans = prefix.to_vec();
+            spans.push(Span::styled(bullet, Style::default().fg(Color::Cyan)));
+            out.push(Line::from(spans));
+        }
+    }
+}
+
+/// Pushes inline content as one or more lines, splitting on hard breaks.
+fn push_inlines(
+    inlines: &[Inline],
+    base: Style,
+    prefix: &[Span<'static>],
+    out: &mut Vec<Line<'static>>,
+) {
+    let mut spans = prefix.to_vec();
+    for inline in inlines {
+        match inline {
+            Inline::Text { text, marks } => {
+                let mut style = base;
+                let mut link = None;
+                for mark in marks {
+                    style = match mark {
+                        Mark::Strong => style.add_modifier(Modifier::BOLD),
+                        Mark::Em => style.add_modifier(Modifier::ITALIC),
+                        Mark::Code => style.fg(Color::Green).bg(Color::Black),
+                        Mark::Strike => style.add_modifier(Modifier::CROSSED_OUT),
+                        Mark::Underline => style.add_modifier(Modifier::UNDERLINED),
+                        Mark::Link(href) => {
+                            link = Some(href.clone());
+                            style.fg(Color::Blue).add_modifier(Modifier::UNDERLINED)
+                        }
+                    };
+                }
+                // Text may still contain raw newlines (e.g. plain-text descriptions)
+                for (i, part) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        out.push(Line::from(std::mem::replace(&mut spans, prefix.to_vec())));
+                    }
+                    spans.push(Span::styled(part.to_string(), style));
+                }
+                if let Some(href) = link.filter(|href| href != text) {
+                    spans.push(Span::styled(
+                        format!(" <{}>", href),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+            }
+            Inline::Mention { text, .. } => {
+                let name = if text.starts_with('@') {
+                    text.clone()
+                } else {
+                    format!("@{}", text)
+                };
+                spans.push(Span::styled(
+                    name,
+                    base.fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ));
+            }
+            Inline::Emoji(shortcode) => spans.push(Span::styled(shortcode.clone(), base)),
+            Inline::HardBreak => {
+                out.push(Line::from(std::mem::replace(&mut spans, prefix.to_vec())));
+            }
+        }
+    }
+    out.push(Line::from(spans));
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::domain::models::{Sprint, SprintState};
+
+/// Height of the header, borders included
+pub const HEIGHT: u16 = 4;
+
+/// Renders the sprint shown in the backlog: name, dates, days remaining and goal
+pub fn render(frame: &mut Frame, area: Rect, sprint: &Sprint) {
+    let state_color = match sprint.state {
+        SprintState::Active => Color::Green,
+        SprintState::Future => Color::Cyan,
+        SprintState::Closed => Color::DarkGray,
+    };
+
+    let summary = Line::from(vec![
+        Span::styled(
+            format!(" {} ", sprint.name),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("[{}] ", state_label(sprint.state)),
+            Style::default().fg(state_color),
+        ),
+        Span::raw(format!("{}  ", date_range(sprint))),
+        Span::styled(
+            timing(sprint, Utc::now()),
+            Style::default().fg(Color::Yellow),
+        ),
+    ]);
+    let goal = Line::from(vec![
+        Span::styled(" Goal: ", Style::default().fg(Color::DarkGray)),
+        Span::raw(sprint.goal.as_deref().unwrap_or("-")),
+    ]);
+
+    let header = Paragraph::new(vec![summary, goal])
+        .block(Block::default().borders(Borders::ALL).title(" Sprint "))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(header, area);
+}
+
+pub fn state_label(state: SprintState) -> &'static str {
+    match state {
+        SprintState::Active => "active",
+        SprintState::Future => "future",
+        SprintState::Closed => "closed",
+    }
+}
+
+/// `01 Oct → 14 Oct`, in local time
+pub fn date_range(sprint: &Sprint) -> String {
+    let date = |d: Option<DateTime<Utc>>| {
+        d.map(|d| d.with_timezone(&Local).format("%d %b").to_string())
+            .unwrap_or_else(|| "?".to_string())
+    };
+    match (sprint.start_date, sprint.end_date) {
+        (None, None) => "not scheduled".to_string(),
+        (start, end) => format!("{} → {}", date(start), date(end)),
+    }
+}
+
+/// Days left in an active sprint, or how it relates to today otherwise
+pub fn timing(sprint: &Sprint, now: DateTime<Utc>) -> String {
+    match sprint.state {
+        SprintState::Closed => "completed".to_string(),
+        SprintState::Future => match sprint.start_date {
+            Some(start) if start > now => {
+                format!("starts in {} days", (start - now).num_days().max(1))
+            }
+            _ => "not started".to_string(),
+        },
+        SprintState::Active => match sprint.days_remaining(now) {
+            Some(days) if days > 1 => format!("{} days remaining", days),
+            Some(1) => "1 day remaining".to_string(),
+            Some(0) => "ends today".to_string(),
+            Some(days) => format!("overdue by {} days", -days),
+            None => "no end date".to_string(),
+        },
+    }
+}
@@ -0,0 +1,102 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::domain::models::SprintState;
+use crate::ui::app::{App, SprintPickerMode};
+
+use super::sprint_header::{date_range, state_label};
+use super::utils::centered_rect;
+
+/// Renders the sprint picker as a popup overlay
+pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let popup_area = centered_rect(60, 60, area);
+
+    frame.render_widget(Clear, popup_area);
+
+    let (title, first_entry, help) = match app.sprint_picker_mode {
+        SprintPickerMode::Browse => (
+            " Show sprint ".to_string(),
+            "All board issues",
+            " j/k: Navigate | Enter: Show | Esc: Cancel ",
+        ),
+        SprintPickerMode::MoveIssue => (
+            match app.get_selected_issue() {
+                Some(issue) => format!(" Move {} to sprint... ", issue.key),
+                None => " Move to sprint... ".to_string(),
+            },
+            "Backlog (no sprint)",
+            " j/k: Navigate | Enter: Move | Esc: Cancel ",
+        ),
+    };
+
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let current = app.current_sprint_id();
+    let items: Vec<ListItem> = app
+        .sprint_picker_entries()
+        .into_iter()
+        .map(|entry| match entry {
+            None => ListItem::new(Line::from(Span::styled(
+                format!(" {}", first_entry),
+                Style::default().fg(Color::White),
+            ))),
+            Some(sprint) => {
+                let state_color = match sprint.state {
+                    SprintState::Active => Color::Green,
+                    SprintState::Future => Color::Cyan,
+                    SprintState::Closed => Color::DarkGray,
+                };
+                let marker = if current == Some(sprint.id) {
+                    "● "
+                } else {
+                    "  "
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(marker),
+                    Span::styled(
+                        format!("{:<28}", sprint.name),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!("{:<8}", state_label(sprint.state)),
+                        Style::default().fg(state_color),
+                    ),
+                    Span::styled(date_range(sprint), Style::default().fg(Color::DarkGray)),
+                ]))
+            }
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = ListState::default();
+    state.select(Some(app.selected_sprint_index));
+    frame.render_stateful_widget(list, chunks[0], &mut state);
+
+    let help_text = Paragraph::new(help)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    frame.render_widget(help_text, chunks[1]);
+}
@@ -0,0 +1,91 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::domain::models::IssueStatus;
+use crate::ui::app::App;
+
+use super::utils::centered_rect;
+
+/// Renders the transition picker as a popup overlay
+pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let popup_area = centered_rect(50, 50, area);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = match app.get_selected_issue() {
+        Some(issue) => format!(" Move {} to... ", issue.key),
+        None => " Move to... ".to_string(),
+    };
+
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    if app.transitions.is_empty() {
+        let message = if app.is_loading {
+            ""
+        } else {
+            "No transitions available for this issue"
+        };
+        let empty = Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = app
+            .transitions
+            .iter()
+            .map(|t| {
+                let status_color = match t.to_status {
+                    IssueStatus::Todo => Color::Gray,
+                    IssueStatus::InProgress => Color::Yellow,
+                    IssueStatus::Done => Color::Green,
+                    _ => Color::Magenta,
+                };
+                let target = match &t.to_status {
+                    IssueStatus::Other(name) => name.clone(),
+                    other => format!("{:?}", other),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!(" {:<24}", t.name),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::raw("→ "),
+                    Span::styled(target, Style::default().fg(status_color)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = ListState::default();
+        state.select(Some(app.selected_transition_index));
+        frame.render_stateful_widget(list, chunks[0], &mut state);
+    }
+
+    let help_text = Paragraph::new(" j/k: Navigate | Enter: Apply | Esc: Cancel ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    frame.render_widget(help_text, chunks[1]);
+}
@@ -0,0 +1,113 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::ui::app::{App, UserPickerMode};
+
+use super::utils::centered_rect;
+
+/// Renders the user picker as a popup overlay: a search input above the matching users
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(50, 60, area);
+
+    frame.render_widget(Clear, popup_area);
+
+    let (title, help) = match app.user_picker_mode {
+        UserPickerMode::AssignIssue => (
+            match app.get_selected_issue() {
+                Some(issue) => format!(" Assign {} to... ", issue.key),
+                None => " Assign to... ".to_string(),
+            },
+            " Type to search | ↑/↓: Navigate | Enter: Assign | Esc: Cancel ",
+        ),
+        UserPickerMode::FilterAssignee => (
+            " Show issues assigned to... ".to_string(),
+            " Type to search | ↑/↓: Navigate | Enter: Choose | Esc: Cancel ",
+        ),
+    };
+
+    let popup_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::Cyan));
+
+    let inner_area = popup_block.inner(popup_area);
+    frame.render_widget(popup_block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Search
+            Constraint::Min(1),    // Users
+            Constraint::Length(1), // Help
+        ])
+        .split(inner_area);
+
+    let search = Line::from(vec![
+        Span::styled(" Search: ", Style::default().fg(Color::Yellow)),
+        Span::styled(app.user_query.clone(), Style::default().fg(Color::White)),
+        Span::styled("█", Style::default().fg(Color::Yellow)),
+    ]);
+    frame.render_widget(Paragraph::new(search), chunks[0]);
+
+    let entries = app.user_picker_entries();
+    if entries.is_empty() {
+        let message = match app.user_picker_mode {
+            _ if app.is_loading => "",
+            UserPickerMode::FilterAssignee if app.user_query.is_empty() => {
+                "Type a name to search users"
+            }
+            _ => "No matching users",
+        };
+        let empty = Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, chunks[1]);
+    } else {
+        let assignee = app
+            .get_selected_issue()
+            .and_then(|issue| issue.assignee.as_ref())
+            .filter(|_| app.user_picker_mode == UserPickerMode::AssignIssue);
+        let items: Vec<ListItem> = entries
+            .into_iter()
+            .map(|entry| match entry {
+                None => ListItem::new(Line::from(Span::styled(
+                    "  Unassigned",
+                    Style::default().fg(Color::Gray),
+                ))),
+                Some(user) => {
+                    let marker = if assignee.is_some_and(|a| a.account_id == user.account_id) {
+                        "● "
+                    } else {
+                        "  "
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::raw(marker),
+                        Span::styled(user.display_name.clone(), Style::default().fg(Color::White)),
+                    ]))
+                }
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = ListState::default();
+        state.select(Some(app.selected_user_index));
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+
+    let help_text = Paragraph::new(help)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    frame.render_widget(help_text, chunks[2]);
+}
@@ -86,10 +86,12 @@ fn render_worklog_table(frame: &mut Frame, area: Rect, app: &App) {
                 .comment
                 .as_ref()
                 .map(|c| {
-                    if c.len() > 60 {
-                        format!("{}...", &c[..57])
+                    // Comments may span several lines; keep the row single-line
+                    let flat = c.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if flat.chars().count() > 60 {
+                        format!("{}...", flat.chars().take(57).collect::<String>())
                     } else {
-                        c.clone()
+                        flat
                     }
                 })
                 .unwrap_or_else(|| "-".to_string());
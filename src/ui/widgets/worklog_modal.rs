@@ -2,7 +2,7 @@ use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
@@ -30,7 +30,7 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             Constraint::Length(3), // Date
             Constraint::Length(3), // Time
             Constraint::Length(3), // Time spent
-            Constraint::Length(5), // Comment
+            Constraint::Min(5),    // Comment
             Constraint::Length(1),
             Constraint::Length(3), // Help
         ])
@@ -181,12 +181,16 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     };
 
     let comment_text = if app.worklog_comment.is_empty() {
-        Line::from(vec![Span::styled(
-            " (Optional) Work description...",
+        Text::from(Line::from(vec![Span::styled(
+            " (Optional) Work description, Markdown supported...",
             Style::default().fg(Color::DarkGray),
-        )])
+        )]))
     } else {
-        Line::from(vec![Span::raw(format!(" {}", app.worklog_comment))])
+        // Split on '\n' rather than `lines()` so a fresh empty line shows
+        app.worklog_comment
+            .split('\n')
+            .map(|line| Line::from(format!(" {}", line)))
+            .collect()
     };
 
     let comment_block = Paragraph::new(comment_text).block(
@@ -199,7 +203,7 @@ pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     frame.render_widget(comment_block, chunks[3]);
 
     let help_text = Paragraph::new(
-        " Tab/j/k: Switch field | 0-9: Edit | Backspace: Delete | Enter: Save | Esc: Cancel ",
+        " Tab: Switch field | Enter: Save (new line in comment) | Ctrl+S: Save | Esc: Cancel ",
     )
     .style(Style::default().fg(Color::DarkGray))
     .alignment(Alignment::Center);